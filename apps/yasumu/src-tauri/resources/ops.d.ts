@@ -19,6 +19,14 @@ declare global {
         op_get_rpc_port(): number | null;
         op_set_workspace_dir(path: string | null): void;
         op_get_workspace_dir(): string | null;
+        op_set_module_auth_tokens(tokens: string): void;
+        op_read_snapshots(test: string): Record<string, string>;
+        op_write_snapshots(
+          test: string,
+          snapshots: Record<string, string>,
+        ): void;
+        op_hrtime(): number;
+        op_get_env_overrides(): Record<string, string> | null;
         op_show_confirmation_dialog_sync(
           title: string,
           message: string,
@@ -0,0 +1,107 @@
+//! `tanxium bench`: runs benchmark files and reports their timings.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use tanxium::{BenchFileReport, BenchOptions, Tanxium, TestOutcome, discover_bench_files};
+
+/// Runs the benchmarks found in `paths` and returns whether all of them
+/// completed.
+pub fn run(
+    runtime: &Tanxium,
+    paths: Vec<PathBuf>,
+    options: &BenchOptions,
+    json: bool,
+) -> Result<bool> {
+    let paths = if paths.is_empty() {
+        vec![PathBuf::from(".")]
+    } else {
+        paths
+    };
+    let files = discover_bench_files(&paths)?;
+    if files.is_empty() {
+        anyhow::bail!("No benchmark files found");
+    }
+
+    let report = runtime.bench(&files, options, |file| {
+        if !json {
+            print_file(file);
+        }
+    })?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    }
+    Ok(report.success())
+}
+
+fn print_file(file: &BenchFileReport) {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    println!("{}", display_path(&file.path, &cwd));
+
+    let width = file
+        .results
+        .iter()
+        .map(|result| result.name.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max("benchmark".len());
+    if !file.results.is_empty() {
+        println!(
+            "  {:width$}  {:>11}  {:>11}  {:>11}  {:>14}",
+            "benchmark", "mean", "p50", "p99", "ops/s"
+        );
+    }
+    for result in &file.results {
+        match result.result {
+            TestOutcome::Pass => println!(
+                "  {:width$}  {:>11}  {:>11}  {:>11}  {:>14}",
+                result.name,
+                format_time(result.mean),
+                format_time(result.p50),
+                format_time(result.p99),
+                format_ops(result.ops_per_second)
+            ),
+            TestOutcome::Skip => println!("  {:width$}  (skipped)", result.name),
+            TestOutcome::Fail => {
+                println!("  {:width$}  ✗ failed", result.name);
+                if let Some(error) = &result.error {
+                    for line in error.lines() {
+                        println!("      {line}");
+                    }
+                }
+            }
+        }
+    }
+    if let Some(error) = &file.error {
+        println!("  ✗ {}", error.replace('\n', "\n    "));
+    }
+    println!();
+}
+
+fn display_path(path: &Path, cwd: &Path) -> String {
+    path.strip_prefix(cwd).unwrap_or(path).display().to_string()
+}
+
+fn format_time(nanoseconds: f64) -> String {
+    if nanoseconds < 1e3 {
+        format!("{nanoseconds:.1} ns")
+    } else if nanoseconds < 1e6 {
+        format!("{:.2} µs", nanoseconds / 1e3)
+    } else if nanoseconds < 1e9 {
+        format!("{:.2} ms", nanoseconds / 1e6)
+    } else {
+        format!("{:.2} s", nanoseconds / 1e9)
+    }
+}
+
+fn format_ops(ops: f64) -> String {
+    let digits = format!("{:.0}", ops);
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
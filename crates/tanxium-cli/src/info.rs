@@ -0,0 +1,143 @@
+//! `tanxium info`: prints the module graph of an entrypoint.
+
+use anyhow::Result;
+use std::collections::HashSet;
+use tanxium::{Dependency, ModuleGraph, ModuleInfo, ModuleKind};
+
+/// Prints the graph as a dependency tree, or as JSON when requested.
+pub fn print(graph: &ModuleGraph, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(graph)?);
+        return Ok(());
+    }
+
+    let Some(root) = graph.get(&graph.root) else {
+        return Ok(());
+    };
+    println!("{}{}", root.specifier, describe(root, None));
+
+    let mut expanded = HashSet::from([root.specifier.as_str()]);
+    print_dependencies(graph, root, "", &mut expanded);
+
+    let total_size = graph
+        .modules
+        .iter()
+        .filter_map(|module| module.size)
+        .sum::<usize>();
+    println!();
+    println!(
+        "{} modules, {}",
+        graph.modules.len(),
+        format_size(total_size)
+    );
+    Ok(())
+}
+
+/// Prints each dependency of a module. Modules already shown elsewhere in
+/// the tree are marked with `*` instead of being expanded again.
+fn print_dependencies<'a>(
+    graph: &'a ModuleGraph,
+    module: &'a ModuleInfo,
+    prefix: &str,
+    expanded: &mut HashSet<&'a str>,
+) {
+    for (index, dependency) in module.dependencies.iter().enumerate() {
+        let last = index + 1 == module.dependencies.len();
+        let (branch, indent) = if last {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+
+        let target = dependency
+            .resolved
+            .as_deref()
+            .and_then(|resolved| graph.get(resolved));
+        let mut line = format!("{prefix}{branch}{}", dependency.specifier);
+        if let Some(resolved) = dependency
+            .resolved
+            .as_deref()
+            .filter(|resolved| *resolved != dependency.specifier)
+        {
+            line.push_str(&format!(" → {resolved}"));
+        }
+
+        match (target, &dependency.error) {
+            (_, Some(error)) => line.push_str(&format!(" ✗ {error}")),
+            (Some(target), None) => {
+                line.push_str(&describe(target, Some(dependency)));
+                if !expanded.insert(target.specifier.as_str()) && !target.dependencies.is_empty() {
+                    line.push_str(" *");
+                    println!("{line}");
+                    continue;
+                }
+            }
+            (None, None) => {}
+        }
+        println!("{line}");
+
+        if let Some(target) = target {
+            print_dependencies(graph, target, &format!("{prefix}{indent}"), expanded);
+        }
+    }
+}
+
+/// Summarizes a module as ` (TypeScript, 1.2 KB, remote, cached)`.
+fn describe(module: &ModuleInfo, dependency: Option<&Dependency>) -> String {
+    let mut details = Vec::new();
+    if let Some(media_type) = &module.media_type {
+        details.push(media_type.clone());
+    }
+    if let Some(size) = module.size {
+        details.push(format_size(size));
+    }
+    let kind = match module.kind {
+        ModuleKind::Local => None,
+        ModuleKind::Package => Some("package"),
+        ModuleKind::Remote => Some("remote"),
+        ModuleKind::Builtin => Some("built-in"),
+        ModuleKind::Virtual => Some("virtual"),
+        ModuleKind::Node => Some("node"),
+        ModuleKind::Inline => Some("inline"),
+    };
+    details.extend(kind.map(str::to_owned));
+    match module.cached {
+        Some(true) => details.push("cached".to_string()),
+        Some(false) => details.push("fetched".to_string()),
+        None => {}
+    }
+    if dependency.is_some_and(|dependency| dependency.dynamic) {
+        details.push("dynamic".to_string());
+    }
+
+    let mut description = if details.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", details.join(", "))
+    };
+    if let Some(redirect) = &module.redirect {
+        description.push_str(&format!(" ⇢ {redirect}"));
+    }
+    if let Some(error) = &module.error {
+        description.push_str(&format!(" ✗ {error}"));
+    }
+    description
+}
+
+fn format_size(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for next in &UNITS[1..] {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+    format!("{size:.1} {unit}")
+}
@@ -1,12 +1,24 @@
 //! Command-line entrypoint for Tanxium.
 
+mod bench;
+mod info;
 mod repl;
 mod terminal_host;
+mod test;
+mod watch;
 
 use anyhow::Result;
-use clap::{ArgAction, Parser, Subcommand, builder::BoolishValueParser};
-use std::{path::PathBuf, sync::Arc};
-use tanxium::{Tanxium, install_permission_prompter};
+use clap::{ArgAction, Args, Parser, Subcommand, builder::BoolishValueParser};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+use tanxium::{
+    AuthTokens, BenchOptions, CompileOptions, ExecutionReport, RESOLUTION_TRACE_TARGET,
+    StandaloneArchive, Tanxium, TanxiumBuilder, TestOptions, TestShard,
+    install_permission_prompter, read_env_file,
+};
 use terminal_host::TerminalHost;
 
 /// Execute scripts or start an interactive Tanxium session.
@@ -32,6 +44,12 @@ struct Cli {
     /// Allow modules to be imported over insecure HTTP.
     #[arg(long, global = true, action = ArgAction::SetTrue)]
     allow_http_imports: bool,
+    /// Log every module resolution step, including each directory searched
+    /// for packages, to stderr.
+    #[arg(long, global = true, action = ArgAction::SetTrue)]
+    trace_resolution: bool,
+    #[command(flatten)]
+    env: ScriptEnv,
     #[command(subcommand)]
     command: Option<Command>,
 }
@@ -42,6 +60,31 @@ impl Cli {
     }
 }
 
+/// Environment variables given to scripts.
+#[derive(Args)]
+struct ScriptEnv {
+    /// Load environment variables for scripts from a dotenv file
+    /// (repeatable; later files win).
+    #[arg(long = "env-file", value_name = "PATH", global = true)]
+    files: Vec<PathBuf>,
+    /// Hide this process's environment from scripts, leaving only the
+    /// variables from `--env-file`.
+    #[arg(long, global = true, action = ArgAction::SetTrue)]
+    clear_env: bool,
+}
+
+impl ScriptEnv {
+    fn apply(&self, mut builder: TanxiumBuilder) -> Result<TanxiumBuilder> {
+        for path in &self.files {
+            builder = builder.envs(read_env_file(path)?);
+        }
+        if self.clear_env {
+            builder = builder.env_clear();
+        }
+        Ok(builder)
+    }
+}
+
 /// Supported CLI commands.
 #[derive(Subcommand)]
 enum Command {
@@ -58,6 +101,107 @@ enum Command {
         /// Print JSON renderer events emitted by the runtime.
         #[arg(long)]
         verbose: bool,
+        /// Restart the script when a local module it imports changes.
+        #[arg(long)]
+        watch: bool,
+        /// Extra file, directory, or glob pattern to watch (repeatable).
+        #[arg(long = "watch-path", value_name = "PATTERN", requires = "watch")]
+        watch_paths: Vec<String>,
+        /// Arguments passed to the script as `Deno.args`, after `--`.
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// Print the module graph of an entrypoint without running it.
+    Info {
+        /// Entrypoint file to inspect.
+        file: PathBuf,
+        /// Workspace used for package resolution.
+        #[arg(long)]
+        workspace: Option<PathBuf>,
+        /// Print the graph as JSON.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Build a standalone executable that runs an entrypoint.
+    Compile {
+        /// Entrypoint file to embed.
+        file: PathBuf,
+        /// Path of the executable to write. Defaults to the entrypoint's
+        /// name in the current directory.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Workspace used for package resolution.
+        #[arg(long)]
+        workspace: Option<PathBuf>,
+        /// Extra module to embed with its dependencies (repeatable).
+        #[arg(long)]
+        include: Vec<PathBuf>,
+    },
+    /// Bundle an entrypoint and its imports into a single ES module.
+    Bundle {
+        /// Entrypoint file to bundle.
+        file: PathBuf,
+        /// Path of the bundle to write. Its source map is written next to
+        /// it with a `.map` suffix.
+        #[arg(short, long)]
+        output: PathBuf,
+        /// Workspace used for package resolution.
+        #[arg(long)]
+        workspace: Option<PathBuf>,
+    },
+    /// Run `*.test.ts` and `*_test.ts` files with `yasumu:test`.
+    Test {
+        /// Test files, or directories to search for them. Defaults to the
+        /// current directory.
+        paths: Vec<PathBuf>,
+        /// Only run tests whose full name contains this string, or matches
+        /// it when wrapped in slashes (`/pattern/`).
+        #[arg(long)]
+        filter: Option<String>,
+        /// Output format.
+        #[arg(long, value_enum, default_value_t = test::Reporter::Pretty)]
+        reporter: test::Reporter,
+        /// Number of test files to run at once.
+        #[arg(long, default_value_t = 1, value_name = "N")]
+        jobs: usize,
+        /// Run only one slice of the test files, such as `2/4`.
+        #[arg(long, value_name = "INDEX/COUNT")]
+        shard: Option<TestShard>,
+        /// Fail a test that runs longer than this many milliseconds and
+        /// stop its file.
+        #[arg(long, value_name = "MS")]
+        timeout: Option<u64>,
+        /// Stop a test file that runs longer than this many milliseconds.
+        #[arg(long, value_name = "MS")]
+        file_timeout: Option<u64>,
+        /// Overwrite snapshots that no longer match and remove obsolete ones.
+        #[arg(long, short = 'u')]
+        update_snapshots: bool,
+        /// Workspace used for package resolution.
+        #[arg(long)]
+        workspace: Option<PathBuf>,
+        /// Resource root exposed to the Yasumu runtime.
+        #[arg(long)]
+        resources: Option<PathBuf>,
+    },
+    /// Run `*.bench.ts` and `*_bench.ts` files with `yasumu:bench`.
+    Bench {
+        /// Benchmark files, or directories to search for them. Defaults to
+        /// the current directory.
+        paths: Vec<PathBuf>,
+        /// Only run benchmarks whose name contains this string, or matches
+        /// it when wrapped in slashes (`/pattern/`).
+        #[arg(long)]
+        filter: Option<String>,
+        /// Print the full report as JSON.
+        #[arg(long)]
+        json: bool,
+        /// Workspace used for package resolution.
+        #[arg(long)]
+        workspace: Option<PathBuf>,
+        /// Resource root exposed to the Yasumu runtime.
+        #[arg(long)]
+        resources: Option<PathBuf>,
     },
     /// Start an interactive JavaScript/TypeScript session.
     Repl {
@@ -73,29 +217,103 @@ enum Command {
     },
 }
 
-fn build_runtime(
+fn runtime_builder(
     workspace: Option<PathBuf>,
     resources: Option<PathBuf>,
     verbose: bool,
     sandboxed: bool,
     allow_http_imports: bool,
-) -> Result<Tanxium> {
+    env: &ScriptEnv,
+) -> Result<TanxiumBuilder> {
     let cwd = std::env::current_dir()?;
     let host = Arc::new(TerminalHost::new(verbose));
     install_permission_prompter(host.clone());
 
+    env.apply(
+        Tanxium::builder()
+            .workspace_dir(workspace.unwrap_or_else(|| cwd.clone()))
+            .resource_dir(resources.unwrap_or(cwd))
+            .ready(true)
+            .allow_main_worker_all_permissions(!sandboxed)
+            .allow_http_imports(allow_http_imports)
+            .auth_tokens(auth_tokens_from_env())
+            .host(host),
+    )
+}
+
+/// Reads remote import credentials from `TANXIUM_AUTH_TOKENS`, falling back
+/// to Deno's `DENO_AUTH_TOKENS`.
+fn auth_tokens_from_env() -> AuthTokens {
+    std::env::var("TANXIUM_AUTH_TOKENS")
+        .or_else(|_| std::env::var("DENO_AUTH_TOKENS"))
+        .map(|tokens| AuthTokens::parse(&tokens))
+        .unwrap_or_default()
+}
+
+/// Prints resolution steps to stderr, leaving stdout to the script.
+fn install_resolution_tracing() {
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::new(format!(
+            "{RESOLUTION_TRACE_TARGET}=debug"
+        )))
+        .with_writer(std::io::stderr)
+        .without_time()
+        .with_target(false)
+        .with_level(false)
+        .try_init();
+}
+
+/// Names a compiled executable after its entrypoint.
+fn default_executable_name(file: &Path) -> PathBuf {
+    let name = PathBuf::from(file.file_stem().unwrap_or(file.as_os_str()));
+    if cfg!(windows) {
+        name.with_extension("exe")
+    } else {
+        name
+    }
+}
+
+/// Runs the entrypoint embedded by `tanxium compile`.
+fn run_standalone(archive: StandaloneArchive) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let host = Arc::new(TerminalHost::new(false));
+    install_permission_prompter(host.clone());
+
     Tanxium::builder()
-        .workspace_dir(workspace.unwrap_or_else(|| cwd.clone()))
-        .resource_dir(resources.unwrap_or(cwd))
+        .resource_dir(cwd)
         .ready(true)
-        .allow_main_worker_all_permissions(!sandboxed)
-        .allow_http_imports(allow_http_imports)
+        .auth_tokens(auth_tokens_from_env())
+        .args(
+            std::env::args_os()
+                .skip(1)
+                .map(|arg| arg.to_string_lossy().into_owned()),
+        )
+        .standalone(archive)
         .host(host)
-        .build()
+        .build()?
+        .run_standalone_blocking()
+        .map(exit_with)
+}
+
+/// Reports how a script ended and exits with its code.
+fn exit_with(report: ExecutionReport) {
+    if let Some(error) = &report.error {
+        eprintln!("{error}");
+    }
+    if report.exit_code != 0 {
+        std::process::exit(report.exit_code);
+    }
 }
 
 fn main() -> Result<()> {
+    if let Some(archive) = StandaloneArchive::from_current_exe()? {
+        return run_standalone(archive);
+    }
+
     let cli = Cli::parse();
+    if cli.trace_resolution {
+        install_resolution_tracing();
+    }
     let sandboxed = cli.sandboxed();
 
     match cli.command {
@@ -104,31 +322,171 @@ fn main() -> Result<()> {
             workspace,
             resources,
             verbose,
-        }) => build_runtime(
+            watch,
+            watch_paths,
+            args,
+        }) => {
+            let runtime = runtime_builder(
+                workspace,
+                resources,
+                verbose,
+                sandboxed,
+                cli.allow_http_imports,
+                &cli.env,
+            )?
+            .args(args)
+            .build()?;
+            if watch {
+                watch::run(&runtime, file, watch_paths)
+            } else {
+                runtime.run_file_blocking(file).map(exit_with)
+            }
+        }
+        Some(Command::Info {
+            file,
+            workspace,
+            json,
+        }) => {
+            let runtime = runtime_builder(
+                workspace,
+                None,
+                false,
+                sandboxed,
+                cli.allow_http_imports,
+                &cli.env,
+            )?
+            .build()?;
+            info::print(&runtime.module_graph(file)?, json)
+        }
+        Some(Command::Compile {
+            file,
+            output,
+            workspace,
+            include,
+        }) => {
+            let runtime = runtime_builder(
+                workspace,
+                None,
+                false,
+                sandboxed,
+                cli.allow_http_imports,
+                &cli.env,
+            )?
+            .build()?;
+            let output = output.unwrap_or_else(|| default_executable_name(&file));
+            runtime
+                .compile(&file, CompileOptions { include })?
+                .write_executable(&output)?;
+            eprintln!("Compiled {} to {}", file.display(), output.display());
+            Ok(())
+        }
+        Some(Command::Bundle {
+            file,
+            output,
+            workspace,
+        }) => {
+            let runtime = runtime_builder(
+                workspace,
+                None,
+                false,
+                sandboxed,
+                cli.allow_http_imports,
+                &cli.env,
+            )?
+            .build()?;
+            let bundle = runtime.bundle(&file)?;
+            let mut map_path = output.clone().into_os_string();
+            map_path.push(".map");
+            let map_path = PathBuf::from(map_path);
+            let map_name = map_path.file_name().unwrap_or_default().to_string_lossy();
+            std::fs::write(
+                &output,
+                format!("{}//# sourceMappingURL={map_name}\n", bundle.code),
+            )?;
+            std::fs::write(&map_path, bundle.source_map)?;
+            eprintln!("Bundled {} to {}", file.display(), output.display());
+            Ok(())
+        }
+        Some(Command::Test {
+            paths,
+            filter,
+            reporter,
+            jobs,
+            shard,
+            timeout,
+            file_timeout,
+            update_snapshots,
             workspace,
             resources,
-            verbose,
-            sandboxed,
-            cli.allow_http_imports,
-        )?
-        .run_file_blocking(file),
-        Some(Command::Repl {
+        }) => {
+            let runtime = runtime_builder(
+                workspace,
+                resources,
+                false,
+                sandboxed,
+                cli.allow_http_imports,
+                &cli.env,
+            )?
+            .build()?;
+            let options = TestOptions {
+                filter,
+                jobs,
+                shard,
+                test_timeout: timeout.map(Duration::from_millis),
+                file_timeout: file_timeout.map(Duration::from_millis),
+                update_snapshots,
+            };
+            if !test::run(&runtime, paths, &options, reporter)? {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        Some(Command::Bench {
+            paths,
+            filter,
+            json,
             workspace,
             resources,
-            verbose,
-        }) => repl::run(build_runtime(
+        }) => {
+            let runtime = runtime_builder(
+                workspace,
+                resources,
+                false,
+                sandboxed,
+                cli.allow_http_imports,
+                &cli.env,
+            )?
+            .build()?;
+            if !bench::run(&runtime, paths, &BenchOptions { filter }, json)? {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        Some(Command::Repl {
             workspace,
             resources,
             verbose,
-            sandboxed,
-            cli.allow_http_imports,
-        )?),
-        None => repl::run(build_runtime(
-            None,
-            None,
-            false,
-            sandboxed,
-            cli.allow_http_imports,
-        )?),
+        }) => repl::run(
+            runtime_builder(
+                workspace,
+                resources,
+                verbose,
+                sandboxed,
+                cli.allow_http_imports,
+                &cli.env,
+            )?
+            .build()?,
+        ),
+        None => repl::run(
+            runtime_builder(
+                None,
+                None,
+                false,
+                sandboxed,
+                cli.allow_http_imports,
+                &cli.env,
+            )?
+            .build()?,
+        ),
     }
 }
@@ -47,8 +47,12 @@ pub fn run(runtime: Tanxium) -> Result<()> {
         buffer.clear();
         let path = std::env::temp_dir().join(format!("tanxium-repl-{}.ts", std::process::id()));
         std::fs::write(&path, &source)?;
-        if let Err(error) = runtime.run_file_blocking(&path) {
-            eprintln!("{error:#}");
+        let error = match runtime.run_file_blocking(&path) {
+            Ok(report) => report.error,
+            Err(error) => Some(format!("{error:#}")),
+        };
+        if let Some(error) = error {
+            eprintln!("{error}");
             source.truncate(source.len().saturating_sub(submission_len));
         }
     }
@@ -0,0 +1,302 @@
+//! `tanxium test`: runs test files and reports their results.
+
+use anyhow::Result;
+use clap::ValueEnum;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use tanxium::{
+    Tanxium, TestFileReport, TestOptions, TestOutcome, TestReport, TestResult, discover_test_files,
+};
+
+/// Output format of `tanxium test`.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum Reporter {
+    /// Human-readable results, printed as each file finishes.
+    #[default]
+    Pretty,
+    /// Test Anything Protocol, version 13.
+    Tap,
+    /// JUnit XML, for CI systems.
+    Junit,
+    /// The full report as JSON.
+    Json,
+}
+
+/// Runs the tests found in `paths` and returns whether all of them passed.
+pub fn run(
+    runtime: &Tanxium,
+    paths: Vec<PathBuf>,
+    options: &TestOptions,
+    reporter: Reporter,
+) -> Result<bool> {
+    let paths = if paths.is_empty() {
+        vec![PathBuf::from(".")]
+    } else {
+        paths
+    };
+    let files = discover_test_files(&paths)?;
+    if files.is_empty() {
+        anyhow::bail!("No test files found");
+    }
+
+    let report = runtime.test(&files, options, |file| {
+        if let Reporter::Pretty = reporter {
+            print_pretty_file(file);
+        }
+    })?;
+
+    match reporter {
+        Reporter::Pretty => print_pretty_summary(&report),
+        Reporter::Tap => print!("{}", tap(&report)),
+        Reporter::Junit => print!("{}", junit(&report)),
+        Reporter::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+    }
+    Ok(report.success())
+}
+
+fn display_path(path: &Path) -> String {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    path.strip_prefix(&cwd)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+fn print_pretty_file(file: &TestFileReport) {
+    println!(
+        "{} ({})",
+        display_path(&file.path),
+        format_duration(file.duration)
+    );
+    for result in &file.results {
+        match result.result {
+            TestOutcome::Pass => println!(
+                "  ✓ {} ({})",
+                result.full_name(),
+                format_duration(result.duration)
+            ),
+            TestOutcome::Skip => println!("  - {} (skipped)", result.full_name()),
+            TestOutcome::Fail => {
+                println!(
+                    "  ✗ {} ({})",
+                    result.full_name(),
+                    format_duration(result.duration)
+                );
+                if let Some(error) = &result.error {
+                    println!("{}", indent(error, "      "));
+                }
+            }
+        }
+    }
+    if let Some(error) = &file.error {
+        println!("  ✗ {}", indent(error, "    ").trim_start());
+    }
+    for name in &file.snapshots.obsolete {
+        println!("  ! obsolete snapshot \"{name}\"");
+    }
+    println!();
+}
+
+fn print_pretty_summary(report: &TestReport) {
+    let errors = report
+        .files
+        .iter()
+        .filter(|file| file.error.is_some())
+        .count();
+    let mut summary = format!(
+        "{} passed, {} failed, {} skipped",
+        report.count(TestOutcome::Pass),
+        report.count(TestOutcome::Fail),
+        report.count(TestOutcome::Skip)
+    );
+    if errors > 0 {
+        let _ = write!(summary, ", {errors} file errors");
+    }
+    println!("{summary} ({})", format_duration(report.duration));
+
+    let (mut added, mut updated, mut obsolete) = (0, 0, 0);
+    for file in &report.files {
+        added += file.snapshots.added;
+        updated += file.snapshots.updated;
+        obsolete += file.snapshots.obsolete.len();
+    }
+    if added + updated + obsolete > 0 {
+        let mut summary = format!("Snapshots: {added} written, {updated} updated");
+        if obsolete > 0 {
+            let _ = write!(
+                summary,
+                ", {obsolete} obsolete (run with --update-snapshots to remove)"
+            );
+        }
+        println!("{summary}");
+    }
+}
+
+fn tap(report: &TestReport) -> String {
+    let mut output = String::from("TAP version 13\n");
+    let mut number = 0;
+    for file in &report.files {
+        let path = display_path(&file.path);
+        let _ = writeln!(output, "# {path}");
+        for result in &file.results {
+            number += 1;
+            let name = tap_escape(&result.full_name());
+            match result.result {
+                TestOutcome::Pass => {
+                    let _ = writeln!(output, "ok {number} - {name}");
+                }
+                TestOutcome::Skip => {
+                    let _ = writeln!(output, "ok {number} - {name} # SKIP");
+                }
+                TestOutcome::Fail => {
+                    let _ = writeln!(output, "not ok {number} - {name}");
+                    tap_diagnostic(&mut output, result.error.as_deref());
+                }
+            }
+        }
+        if let Some(error) = &file.error {
+            number += 1;
+            let _ = writeln!(output, "not ok {number} - {}", tap_escape(&path));
+            tap_diagnostic(&mut output, Some(error));
+        }
+    }
+    let _ = writeln!(output, "1..{number}");
+    output
+}
+
+fn tap_escape(name: &str) -> String {
+    strip_ansi(name).replace('\\', "\\\\").replace('#', "\\#")
+}
+
+fn tap_diagnostic(output: &mut String, error: Option<&str>) {
+    let Some(error) = error else {
+        return;
+    };
+    output.push_str("  ---\n  message: |-\n");
+    output.push_str(&indent(&strip_ansi(error), "    "));
+    output.push_str("\n  ...\n");
+}
+
+fn junit(report: &TestReport) -> String {
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let errors = report
+        .files
+        .iter()
+        .filter(|file| file.error.is_some())
+        .count();
+    let _ = writeln!(
+        output,
+        "<testsuites name=\"tanxium test\" tests=\"{}\" failures=\"{}\" errors=\"{errors}\" skipped=\"{}\" time=\"{:.3}\">",
+        report
+            .files
+            .iter()
+            .map(|file| file.results.len())
+            .sum::<usize>(),
+        report.count(TestOutcome::Fail),
+        report.count(TestOutcome::Skip),
+        report.duration / 1000.0
+    );
+    for file in &report.files {
+        let path = xml_escape(&display_path(&file.path));
+        let _ = writeln!(
+            output,
+            "  <testsuite name=\"{path}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+            file.results.len(),
+            file.count(TestOutcome::Fail),
+            usize::from(file.error.is_some()),
+            file.count(TestOutcome::Skip),
+            file.duration / 1000.0
+        );
+        for result in &file.results {
+            junit_test_case(&mut output, &path, result);
+        }
+        if let Some(error) = &file.error {
+            let _ = writeln!(
+                output,
+                "    <testcase name=\"{path}\" classname=\"{path}\" time=\"0\">\n      <error message=\"{}\">{}</error>\n    </testcase>",
+                xml_escape(error.lines().next().unwrap_or_default()),
+                xml_escape(error)
+            );
+        }
+        output.push_str("  </testsuite>\n");
+    }
+    output.push_str("</testsuites>\n");
+    output
+}
+
+fn junit_test_case(output: &mut String, path: &str, result: &TestResult) {
+    let classname = std::iter::once(path.to_string())
+        .chain(result.suite.iter().map(|suite| xml_escape(suite)))
+        .collect::<Vec<_>>()
+        .join(" &gt; ");
+    let _ = write!(
+        output,
+        "    <testcase name=\"{}\" classname=\"{classname}\" time=\"{:.3}\"",
+        xml_escape(&result.test),
+        result.duration / 1000.0
+    );
+    match (result.result, &result.error) {
+        (TestOutcome::Pass, _) => output.push_str("/>\n"),
+        (TestOutcome::Skip, _) => output.push_str(">\n      <skipped/>\n    </testcase>\n"),
+        (TestOutcome::Fail, error) => {
+            let error = error.as_deref().unwrap_or("Test failed");
+            let _ = writeln!(
+                output,
+                ">\n      <failure message=\"{}\">{}</failure>\n    </testcase>",
+                xml_escape(error.lines().next().unwrap_or_default()),
+                xml_escape(error)
+            );
+        }
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in strip_ansi(text).chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            // XML 1.0 cannot represent other control characters at all.
+            c if c.is_control() => escaped.push(char::REPLACEMENT_CHARACTER),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Removes ANSI escape sequences, such as the colors of assertion diffs,
+/// from text written to machine-readable reports.
+fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            stripped.push(c);
+            continue;
+        }
+        // A control sequence ends at its final byte; other escapes are a
+        // single character long.
+        if chars.next() == Some('[') {
+            chars.by_ref().find(|c| ('\x40'..='\x7e').contains(c));
+        }
+    }
+    stripped
+}
+
+fn indent(text: &str, prefix: &str) -> String {
+    text.lines()
+        .map(|line| format!("{prefix}{line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_duration(milliseconds: f64) -> String {
+    if milliseconds < 1000.0 {
+        format!("{milliseconds:.0}ms")
+    } else {
+        format!("{:.2}s", milliseconds / 1000.0)
+    }
+}
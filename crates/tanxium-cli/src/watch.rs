@@ -0,0 +1,47 @@
+//! `tanxium run --watch`: restarts the script when its files change.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use tanxium::{Tanxium, WatchOptions};
+
+/// Runs `file` until the watcher fails, printing a banner on each restart
+/// and the error of a script that failed.
+pub fn run(runtime: &Tanxium, file: PathBuf, paths: Vec<String>) -> Result<()> {
+    eprintln!("Watching {} for changes...", file.display());
+    let options = WatchOptions {
+        paths,
+        ..Default::default()
+    };
+
+    runtime.run_file_watching(
+        &file,
+        options,
+        |changed| {
+            eprintln!();
+            eprintln!("━━ {} changed, restarting ━━", describe(changed));
+            eprintln!();
+        },
+        |report| {
+            if let Some(error) = &report.error {
+                eprintln!("{error}");
+                eprintln!("━━ Waiting for changes before restarting ━━");
+            }
+        },
+    )
+}
+
+fn describe(changed: &[PathBuf]) -> String {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let name = |path: &Path| {
+        path.strip_prefix(&cwd)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+
+    match changed {
+        [] => "files".to_string(),
+        [path] => name(path),
+        [path, rest @ ..] => format!("{} and {} more", name(path), rest.len()),
+    }
+}
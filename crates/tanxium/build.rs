@@ -1,9 +1,13 @@
 #![allow(dead_code)]
 
+#[path = "src/auth_tokens.rs"]
+mod auth_tokens;
 #[path = "src/ops.rs"]
 mod ops;
 #[path = "src/state.rs"]
 mod state;
+#[path = "src/test_snapshots.rs"]
+mod test_snapshots;
 #[path = "src/types.rs"]
 mod types;
 
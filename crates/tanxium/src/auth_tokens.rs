@@ -0,0 +1,200 @@
+//! Per-host credentials for authenticated remote imports.
+//!
+//! Tokens use Deno's `DENO_AUTH_TOKENS` format: a semicolon-separated list of
+//! `token@host` (bearer) or `username:password@host` (basic) entries, where
+//! `host` may include a port.
+
+use std::fmt;
+
+use base64::Engine;
+use deno_core::ModuleSpecifier;
+
+#[derive(Clone, Eq, PartialEq)]
+enum Credentials {
+    Bearer(String),
+    Basic { username: String, password: String },
+}
+
+#[derive(Clone, Eq, PartialEq)]
+struct AuthToken {
+    host: String,
+    port: Option<u16>,
+    credentials: Credentials,
+}
+
+impl AuthToken {
+    /// Matches the exact host and its subdomains. A port in the entry must
+    /// match the URL's effective port.
+    fn matches(&self, url: &ModuleSpecifier) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        let host = host.to_ascii_lowercase();
+        let host_matches = host == self.host
+            || host
+                .strip_suffix(&self.host)
+                .is_some_and(|prefix| prefix.ends_with('.'));
+
+        host_matches
+            && self
+                .port
+                .is_none_or(|port| url.port_or_known_default() == Some(port))
+    }
+
+    fn header_value(&self) -> String {
+        match &self.credentials {
+            Credentials::Bearer(token) => format!("Bearer {token}"),
+            Credentials::Basic { username, password } => format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(format!("{username}:{password}"))
+            ),
+        }
+    }
+}
+
+/// Credentials attached to remote module, source-map, and existence-check
+/// requests. The set is write-only from JavaScript and never rendered by
+/// `Debug`, so tokens cannot leak into logs or scripts.
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct AuthTokens(Vec<AuthToken>);
+
+impl AuthTokens {
+    /// Parses a `DENO_AUTH_TOKENS`-style string. Malformed entries are
+    /// ignored.
+    pub fn parse(value: &str) -> Self {
+        Self(
+            value
+                .split(';')
+                .map(str::trim)
+                .filter_map(parse_entry)
+                .collect(),
+        )
+    }
+
+    /// Returns whether no credentials are configured.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the `Authorization` header value for a URL, if any entry
+    /// matches its host. Credentials are only sent over HTTPS or to loopback
+    /// hosts.
+    pub(crate) fn authorization_for(&self, url: &ModuleSpecifier) -> Option<String> {
+        if url.scheme() != "https" && !is_loopback(url) {
+            return None;
+        }
+
+        self.0
+            .iter()
+            .find(|token| token.matches(url))
+            .map(AuthToken::header_value)
+    }
+}
+
+impl fmt::Debug for AuthTokens {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_list()
+            .entries(self.0.iter().map(|token| match token.port {
+                Some(port) => format!("{}:{port}", token.host),
+                None => token.host.clone(),
+            }))
+            .finish()
+    }
+}
+
+fn parse_entry(entry: &str) -> Option<AuthToken> {
+    let (credentials, host) = entry.rsplit_once('@')?;
+    // An IPv6 host such as `[::1]` only has a port after its closing bracket.
+    let port_separator = match host.rfind(']') {
+        Some(end) => host[end..].find(':').map(|index| end + index),
+        None => host.rfind(':'),
+    };
+    let (host, port) = match port_separator {
+        Some(index) => (&host[..index], Some(host[index + 1..].parse().ok()?)),
+        None => (host, None),
+    };
+    if host.is_empty() || credentials.is_empty() {
+        return None;
+    }
+
+    let credentials = match credentials.split_once(':') {
+        Some((username, password)) => Credentials::Basic {
+            username: username.to_string(),
+            password: password.to_string(),
+        },
+        None => Credentials::Bearer(credentials.to_string()),
+    };
+
+    Some(AuthToken {
+        host: host.to_ascii_lowercase(),
+        port,
+        credentials,
+    })
+}
+
+fn is_loopback(url: &ModuleSpecifier) -> bool {
+    matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AuthTokens;
+    use deno_core::ModuleSpecifier;
+
+    fn url(value: &str) -> ModuleSpecifier {
+        ModuleSpecifier::parse(value).unwrap()
+    }
+
+    #[test]
+    fn matches_bearer_and_basic_tokens_by_host_and_port() {
+        let tokens = AuthTokens::parse("abc123@scripts.example.com;ci:s3cret@registry.local:8443");
+
+        assert_eq!(
+            tokens.authorization_for(&url("https://scripts.example.com/mod.ts")),
+            Some("Bearer abc123".to_string())
+        );
+        assert_eq!(
+            tokens.authorization_for(&url("https://cdn.scripts.example.com/mod.ts")),
+            Some("Bearer abc123".to_string())
+        );
+        assert_eq!(
+            tokens.authorization_for(&url("https://registry.local:8443/mod.ts")),
+            Some("Basic Y2k6czNjcmV0".to_string())
+        );
+        assert_eq!(
+            tokens.authorization_for(&url("https://registry.local/mod.ts")),
+            None
+        );
+        assert_eq!(
+            tokens.authorization_for(&url("https://notscripts.example.com/mod.ts")),
+            None
+        );
+    }
+
+    #[test]
+    fn never_sends_tokens_over_plain_http_or_renders_them() {
+        let tokens = AuthTokens::parse("abc123@scripts.example.com");
+
+        assert_eq!(
+            tokens.authorization_for(&url("http://scripts.example.com/mod.ts")),
+            None
+        );
+        assert!(!format!("{tokens:?}").contains("abc123"));
+    }
+
+    #[test]
+    fn keeps_ipv6_hosts_whole_when_splitting_ports() {
+        let tokens = AuthTokens::parse("abc123@[::1]:8080;def456@[::1]");
+
+        assert_eq!(
+            tokens.authorization_for(&url("http://[::1]:8080/mod.ts")),
+            Some("Bearer abc123".to_string())
+        );
+        assert_eq!(
+            tokens.authorization_for(&url("http://[::1]:9000/mod.ts")),
+            Some("Bearer def456".to_string())
+        );
+        assert_eq!(format!("{tokens:?}"), "[\"[::1]:8080\", \"[::1]\"]");
+    }
+}
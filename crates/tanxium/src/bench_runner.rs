@@ -0,0 +1,211 @@
+//! Benchmark files run through [`crate::Tanxium::bench`].
+//!
+//! Like test files, each file is imported by a generated entry module, here
+//! inside `runBench` from `yasumu:bench`, in a fresh main worker. The entry
+//! posts each benchmark's result back as a renderer message.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::time::Instant;
+
+use base64::Engine;
+use deno_runtime::deno_core::{ModuleSpecifier, error::AnyError};
+use serde::{Deserialize, Serialize};
+
+use crate::standalone::StandaloneArchive;
+use crate::state::{RuntimeHost, RuntimeState};
+use crate::test_runner::{self, MessageHost, TestOutcome};
+use crate::worker::{WorkerRole, WorkerShutdown, start_worker};
+
+const BENCH_SUFFIXES: [&str; 2] = [".bench", "_bench"];
+
+/// Configures [`crate::Tanxium::bench`].
+#[derive(Clone, Debug, Default)]
+pub struct BenchOptions {
+    /// Only run benchmarks whose name contains this string. A value wrapped
+    /// in slashes, such as `/^sign/`, is a regular expression.
+    pub filter: Option<String>,
+}
+
+/// A benchmark as measured by `yasumu:bench`. Times are per call, in
+/// nanoseconds.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchResult {
+    pub name: String,
+    pub result: TestOutcome,
+    /// The error thrown by a failed benchmark.
+    pub error: Option<String>,
+    /// Timed calls across all samples.
+    pub iterations: u64,
+    pub samples: u64,
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+    pub p50: f64,
+    pub p99: f64,
+    pub ops_per_second: f64,
+}
+
+/// The benchmarks of one file.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchFileReport {
+    pub path: PathBuf,
+    pub results: Vec<BenchResult>,
+    /// Why the file failed outside of a benchmark, for example because it
+    /// could not be imported or threw an uncaught error.
+    pub error: Option<String>,
+    /// In milliseconds, including worker startup.
+    pub duration: f64,
+}
+
+impl BenchFileReport {
+    pub fn success(&self) -> bool {
+        self.error.is_none()
+            && self
+                .results
+                .iter()
+                .all(|result| result.result != TestOutcome::Fail)
+    }
+}
+
+/// The benchmarks of every file in a run.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchReport {
+    pub files: Vec<BenchFileReport>,
+    /// In milliseconds.
+    pub duration: f64,
+}
+
+impl BenchReport {
+    /// Whether every file loaded and no benchmark threw.
+    pub fn success(&self) -> bool {
+        self.files.iter().all(BenchFileReport::success)
+    }
+}
+
+/// Finds benchmark files: `*.bench.ts`, `*_bench.ts`, and their JavaScript
+/// and JSX equivalents, below each directory in `paths`, the same way as
+/// [`crate::discover_test_files`].
+pub fn discover_bench_files(paths: &[PathBuf]) -> Result<Vec<PathBuf>, AnyError> {
+    test_runner::discover_files(paths, is_bench_file)
+}
+
+fn is_bench_file(name: &str) -> bool {
+    test_runner::has_source_suffix(name, &BENCH_SUFFIXES)
+}
+
+/// Builds the entry module that runs one benchmark file and reports back.
+fn entry_module(module: &ModuleSpecifier, options: &BenchOptions) -> Result<String, AnyError> {
+    Ok(format!(
+        r#"import {{ runBench }} from "yasumu:bench";
+const errors = [];
+const onError = (event) => {{
+  event.preventDefault();
+  const error = "reason" in event ? event.reason : event.error;
+  errors.push(String(error?.stack ?? error));
+}};
+globalThis.addEventListener("error", onError);
+globalThis.addEventListener("unhandledrejection", onError);
+try {{
+  await runBench(() => import({module}), {{
+    filter: {filter},
+    onBenchFinish: (result) => Yasumu.postMessage({{ type: "tanxium:bench-finish", result }}),
+  }});
+  Yasumu.postMessage({{ type: "tanxium:bench-done", errors }});
+}} catch (error) {{
+  Yasumu.postMessage({{ type: "tanxium:bench-done", error: String(error?.stack ?? error), errors }});
+}}
+"#,
+        module = serde_json::to_string(module.as_str())?,
+        filter = test_runner::filter_expression(options.filter.as_deref())?,
+    ))
+}
+
+/// Progress posted by the entry module.
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum RunnerMessage {
+    #[serde(rename = "tanxium:bench-finish")]
+    Finish { result: BenchResult },
+    #[serde(rename = "tanxium:bench-done")]
+    Done {
+        error: Option<String>,
+        #[serde(default)]
+        errors: Vec<String>,
+    },
+}
+
+/// Runs one benchmark file in a fresh worker and waits for it to finish.
+pub(crate) fn run_bench_file(
+    state: &Arc<RuntimeState>,
+    host: &Arc<dyn RuntimeHost>,
+    main_worker_all_permissions: bool,
+    standalone: Option<Arc<StandaloneArchive>>,
+    path: &Path,
+    module: &ModuleSpecifier,
+    options: &BenchOptions,
+) -> Result<BenchFileReport, AnyError> {
+    let started = Instant::now();
+    let entry = base64::engine::general_purpose::STANDARD.encode(entry_module(module, options)?);
+    let entry = ModuleSpecifier::parse(&format!("data:text/javascript;base64,{entry}"))?;
+
+    let (messages, receiver) = mpsc::channel();
+    let bench_host = Arc::new(MessageHost {
+        inner: host.clone(),
+        messages,
+    });
+    let shutdown = WorkerShutdown::default();
+    let worker = start_worker(
+        entry,
+        state.clone(),
+        bench_host,
+        main_worker_all_permissions,
+        shutdown.clone(),
+        standalone,
+        WorkerRole::Bench,
+    )?;
+
+    let mut results = Vec::new();
+    // The sender is dropped with the worker, so an exit without a report
+    // ends the loop.
+    let error = loop {
+        match receiver.recv() {
+            Ok(RunnerMessage::Finish { result }) => results.push(result),
+            Ok(RunnerMessage::Done { error, errors }) => {
+                break error.or_else(|| {
+                    (!errors.is_empty()).then(|| format!("Uncaught error:\n{}", errors.join("\n")))
+                });
+            }
+            Err(_) => break Some("The benchmark file exited before reporting its results".into()),
+        }
+    };
+
+    shutdown.request();
+    worker
+        .join()
+        .map_err(|_| AnyError::msg("runtime thread panicked"))?;
+
+    Ok(BenchFileReport {
+        path: path.to_path_buf(),
+        results,
+        error,
+        duration: started.elapsed().as_secs_f64() * 1000.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_bench_file;
+
+    #[test]
+    fn recognizes_bench_file_names() {
+        assert!(is_bench_file("sign.bench.ts"));
+        assert!(is_bench_file("sign_bench.js"));
+        assert!(!is_bench_file("sign.test.ts"));
+        assert!(!is_bench_file("bench.ts"));
+    }
+}
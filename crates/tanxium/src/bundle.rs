@@ -0,0 +1,747 @@
+//! Single-file ES module bundles of an entrypoint's module graph.
+//!
+//! Every local, package, and remote module is inlined as an async factory
+//! in a registry, keyed by its path relative to the entrypoint. Import and
+//! export declarations are rewritten in place so each module keeps the
+//! line layout of its transpiled output, which lets the bundle's source map
+//! be an index map with one section per module. References to imported
+//! bindings become reads of the exporting module's exports, which keeps
+//! them live. `yasumu:`, `node:`, and virtual modules stay external.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use base64::Engine;
+use deno_ast::swc::ast::{
+    CallExpr, Callee, Decl, DefaultDecl, ExportSpecifier, Expr, Id, Ident, ImportSpecifier, Lit,
+    ModuleDecl, ModuleExportName, ModuleItem, ObjectPatProp, Pat, Program, Prop, TaggedTpl,
+};
+use deno_ast::swc::ecma_visit::{Visit, VisitWith};
+use deno_ast::{MediaType, ModuleSpecifier, ParseParams, SourceRangedForSpanned};
+use deno_runtime::deno_core::error::AnyError;
+use serde_json::json;
+
+use crate::module_graph::{self, ModuleKind, string_value};
+use crate::standalone::EmbeddedKind;
+use crate::state::RuntimeState;
+
+/// A bundled entrypoint.
+#[derive(Clone, Debug)]
+pub struct Bundle {
+    /// The ES module source.
+    pub code: String,
+    /// An index source map with a section for each transpiled module.
+    pub source_map: String,
+}
+
+const RUNTIME: &str = r#"const __tanxium_cache = new Map();
+function __tanxium_import(id) {
+  let module = __tanxium_cache.get(id);
+  if (!module) {
+    module = { exports: Object.create(null), done: false };
+    __tanxium_cache.set(id, module);
+    module.promise = __tanxium_modules[id](module.exports).then(() => {
+      module.done = true;
+      return module.exports;
+    });
+    return module.promise;
+  }
+  // A module that is still evaluating was imported through a cycle.
+  return module.done ? module.promise : Promise.resolve(module.exports);
+}
+function __tanxium_export(exports, getters) {
+  for (const [name, get] of Object.entries(getters)) {
+    Object.defineProperty(exports, name, { enumerable: true, get });
+  }
+}
+function __tanxium_reexport(exports, source) {
+  for (const name of Object.keys(source)) {
+    if (name !== "default" && !Object.hasOwn(exports, name)) {
+      Object.defineProperty(exports, name, { enumerable: true, get: () => source[name] });
+    }
+  }
+}
+"#;
+
+/// Where an import resolves within the bundle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Target {
+    /// A module inlined under this id.
+    Inlined(String),
+    /// A module imported at runtime from this specifier.
+    External(String),
+}
+
+/// A module's source after its imports and exports were rewritten.
+#[derive(Debug, Default)]
+struct Rewritten {
+    /// Statements run before the body: imports and export bindings. Kept
+    /// on a single line.
+    prologue: String,
+    /// The module's code with the original line layout.
+    body: String,
+    exports: BTreeSet<String>,
+    /// Inlined modules whose exports are re-exported with `export *`.
+    star_exports: Vec<String>,
+}
+
+/// Bundles `root` and everything it imports.
+pub(crate) fn bundle(state: &Arc<RuntimeState>, root: ModuleSpecifier) -> Result<Bundle, AnyError> {
+    let loader = module_graph::loader(state);
+    let graph = module_graph::build(state, root.clone());
+
+    let id_of = |specifier: &str| match ModuleSpecifier::parse(specifier) {
+        Ok(url) if url.scheme() == "file" => match root.make_relative(&url) {
+            Some(relative) if relative.starts_with("../") => relative,
+            Some(relative) if relative.is_empty() => root
+                .path_segments()
+                .and_then(|mut segments| segments.next_back())
+                .map(|name| format!("./{name}"))
+                .unwrap_or(relative),
+            Some(relative) => format!("./{relative}"),
+            None => specifier.to_string(),
+        },
+        _ => specifier.to_string(),
+    };
+    let is_inlined = |kind: ModuleKind| {
+        matches!(
+            kind,
+            ModuleKind::Local | ModuleKind::Package | ModuleKind::Remote
+        )
+    };
+
+    let mut externals = Vec::<(String, Option<String>)>::new();
+    let mut rewritten = Vec::new();
+    for module in &graph.modules {
+        if let Some(error) = &module.error {
+            return Err(AnyError::msg(format!(
+                "Unable to bundle {}: {error}",
+                module.specifier
+            )));
+        }
+        if !is_inlined(module.kind) {
+            continue;
+        }
+
+        let mut targets = HashMap::new();
+        for dependency in &module.dependencies {
+            let resolved = match (&dependency.resolved, &dependency.error) {
+                (Some(resolved), _) => resolved,
+                (None, Some(error)) if !dependency.dynamic => {
+                    return Err(AnyError::msg(format!(
+                        "Unable to resolve {} from {}: {error}",
+                        dependency.specifier, module.specifier
+                    )));
+                }
+                (None, _) => continue,
+            };
+            let target = match graph.get(resolved) {
+                Some(target) if is_inlined(target.kind) => Target::Inlined(id_of(resolved)),
+                _ => {
+                    let external = (resolved.clone(), dependency.import_type.clone());
+                    if !externals.contains(&external) {
+                        externals.push(external);
+                    }
+                    Target::External(resolved.clone())
+                }
+            };
+            targets.insert(dependency.specifier.clone(), target);
+        }
+
+        let specifier = ModuleSpecifier::parse(&module.specifier)?;
+        let embedded = loader
+            .embed_module(
+                &specifier,
+                matches!(module.media_type.as_deref(), Some("text" | "bytes")),
+            )
+            .map_err(|error| AnyError::msg(format!("Unable to bundle {specifier}: {error}")))?;
+        if embedded.common_js {
+            tracing::warn!(
+                "Bundling CommonJS module {specifier}: its require() calls still load files from disk"
+            );
+        }
+        let module_rewritten = match embedded.kind {
+            EmbeddedKind::JavaScript => {
+                let code = String::from_utf8(embedded.source)?;
+                rewrite_module(&specifier, code, &targets, |external| {
+                    external_binding(&externals, external)
+                })
+                .map_err(|error| AnyError::msg(format!("Unable to bundle {specifier}: {error}")))?
+            }
+            EmbeddedKind::Json | EmbeddedKind::Data => {
+                let json = serde_json::to_string(&String::from_utf8(embedded.source)?)?;
+                default_export(format!("JSON.parse({json})"))
+            }
+            EmbeddedKind::Raw if module.media_type.as_deref() == Some("text") => {
+                let text = serde_json::to_string(&String::from_utf8(embedded.source)?)?;
+                default_export(text)
+            }
+            EmbeddedKind::Raw => {
+                let encoded = base64::engine::general_purpose::STANDARD.encode(&embedded.source);
+                default_export(format!(
+                    "Uint8Array.from(atob(\"{encoded}\"), (c) => c.charCodeAt(0))"
+                ))
+            }
+            EmbeddedKind::Wasm => {
+                return Err(AnyError::msg(format!(
+                    "WebAssembly module {specifier} cannot be bundled"
+                )));
+            }
+        };
+        rewritten.push((
+            id_of(&module.specifier),
+            module_rewritten,
+            embedded.source_map,
+        ));
+    }
+
+    let mut code = String::new();
+    for (index, (specifier, import_type)) in externals.iter().enumerate() {
+        let specifier = serde_json::to_string(specifier)?;
+        match import_type {
+            Some(import_type) => writeln!(
+                code,
+                "import * as __tanxium_external_{index} from {specifier} with {{ type: {} }};",
+                serde_json::to_string(import_type)?
+            )?,
+            None => writeln!(
+                code,
+                "import * as __tanxium_external_{index} from {specifier};"
+            )?,
+        }
+    }
+    code.push_str("const __tanxium_modules = {\n");
+
+    let mut sections = Vec::new();
+    for (id, module, source_map) in &rewritten {
+        writeln!(
+            code,
+            "{}: async (__tanxium_exports) => {{ {}",
+            serde_json::to_string(id)?,
+            module.prologue
+        )?;
+        if let Some(source_map) = source_map {
+            let map = serde_json::from_slice::<serde_json::Value>(source_map)?;
+            let line = code.matches('\n').count();
+            sections.push(json!({ "offset": { "line": line, "column": 0 }, "map": map }));
+        }
+        code.push_str(&module.body);
+        code.push_str("\n},\n");
+    }
+    code.push_str("};\n");
+    code.push_str(RUNTIME);
+
+    let root_id = id_of(&graph.root);
+    writeln!(
+        code,
+        "const __tanxium_entry = await __tanxium_import({});",
+        serde_json::to_string(&root_id)?
+    )?;
+    for (index, name) in entry_exports(&root_id, &rewritten).iter().enumerate() {
+        let name = serde_json::to_string(name)?;
+        writeln!(
+            code,
+            "const __tanxium_export_{index} = __tanxium_entry[{name}];\nexport {{ __tanxium_export_{index} as {name} }};"
+        )?;
+    }
+    // Star re-exports of external modules cannot be listed statically.
+    if let Some((_, root, _)) = rewritten.iter().find(|(id, _, _)| *id == root_id) {
+        for (index, external) in externals.iter().enumerate() {
+            if root
+                .star_exports
+                .contains(&format!("__tanxium_external_{index}"))
+            {
+                writeln!(
+                    code,
+                    "export * from {};",
+                    serde_json::to_string(&external.0)?
+                )?;
+            }
+        }
+    }
+
+    let source_map = json!({ "version": 3, "sections": sections }).to_string();
+    Ok(Bundle { code, source_map })
+}
+
+fn external_binding(externals: &[(String, Option<String>)], specifier: &str) -> String {
+    let index = externals
+        .iter()
+        .position(|(external, _)| external == specifier)
+        .unwrap_or_default();
+    format!("__tanxium_external_{index}")
+}
+
+fn default_export(value: String) -> Rewritten {
+    Rewritten {
+        prologue: "__tanxium_export(__tanxium_exports, { default: () => __tanxium_default });"
+            .to_string(),
+        body: format!("const __tanxium_default = {value};"),
+        exports: BTreeSet::from(["default".to_string()]),
+        star_exports: Vec::new(),
+    }
+}
+
+/// Lists the export names of the entrypoint, following `export *` through
+/// inlined modules.
+fn entry_exports(
+    root_id: &str,
+    modules: &[(String, Rewritten, Option<Vec<u8>>)],
+) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    let mut visited = BTreeSet::new();
+    let mut pending = vec![(root_id.to_string(), true)];
+    while let Some((id, is_root)) = pending.pop() {
+        if !visited.insert(id.clone()) {
+            continue;
+        }
+        let Some((_, module, _)) = modules.iter().find(|(module_id, _, _)| *module_id == id) else {
+            continue;
+        };
+        names.extend(
+            module
+                .exports
+                .iter()
+                .filter(|name| is_root || *name != "default")
+                .cloned(),
+        );
+        pending.extend(
+            module
+                .star_exports
+                .iter()
+                .map(|source| (source.clone(), false)),
+        );
+    }
+    names
+}
+
+/// Rewrites a transpiled ES module into the body of a bundle factory.
+fn rewrite_module(
+    specifier: &ModuleSpecifier,
+    code: String,
+    targets: &HashMap<String, Target>,
+    external_binding: impl Fn(&str) -> String,
+) -> Result<Rewritten, String> {
+    let parsed = deno_ast::parse_module(ParseParams {
+        specifier: specifier.clone(),
+        text: code.clone().into(),
+        media_type: MediaType::JavaScript,
+        capture_tokens: false,
+        scope_analysis: true,
+        maybe_syntax: None,
+    })
+    .map_err(|error| error.to_string())?;
+    let start = parsed.text_info_lazy().range().start;
+    let program = parsed.program();
+    let Program::Module(module) = &*program else {
+        return Err("expected an ES module".to_string());
+    };
+
+    let mut rewritten = Rewritten::default();
+    let mut imports = Vec::new();
+    let mut getters = Vec::new();
+    let mut edits = Vec::new();
+    let mut bindings = HashMap::new();
+    let mut imported = HashMap::new();
+    let mut binding_of = |source: &str, imports: &mut Vec<String>| -> Result<String, String> {
+        let target = targets
+            .get(source)
+            .ok_or_else(|| format!("unresolved import {source}"))?;
+        Ok(match target {
+            Target::External(external) => external_binding(external),
+            Target::Inlined(id) => {
+                if let Some(binding) = bindings.get(id) {
+                    return Ok(binding.clone());
+                }
+                let binding = format!("__tanxium_dep_{}", bindings.len());
+                imports.push(format!(
+                    "const {binding} = await __tanxium_import({});",
+                    serde_json::to_string(id).unwrap()
+                ));
+                bindings.insert(id.clone(), binding.clone());
+                binding
+            }
+        })
+    };
+
+    for item in &module.body {
+        let ModuleItem::ModuleDecl(decl) = item else {
+            continue;
+        };
+        let range = decl.range().as_byte_range(start);
+        match decl {
+            ModuleDecl::Import(import) => {
+                let source = string_value(&import.src).unwrap_or_default();
+                let binding = binding_of(&source, &mut imports)?;
+                for specifier in &import.specifiers {
+                    match specifier {
+                        ImportSpecifier::Default(default) => {
+                            imported.insert(default.local.to_id(), format!("{binding}.default"));
+                        }
+                        ImportSpecifier::Namespace(namespace) => {
+                            imports.push(format!("const {} = {binding};", namespace.local.sym));
+                        }
+                        ImportSpecifier::Named(import) => {
+                            let name = import
+                                .imported
+                                .as_ref()
+                                .map_or_else(|| import.local.sym.to_string(), export_name);
+                            imported.insert(
+                                import.local.to_id(),
+                                format!("{binding}[{}]", serde_json::to_string(&name).unwrap()),
+                            );
+                        }
+                    }
+                }
+                edits.push((range, String::new()));
+            }
+            ModuleDecl::ExportAll(export) => {
+                let source = string_value(&export.src).unwrap_or_default();
+                let binding = binding_of(&source, &mut imports)?;
+                imports.push(format!("__tanxium_reexport(__tanxium_exports, {binding});"));
+                rewritten.star_exports.push(match targets.get(&source) {
+                    Some(Target::Inlined(id)) => id.clone(),
+                    _ => binding,
+                });
+                edits.push((range, String::new()));
+            }
+            ModuleDecl::ExportNamed(export) => {
+                let source = match &export.src {
+                    Some(source) => Some(binding_of(
+                        &string_value(source).unwrap_or_default(),
+                        &mut imports,
+                    )?),
+                    None => None,
+                };
+                for specifier in &export.specifiers {
+                    let (exported, local) = match specifier {
+                        ExportSpecifier::Named(named) => {
+                            let orig = export_name(&named.orig);
+                            let exported =
+                                named.exported.as_ref().map_or(orig.clone(), export_name);
+                            let local = match (&source, &named.orig) {
+                                (Some(binding), _) => {
+                                    format!("{binding}[{}]", serde_json::to_string(&orig).unwrap())
+                                }
+                                (None, ModuleExportName::Ident(ident)) => {
+                                    imported.get(&ident.to_id()).cloned().unwrap_or(orig)
+                                }
+                                (None, ModuleExportName::Str(_)) => orig,
+                            };
+                            (exported, local)
+                        }
+                        ExportSpecifier::Namespace(namespace) => {
+                            let Some(binding) = &source else { continue };
+                            (export_name(&namespace.name), binding.clone())
+                        }
+                        ExportSpecifier::Default(default) => {
+                            let Some(binding) = &source else { continue };
+                            (
+                                default.exported.sym.to_string(),
+                                format!("{binding}.default"),
+                            )
+                        }
+                    };
+                    getters.push((exported, local));
+                }
+                edits.push((range, String::new()));
+            }
+            ModuleDecl::ExportDecl(export) => {
+                let mut names = Vec::new();
+                match &export.decl {
+                    Decl::Var(var) => {
+                        for declarator in &var.decls {
+                            pattern_names(&declarator.name, &mut names);
+                        }
+                    }
+                    Decl::Fn(function) => names.push(function.ident.sym.to_string()),
+                    Decl::Class(class) => names.push(class.ident.sym.to_string()),
+                    _ => {}
+                }
+                getters.extend(names.into_iter().map(|name| (name.clone(), name)));
+                let keyword = export.decl.range().as_byte_range(start).start;
+                edits.push((range.start..keyword, String::new()));
+            }
+            ModuleDecl::ExportDefaultDecl(export) => {
+                let (ident, inner) = match &export.decl {
+                    DefaultDecl::Fn(function) => (
+                        function.ident.as_ref().map(|ident| ident.sym.to_string()),
+                        function.range(),
+                    ),
+                    DefaultDecl::Class(class) => (
+                        class.ident.as_ref().map(|ident| ident.sym.to_string()),
+                        class.range(),
+                    ),
+                    DefaultDecl::TsInterfaceDecl(_) => continue,
+                };
+                let inner = inner.as_byte_range(start).start;
+                match ident {
+                    Some(ident) => {
+                        getters.push(("default".to_string(), ident));
+                        edits.push((range.start..inner, String::new()));
+                    }
+                    None => {
+                        getters.push(("default".to_string(), "__tanxium_default".to_string()));
+                        edits.push((range.start..inner, "const __tanxium_default = ".to_string()));
+                    }
+                }
+            }
+            ModuleDecl::ExportDefaultExpr(export) => {
+                let inner = export.expr.range().as_byte_range(start).start;
+                getters.push(("default".to_string(), "__tanxium_default".to_string()));
+                edits.push((range.start..inner, "const __tanxium_default = ".to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    let mut references = ImportReferences {
+        imported: &imported,
+        edits: Vec::new(),
+    };
+    program.visit_with(&mut references);
+    edits.extend(
+        references
+            .edits
+            .into_iter()
+            .map(|(range, replacement)| (range.as_byte_range(start), replacement)),
+    );
+
+    let mut dynamic_imports = DynamicImports::default();
+    program.visit_with(&mut dynamic_imports);
+    for (call, source) in dynamic_imports.calls {
+        if let Some(Target::Inlined(id)) = targets.get(&source) {
+            edits.push((
+                call.as_byte_range(start),
+                format!("__tanxium_import({})", serde_json::to_string(id).unwrap()),
+            ));
+        }
+    }
+
+    // Replaced text keeps its line breaks so the source map stays accurate.
+    edits.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+    let mut body = code;
+    for (range, replacement) in edits {
+        let newlines = body[range.clone()].matches('\n').count();
+        body.replace_range(range, &format!("{replacement}{}", "\n".repeat(newlines)));
+    }
+
+    rewritten.exports = getters.iter().map(|(name, _)| name.clone()).collect();
+    let getters = getters
+        .iter()
+        .map(|(name, local)| format!("{}: () => {local}", serde_json::to_string(name).unwrap()))
+        .collect::<Vec<_>>();
+    rewritten.prologue = format!(
+        "__tanxium_export(__tanxium_exports, {{ {} }}); {}",
+        getters.join(", "),
+        imports.join(" ")
+    );
+    rewritten.body = body;
+    Ok(rewritten)
+}
+
+fn export_name(name: &ModuleExportName) -> String {
+    match name {
+        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+        ModuleExportName::Str(name) => string_value(name).unwrap_or_default(),
+    }
+}
+
+/// Collects the names bound by a declaration pattern.
+fn pattern_names(pattern: &Pat, names: &mut Vec<String>) {
+    match pattern {
+        Pat::Ident(ident) => names.push(ident.id.sym.to_string()),
+        Pat::Array(array) => {
+            for element in array.elems.iter().flatten() {
+                pattern_names(element, names);
+            }
+        }
+        Pat::Object(object) => {
+            for property in &object.props {
+                match property {
+                    ObjectPatProp::KeyValue(property) => pattern_names(&property.value, names),
+                    ObjectPatProp::Assign(property) => names.push(property.key.sym.to_string()),
+                    ObjectPatProp::Rest(rest) => pattern_names(&rest.arg, names),
+                }
+            }
+        }
+        Pat::Rest(rest) => pattern_names(&rest.arg, names),
+        Pat::Assign(assign) => pattern_names(&assign.left, names),
+        _ => {}
+    }
+}
+
+/// Rewrites references to imported bindings into reads of the exporting
+/// module, so they stay live and can be used by modules in an import cycle
+/// once the binding is initialized, as with ES module imports.
+struct ImportReferences<'a> {
+    /// The expression reading each imported binding.
+    imported: &'a HashMap<Id, String>,
+    edits: Vec<(deno_ast::SourceRange, String)>,
+}
+
+impl ImportReferences<'_> {
+    /// Rewrites a function called through an import so it is not called
+    /// with the exporting module as `this`.
+    fn visit_called(&mut self, callee: &Expr) {
+        if let Expr::Ident(ident) = callee
+            && let Some(access) = self.imported.get(&ident.to_id())
+        {
+            self.edits.push((ident.range(), format!("(0, {access})")));
+        } else {
+            callee.visit_with(self);
+        }
+    }
+}
+
+impl Visit for ImportReferences<'_> {
+    fn visit_module_item(&mut self, node: &ModuleItem) {
+        // Import declarations and local `export { .. }` lists are removed.
+        if let ModuleItem::ModuleDecl(ModuleDecl::Import(_) | ModuleDecl::ExportNamed(_)) = node {
+            return;
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, node: &Ident) {
+        if let Some(access) = self.imported.get(&node.to_id()) {
+            self.edits.push((node.range(), access.clone()));
+        }
+    }
+
+    fn visit_prop(&mut self, node: &Prop) {
+        if let Prop::Shorthand(ident) = node
+            && let Some(access) = self.imported.get(&ident.to_id())
+        {
+            self.edits
+                .push((ident.range(), format!("{}: {access}", ident.sym)));
+            return;
+        }
+        node.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        match &node.callee {
+            Callee::Expr(callee) => {
+                self.visit_called(callee);
+                node.args.visit_with(self);
+            }
+            _ => node.visit_children_with(self),
+        }
+    }
+
+    fn visit_tagged_tpl(&mut self, node: &TaggedTpl) {
+        self.visit_called(&node.tag);
+        node.tpl.visit_with(self);
+    }
+}
+
+/// Finds `import()` calls with a literal specifier.
+#[derive(Default)]
+struct DynamicImports {
+    calls: Vec<(deno_ast::SourceRange, String)>,
+}
+
+impl Visit for DynamicImports {
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        if let Callee::Import(_) = node.callee
+            && let Some(Expr::Lit(Lit::Str(source))) = node.args.first().map(|arg| &*arg.expr)
+            && let Some(source) = string_value(source)
+        {
+            self.calls.push((node.range(), source));
+        }
+
+        node.visit_children_with(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Target, rewrite_module};
+    use deno_ast::ModuleSpecifier;
+    use std::collections::HashMap;
+
+    #[test]
+    fn rewrites_imports_and_exports_without_moving_lines() {
+        let specifier = ModuleSpecifier::parse("file:///app/main.js").unwrap();
+        let targets = HashMap::from([
+            (
+                "./lib.js".to_string(),
+                Target::Inlined("./lib.js".to_string()),
+            ),
+            (
+                "yasumu:test".to_string(),
+                Target::External("yasumu:test".to_string()),
+            ),
+        ]);
+        let code = "import { helper as run } from \"./lib.js\";\nimport {\n  test\n} from \"yasumu:test\";\nexport const answer = run();\nexport default test;\nexport * from \"./lib.js\";\nconst lazy = () => import(\"./lib.js\");\n";
+
+        let rewritten = rewrite_module(&specifier, code.to_string(), &targets, |_| {
+            "__tanxium_external_0".to_string()
+        })
+        .unwrap();
+
+        assert_eq!(rewritten.body.lines().count(), code.lines().count());
+        assert!(
+            rewritten
+                .body
+                .contains("const answer = (0, __tanxium_dep_0[\"helper\"])();")
+        );
+        assert!(
+            rewritten
+                .body
+                .contains("const __tanxium_default = __tanxium_external_0[\"test\"];")
+        );
+        assert!(
+            rewritten
+                .body
+                .contains("() => __tanxium_import(\"./lib.js\")")
+        );
+        assert!(!rewritten.body.contains("import {"));
+        assert!(
+            rewritten
+                .prologue
+                .contains("const __tanxium_dep_0 = await __tanxium_import(\"./lib.js\");")
+        );
+        assert_eq!(
+            rewritten.exports.iter().collect::<Vec<_>>(),
+            ["answer", "default"]
+        );
+        assert_eq!(rewritten.star_exports, ["./lib.js"]);
+    }
+
+    #[test]
+    fn keeps_imported_bindings_live() {
+        let specifier = ModuleSpecifier::parse("file:///app/main.js").unwrap();
+        let targets = HashMap::from([(
+            "./counter.js".to_string(),
+            Target::Inlined("./counter.js".to_string()),
+        )]);
+        let code = "import { count, increment } from \"./counter.js\";\nfunction read(count) { return count; }\nexport const state = { count };\nincrement();\nexport { count as current };\n";
+
+        let rewritten =
+            rewrite_module(&specifier, code.to_string(), &targets, |_| unreachable!()).unwrap();
+
+        assert!(
+            rewritten
+                .body
+                .contains("function read(count) { return count; }")
+        );
+        assert!(
+            rewritten
+                .body
+                .contains("const state = { count: __tanxium_dep_0[\"count\"] };")
+        );
+        assert!(
+            rewritten
+                .body
+                .contains("(0, __tanxium_dep_0[\"increment\"])();")
+        );
+        assert!(
+            rewritten
+                .prologue
+                .contains("\"current\": () => __tanxium_dep_0[\"count\"]")
+        );
+    }
+}
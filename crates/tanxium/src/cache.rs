@@ -0,0 +1,116 @@
+//! Location of the on-disk cache shared by registry packages and remote
+//! modules.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use crate::state::RuntimeContext;
+
+/// How long registry metadata is reused before it is fetched again.
+const METADATA_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Resolves the cache root: the builder setting, then `TANXIUM_DIR`, then the
+/// host's app data directory, then the platform cache directory.
+pub(crate) fn cache_root(context: &RuntimeContext) -> PathBuf {
+    if let Some(dir) = &context.cache_dir {
+        return dir.clone();
+    }
+
+    if let Some(dir) = std::env::var_os("TANXIUM_DIR").filter(|dir| !dir.is_empty()) {
+        return PathBuf::from(dir);
+    }
+
+    if let Some(dir) = &context.app_data_dir {
+        return dir.join("cache");
+    }
+
+    platform_cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("tanxium")
+}
+
+fn platform_cache_dir() -> Option<PathBuf> {
+    let var = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+
+    if cfg!(windows) {
+        var("LOCALAPPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| PathBuf::from(home).join("Library").join("Caches"))
+    } else {
+        var("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".cache")))
+    }
+}
+
+/// Registry metadata kept in memory, keyed by URL. Entries expire after a
+/// few minutes, so repeated resolutions skip the network while a
+/// long-running host still sees newly published versions.
+pub(crate) struct MetadataCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, Arc<Value>)>>,
+}
+
+impl Default for MetadataCache {
+    fn default() -> Self {
+        Self::with_ttl(METADATA_TTL)
+    }
+}
+
+impl MetadataCache {
+    fn with_ttl(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Default::default(),
+        }
+    }
+
+    pub fn get(&self, url: &str) -> Option<Arc<Value>> {
+        let entries = self.entries.lock().expect("metadata cache lock poisoned");
+        entries
+            .get(url)
+            .filter(|(fetched, _)| fetched.elapsed() < self.ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Stores a document and drops the expired ones.
+    pub fn insert(&self, url: String, value: Arc<Value>) {
+        let mut entries = self.entries.lock().expect("metadata cache lock poisoned");
+        entries.retain(|_, (fetched, _)| fetched.elapsed() < self.ttl);
+        entries.insert(url, (Instant::now(), value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MetadataCache;
+    use serde_json::json;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn expires_metadata_after_its_ttl() {
+        let fresh = MetadataCache::with_ttl(Duration::from_secs(60));
+        fresh.insert(
+            "https://registry.example/pkg".to_string(),
+            Arc::new(json!({})),
+        );
+        assert!(fresh.get("https://registry.example/pkg").is_some());
+
+        let expired = MetadataCache::with_ttl(Duration::ZERO);
+        expired.insert(
+            "https://registry.example/pkg".to_string(),
+            Arc::new(json!({})),
+        );
+        assert!(expired.get("https://registry.example/pkg").is_none());
+        expired.insert(
+            "https://registry.example/other".to_string(),
+            Arc::new(json!({})),
+        );
+        assert_eq!(expired.entries.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,273 @@
+//! CommonJS interop for modules imported from ESM.
+//!
+//! Named exports are discovered with `deno_ast`'s port of Node's
+//! `cjs-module-lexer`, so Tanxium exposes the same names Node would without
+//! evaluating the module during loading.
+
+use std::path::{Path, PathBuf};
+
+use deno_ast::{MediaType, ModuleSpecifier, ParseParams};
+use node_resolver::PackageJsonResolver;
+use node_resolver::errors::PackageJsonLoadError;
+use sys_traits::impls::RealSys;
+
+/// Extensions tried, in order, when a relative `require()` omits one.
+const REQUIRE_EXTENSIONS: [&str; 3] = ["js", "cjs", "json"];
+
+/// How a local JavaScript or TypeScript file is evaluated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ModuleFormat {
+    Esm,
+    Cjs,
+}
+
+/// Determines a file's format from its extension and the `"type"` of its
+/// closest `package.json`, read through the shared resolver cache.
+///
+/// `.cjs`/`.cts` are always CommonJS and every other extension except `.js`
+/// is always ESM. A `.js` file outside any package is ESM; inside a package
+/// without a `"type"` its format depends on its syntax, so `None` is
+/// returned and the caller falls back to [`detect_format`].
+pub(crate) fn format_from_path(
+    pkg_json_resolver: &PackageJsonResolver<RealSys>,
+    path: &Path,
+) -> Result<Option<ModuleFormat>, PackageJsonLoadError> {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("cjs" | "cts") => return Ok(Some(ModuleFormat::Cjs)),
+        Some("js") => {}
+        _ => return Ok(Some(ModuleFormat::Esm)),
+    }
+
+    let Some(package_json) = pkg_json_resolver.get_closest_package_json(path)? else {
+        return Ok(Some(ModuleFormat::Esm));
+    };
+    Ok(match package_json.typ.as_str() {
+        "module" => Some(ModuleFormat::Esm),
+        "commonjs" => Some(ModuleFormat::Cjs),
+        _ => None,
+    })
+}
+
+/// Detects the format of a `.js` file whose package does not declare one:
+/// sources with `import` or `export` declarations are ESM and anything else
+/// is CommonJS, matching Node's syntax detection.
+pub(crate) fn detect_format(specifier: &ModuleSpecifier, code: &str) -> ModuleFormat {
+    let parsed = deno_ast::parse_program(ParseParams {
+        specifier: specifier.clone(),
+        text: code.into(),
+        media_type: MediaType::JavaScript,
+        capture_tokens: false,
+        scope_analysis: false,
+        maybe_syntax: None,
+    });
+
+    match parsed {
+        Ok(parsed) if !parsed.compute_is_script() => ModuleFormat::Esm,
+        _ => ModuleFormat::Cjs,
+    }
+}
+
+/// Statically detectable exports of a CommonJS module.
+#[derive(Debug, Default)]
+pub(crate) struct CjsAnalysis {
+    /// Property names assigned on `exports` or `module.exports`.
+    pub exports: Vec<String>,
+    /// Specifiers passed to `require()` whose exports are re-exported, for
+    /// example through `module.exports = require(...)` or `__exportStar`.
+    pub reexports: Vec<String>,
+}
+
+/// Analyzes a CommonJS module. Sources that fail to parse have no
+/// detectable exports; the module still loads and exposes `default`.
+pub(crate) fn analyze(specifier: &ModuleSpecifier, code: &str) -> CjsAnalysis {
+    let parsed = deno_ast::parse_program(ParseParams {
+        specifier: specifier.clone(),
+        text: code.into(),
+        media_type: match MediaType::from_specifier(specifier) {
+            MediaType::Cts => MediaType::Cts,
+            _ => MediaType::Cjs,
+        },
+        capture_tokens: true,
+        scope_analysis: false,
+        maybe_syntax: None,
+    });
+
+    match parsed {
+        Ok(parsed) => {
+            let analysis = parsed.analyze_cjs();
+            CjsAnalysis {
+                exports: analysis.exports,
+                reexports: analysis.reexports,
+            }
+        }
+        Err(error) => {
+            tracing::debug!("Unable to analyze CommonJS exports of {specifier}: {error}");
+            CjsAnalysis::default()
+        }
+    }
+}
+
+/// Resolves a relative `require()` target the way Node does: the exact
+/// path, then known extensions, then the directory's `main` or `index`.
+pub(crate) fn resolve_relative_require(path: &Path) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path.to_path_buf());
+    }
+
+    let with_extension = |path: &Path| {
+        REQUIRE_EXTENSIONS.iter().find_map(|extension| {
+            let mut candidate = path.as_os_str().to_owned();
+            candidate.push(".");
+            candidate.push(extension);
+            let candidate = PathBuf::from(candidate);
+            candidate.is_file().then_some(candidate)
+        })
+    };
+    if let Some(candidate) = with_extension(path) {
+        return Some(candidate);
+    }
+
+    if !path.is_dir() {
+        return None;
+    }
+
+    let main = std::fs::read_to_string(path.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|package| package.get("main")?.as_str().map(str::to_owned));
+    if let Some(resolved) = main.and_then(|main| {
+        let main = path.join(main);
+        if main.is_file() {
+            Some(main)
+        } else {
+            with_extension(&main).or_else(|| with_extension(&main.join("index")))
+        }
+    }) {
+        return Some(resolved);
+    }
+
+    with_extension(&path.join("index"))
+}
+
+/// Wraps a CommonJS module as ESM: `module.exports` becomes the default
+/// export and every detected name becomes a named export.
+pub(crate) fn wrap_module(code: String, path: &Path, exports: &[String]) -> String {
+    let filename = serde_json::to_string(&path.to_string_lossy()).unwrap();
+    let dirname = serde_json::to_string(&path.parent().unwrap_or(path).to_string_lossy()).unwrap();
+    // Export names are not necessarily identifiers, so each one is bound to
+    // a generated local and exported under a string name.
+    let named_exports = exports
+        .iter()
+        .filter(|name| *name != "default")
+        .enumerate()
+        .map(|(index, name)| {
+            let name = serde_json::to_string(name).unwrap();
+            format!(
+                "const __tanxium_cjs_export_{index} = module.exports[{name}];\n\
+                 export {{ __tanxium_cjs_export_{index} as {name} }};"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        r#"import "node:process";
+import {{ createRequire }} from "node:module";
+const require = createRequire(import.meta.url);
+const module = {{ exports: {{}} }};
+const exports = module.exports;
+const __filename = {filename};
+const __dirname = {dirname};
+(function (require, module, exports, __filename, __dirname) {{
+{code}
+}})(require, module, exports, __filename, __dirname);
+export default module.exports;
+{named_exports}"#,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ModuleFormat, analyze, detect_format, wrap_module};
+    use deno_ast::ModuleSpecifier;
+    use std::path::Path;
+
+    fn analyze_source(code: &str) -> super::CjsAnalysis {
+        let specifier = ModuleSpecifier::parse("file:///pkg/index.js").unwrap();
+        analyze(&specifier, code)
+    }
+
+    #[test]
+    fn detects_object_literal_define_property_and_assignment_exports() {
+        let analysis = analyze_source(
+            r#"
+            // exports.commented = 1;
+            const text = "exports.quoted = 2";
+            exports.answer = 42;
+            Object.defineProperty(exports, "defined", { value: 1 });
+            module.exports = { a, b: 2, answer };
+            "#,
+        );
+
+        for name in ["a", "b", "answer", "defined"] {
+            assert!(
+                analysis.exports.iter().any(|export| export == name),
+                "{name}"
+            );
+        }
+        assert!(!analysis.exports.iter().any(|export| export == "commented"));
+        assert!(!analysis.exports.iter().any(|export| export == "quoted"));
+    }
+
+    #[test]
+    fn detects_transpiler_reexports() {
+        let analysis = analyze_source(
+            r#"
+            "use strict";
+            Object.defineProperty(exports, "__esModule", { value: true });
+            __exportStar(require("./client"), exports);
+            module.exports = require("./server");
+            "#,
+        );
+
+        for specifier in ["./client", "./server"] {
+            assert!(
+                analysis
+                    .reexports
+                    .iter()
+                    .any(|reexport| reexport == specifier)
+            );
+        }
+    }
+
+    #[test]
+    fn detects_esm_syntax_in_untyped_packages() {
+        let specifier = ModuleSpecifier::parse("file:///pkg/index.js").unwrap();
+
+        for code in [
+            "export const answer = 42;",
+            "import value from './value.js'; console.log(value);",
+        ] {
+            assert_eq!(detect_format(&specifier, code), ModuleFormat::Esm, "{code}");
+        }
+        for code in [
+            "module.exports = { answer: 42 };",
+            "const value = require('./value'); // import x from 'y'",
+            "this is not javascript",
+        ] {
+            assert_eq!(detect_format(&specifier, code), ModuleFormat::Cjs, "{code}");
+        }
+    }
+
+    #[test]
+    fn exports_non_identifier_names_as_strings() {
+        let wrapped = wrap_module(
+            String::new(),
+            Path::new("/pkg/index.js"),
+            &["default".to_string(), "kebab-name".to_string()],
+        );
+
+        assert!(wrapped.contains(r#"export { __tanxium_cjs_export_0 as "kebab-name" };"#));
+        assert!(!wrapped.contains(r#"as "default""#));
+    }
+}
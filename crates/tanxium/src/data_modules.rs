@@ -0,0 +1,224 @@
+//! YAML, TOML, JSONC, and JSON5 files imported as JSON modules.
+//!
+//! Files are parsed in Rust and handed to V8 as JSON text, so scripts get
+//! the same plain objects a `.json` import would produce.
+
+use std::path::Path;
+
+use serde_json::Value;
+
+/// A structured data format recognised by file extension.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum DataFormat {
+    Yaml,
+    Toml,
+    Jsonc,
+    Json5,
+}
+
+impl DataFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "yaml" | "yml" => Some(Self::Yaml),
+            "toml" => Some(Self::Toml),
+            "jsonc" => Some(Self::Jsonc),
+            "json5" => Some(Self::Json5),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Yaml => "YAML",
+            Self::Toml => "TOML",
+            Self::Jsonc => "JSONC",
+            Self::Json5 => "JSON5",
+        }
+    }
+}
+
+/// A parse failure, with the 1-based line and column it was reported at.
+struct ParseFailure {
+    message: String,
+    location: Option<(usize, usize)>,
+}
+
+/// Parses a data file into JSON text. `origin` names the file in errors,
+/// which quote the offending line.
+pub(crate) fn to_json(format: DataFormat, source: &str, origin: &str) -> Result<String, String> {
+    let value = match format {
+        DataFormat::Yaml => parse_yaml(source),
+        DataFormat::Toml => parse_toml(source),
+        DataFormat::Jsonc => parse_jsonc(source),
+        DataFormat::Json5 => parse_json5(source),
+    };
+
+    match value {
+        Ok(value) => Ok(value.to_string()),
+        Err(failure) => Err(describe_failure(format, failure, source, origin)),
+    }
+}
+
+fn parse_yaml(source: &str) -> Result<Value, ParseFailure> {
+    serde_yaml_ng::from_str::<Value>(source).map_err(|error| {
+        let location = error
+            .location()
+            .map(|location| (location.line(), location.column()));
+        let message = error.to_string();
+        // serde_yaml_ng appends the location to its message; it is reported
+        // separately here.
+        let message = match message.rsplit_once(" at line ") {
+            Some((message, _)) if location.is_some() => message.to_string(),
+            _ => message,
+        };
+        ParseFailure { message, location }
+    })
+}
+
+fn parse_toml(source: &str) -> Result<Value, ParseFailure> {
+    toml::from_str::<toml::Value>(source)
+        .map(toml_to_json)
+        .map_err(|error| ParseFailure {
+            message: error.message().to_string(),
+            location: error.span().map(|span| line_and_column(source, span.start)),
+        })
+}
+
+fn parse_jsonc(source: &str) -> Result<Value, ParseFailure> {
+    jsonc_parser::parse_to_serde_value(source, &Default::default())
+        .map(|value| value.unwrap_or(Value::Null))
+        .map_err(|error| ParseFailure {
+            message: error.kind().to_string(),
+            location: Some((error.line_display(), error.column_display())),
+        })
+}
+
+fn parse_json5(source: &str) -> Result<Value, ParseFailure> {
+    json5::from_str::<Value>(source).map_err(|json5::Error::Message { msg, location }| {
+        // Syntax errors embed their own snippet of the source before the
+        // expectation; only the expectation is kept.
+        let message = match msg.rsplit_once("\n  = ") {
+            Some((_, message)) => message.to_string(),
+            None => msg,
+        };
+        ParseFailure {
+            message,
+            location: location.map(|location| (location.line, location.column)),
+        }
+    })
+}
+
+/// TOML dates and times have no JSON equivalent and become strings.
+fn toml_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(value) => Value::String(value),
+        toml::Value::Integer(value) => Value::from(value),
+        toml::Value::Float(value) => {
+            serde_json::Number::from_f64(value).map_or(Value::Null, Value::Number)
+        }
+        toml::Value::Boolean(value) => Value::Bool(value),
+        toml::Value::Datetime(value) => Value::String(value.to_string()),
+        toml::Value::Array(values) => Value::Array(values.into_iter().map(toml_to_json).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset.min(source.len())];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+fn describe_failure(
+    format: DataFormat,
+    failure: ParseFailure,
+    source: &str,
+    origin: &str,
+) -> String {
+    let name = format.name();
+    let Some((line, column)) = failure.location else {
+        return format!("Invalid {name} in {origin}: {}", failure.message);
+    };
+
+    let text = source
+        .lines()
+        .nth(line.saturating_sub(1))
+        .unwrap_or_default();
+    let gutter = " ".repeat(line.to_string().len());
+    let caret = " ".repeat(column.saturating_sub(1));
+    format!(
+        "Invalid {name} in {origin} at line {line}, column {column}: {}\n\
+         {line} | {text}\n\
+         {gutter} | {caret}^",
+        failure.message
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DataFormat, to_json};
+    use serde_json::{Value, json};
+
+    fn parse(format: DataFormat, source: &str) -> Value {
+        serde_json::from_str(&to_json(format, source, "fixture").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn converts_each_format_to_json() {
+        assert_eq!(
+            parse(DataFormat::Yaml, "name: api\nports:\n  - 80\n  - 443\n"),
+            json!({ "name": "api", "ports": [80, 443] })
+        );
+        assert_eq!(
+            parse(
+                DataFormat::Toml,
+                "[server]\nhost = \"localhost\"\nstarted = 1979-05-27T07:32:00Z\n"
+            ),
+            json!({ "server": { "host": "localhost", "started": "1979-05-27T07:32:00Z" } })
+        );
+        assert_eq!(
+            parse(
+                DataFormat::Json5,
+                "{\n  // comment\n  name: 'api',\n  retries: 0x3,\n  ratio: .5,\n  note: 'a\\\nb',\n}"
+            ),
+            json!({ "name": "api", "retries": 3, "ratio": 0.5, "note": "ab" })
+        );
+    }
+
+    #[test]
+    fn errors_quote_the_offending_line() {
+        let error = to_json(
+            DataFormat::Toml,
+            "title = \"ok\"\nport = = 80\n",
+            "config.toml",
+        )
+        .unwrap_err();
+
+        assert!(
+            error.starts_with("Invalid TOML in config.toml at line 2, column"),
+            "{error}"
+        );
+        assert!(error.contains("2 | port = = 80"), "{error}");
+
+        let error = to_json(DataFormat::Yaml, "a: 1\nb: [1, 2\n", "data.yaml").unwrap_err();
+        assert!(
+            error.starts_with("Invalid YAML in data.yaml at line"),
+            "{error}"
+        );
+
+        let error = to_json(DataFormat::Json5, "{\n  a: 1,,\n}", "data.json5").unwrap_err();
+        assert_eq!(
+            error,
+            "Invalid JSON5 in data.json5 at line 2, column 8: expected identifier or string\n\
+             2 |   a: 1,,\n  |        ^"
+        );
+    }
+}
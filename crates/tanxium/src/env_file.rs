@@ -0,0 +1,70 @@
+//! `.env` files for [`crate::TanxiumBuilder::envs`].
+
+use std::path::Path;
+
+use deno_runtime::deno_core::error::AnyError;
+use sys_traits::impls::RealSys;
+
+/// Reads the variables of a dotenv file, in order.
+///
+/// The file is parsed by `deno_dotenv`, like Deno's `--env-file`, so
+/// quoting, comments, `export` prefixes, and `${VAR}` expansion behave the
+/// same as in Deno.
+pub fn read_env_file(path: &Path) -> Result<Vec<(String, String)>, AnyError> {
+    let vars = deno_dotenv::from_path_sanitized_iter(&RealSys, path).map_err(|error| {
+        AnyError::msg(format!(
+            "Unable to read env file {}: {error}",
+            path.display()
+        ))
+    })?;
+    vars.map(|var| {
+        var.map_err(|error| AnyError::msg(format!("Invalid env file {}: {error}", path.display())))
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_env_file;
+    use deno_runtime::deno_core::error::AnyError;
+
+    fn read(name: &str, contents: &str) -> Result<Vec<(String, String)>, AnyError> {
+        let path = std::env::temp_dir().join(format!("tanxium-{name}-{}.env", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        let vars = read_env_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        vars
+    }
+
+    #[test]
+    fn parses_dotenv_syntax() {
+        let vars = read(
+            "dotenv-syntax",
+            "# api settings\n\
+             API_URL=https://example.com # production\n\
+             export TOKEN='a#b $c'\n\
+             \n\
+             GREETING=\"hello\\n\\\"world\\\"\"\n\
+             ENDPOINT=\"${API_URL}/v1\"\n\
+             EMPTY=\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            vars,
+            [
+                ("API_URL".to_string(), "https://example.com".to_string()),
+                ("TOKEN".to_string(), "a#b $c".to_string()),
+                ("GREETING".to_string(), "hello\n\"world\"".to_string()),
+                ("ENDPOINT".to_string(), "https://example.com/v1".to_string()),
+                ("EMPTY".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        assert!(read("dotenv-unterminated", "TOKEN=\"unterminated").is_err());
+        assert!(read("dotenv-key", "MY KEY=1").is_err());
+    }
+}
@@ -4,6 +4,7 @@ pub const YASUMU_RUNTIME_API_VERSION: u32 = 1;
 pub const YASUMU_SCRIPT_HOOKS: &[&str] = &["onRequest", "onResponse", "onTest", "onEmail"];
 pub const YASUMU_VIRTUAL_MODULES: &[&str] = &[
     "yasumu:test",
+    "yasumu:bench",
     "yasumu:workspace",
     "yasumu:runtime",
     "yasumu:env",
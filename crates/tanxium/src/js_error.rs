@@ -0,0 +1,191 @@
+//! Formatting of uncaught JavaScript errors for the CLI, runtime events and
+//! web workers.
+
+use std::fmt::Write;
+
+use deno_runtime::deno_core::error::{AnyError, CoreError, CoreErrorKind, JsError, JsStackFrame};
+
+/// Formats an uncaught JavaScript error with the failing source line, a caret
+/// under the failing column, its stack and its `cause` chain.
+///
+/// Frames are already source-mapped by the module loader. Consecutive frames
+/// from Deno and Tanxium internals (`ext:` and `node:` modules) are collapsed
+/// into a single line.
+pub(crate) fn format_js_error(error: &JsError) -> String {
+    let mut out = String::new();
+    render(&mut out, &Report::from(error));
+    out
+}
+
+/// Formats a worker error, using [`format_js_error`] when it was thrown by
+/// JavaScript.
+pub(crate) fn format_error(error: &AnyError) -> String {
+    if let Some(js_error) = error.downcast_ref::<JsError>() {
+        return format_js_error(js_error);
+    }
+    if let Some(core_error) = error.downcast_ref::<CoreError>()
+        && let CoreErrorKind::Js(js_error) = core_error.as_kind()
+    {
+        return format_js_error(js_error);
+    }
+    format!("{error:#}")
+}
+
+struct Report {
+    message: String,
+    source_line: Option<(String, usize)>,
+    frames: Vec<Frame>,
+    cause: Option<Box<Report>>,
+}
+
+struct Frame {
+    location: String,
+    internal: bool,
+}
+
+impl From<&JsError> for Report {
+    fn from(error: &JsError) -> Self {
+        let column = error
+            .source_line_frame_index
+            .and_then(|index| error.frames.get(index))
+            .and_then(|frame| frame.column_number);
+        Self {
+            message: error.exception_message.clone(),
+            source_line: error
+                .source_line
+                .clone()
+                .zip(column)
+                .map(|(line, column)| (line, column.max(1) as usize)),
+            frames: error.frames.iter().map(Frame::from).collect(),
+            cause: error
+                .cause
+                .as_deref()
+                .map(|cause| Box::new(Report::from(cause))),
+        }
+    }
+}
+
+impl From<&JsStackFrame> for Frame {
+    fn from(frame: &JsStackFrame) -> Self {
+        let file = frame.file_name.as_deref().unwrap_or("<anonymous>");
+        let position = match (frame.line_number, frame.column_number) {
+            (Some(line), Some(column)) => format!("{file}:{line}:{column}"),
+            (Some(line), None) => format!("{file}:{line}"),
+            _ => file.to_string(),
+        };
+        let name = match (&frame.type_name, &frame.function_name) {
+            (Some(type_name), Some(function)) if !frame.is_top_level.unwrap_or(true) => {
+                Some(format!("{type_name}.{function}"))
+            }
+            (_, Some(function)) => Some(function.clone()),
+            _ => None,
+        };
+        let prefix = match (frame.is_async, frame.is_constructor) {
+            (true, _) => "async ",
+            (_, true) => "new ",
+            _ => "",
+        };
+        Self {
+            location: match name {
+                Some(name) => format!("{prefix}{name} ({position})"),
+                None => format!("{prefix}{position}"),
+            },
+            internal: file.starts_with("ext:") || file.starts_with("node:"),
+        }
+    }
+}
+
+fn render(out: &mut String, report: &Report) {
+    out.push_str(&report.message);
+    if let Some((line, column)) = &report.source_line {
+        // Drops the indentation and keeps the caret under the same character.
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        let offset = trimmed
+            .char_indices()
+            .take_while(|(index, _)| index + indent + 1 < *column)
+            .count();
+        let _ = write!(out, "\n    {trimmed}\n    {}^", " ".repeat(offset));
+    }
+
+    let mut internal = 0;
+    for frame in &report.frames {
+        if frame.internal {
+            internal += 1;
+            continue;
+        }
+        flush_internal(out, &mut internal);
+        let _ = write!(out, "\n    at {}", frame.location);
+    }
+    flush_internal(out, &mut internal);
+
+    if let Some(cause) = &report.cause {
+        out.push_str("\nCaused by: ");
+        render(out, cause);
+    }
+}
+
+fn flush_internal(out: &mut String, count: &mut usize) {
+    match *count {
+        0 => return,
+        1 => out.push_str("\n    ... 1 internal frame"),
+        n => {
+            let _ = write!(out, "\n    ... {n} internal frames");
+        }
+    }
+    *count = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frame, Report, render};
+
+    fn frame(location: &str, internal: bool) -> Frame {
+        Frame {
+            location: location.to_string(),
+            internal,
+        }
+    }
+
+    #[test]
+    fn renders_source_line_frames_and_causes() {
+        let report = Report {
+            message: "Uncaught Error: request failed".to_string(),
+            source_line: Some(("  throw new Error('request failed');".to_string(), 3)),
+            frames: vec![
+                frame("send (file:///app/main.ts:4:3)", false),
+                frame("ext:core/01_core.js:10:5", true),
+                frame("ext:deno_web/02_timers.js:80:12", true),
+                frame("file:///app/main.ts:9:1", false),
+                frame("node:internal/process:1:1", true),
+            ],
+            cause: Some(Box::new(Report {
+                message: "TypeError: invalid url".to_string(),
+                source_line: None,
+                frames: vec![frame("parse (file:///app/url.ts:2:9)", false)],
+                cause: None,
+            })),
+        };
+
+        let mut out = String::new();
+        render(&mut out, &report);
+        assert_eq!(
+            out,
+            "Uncaught Error: request failed\n    throw new Error('request failed');\n    ^\n    at send (file:///app/main.ts:4:3)\n    ... 2 internal frames\n    at file:///app/main.ts:9:1\n    ... 1 internal frame\nCaused by: TypeError: invalid url\n    at parse (file:///app/url.ts:2:9)"
+        );
+    }
+
+    #[test]
+    fn places_the_caret_under_the_failing_column() {
+        let report = Report {
+            message: "Uncaught ReferenceError: missing is not defined".to_string(),
+            source_line: Some(("\tconst value = missing;".to_string(), 16)),
+            frames: Vec::new(),
+            cause: None,
+        };
+
+        let mut out = String::new();
+        render(&mut out, &report);
+        assert!(out.ends_with("\n    const value = missing;\n                  ^"));
+    }
+}
@@ -0,0 +1,432 @@
+//! `jsr:` specifiers resolved against a JSR registry.
+//!
+//! A specifier is resolved to the HTTPS URL of the exported file, which the
+//! loader then fetches like any remote module. Published versions are
+//! immutable, so version metadata and module sources are served from
+//! `<cache>/remote` once downloaded. Each downloaded file is checked
+//! against the checksum in its version's manifest before it is cached.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+
+use deno_ast::ModuleSpecifier;
+use deno_runtime::deno_core::error::ModuleLoaderError;
+use deno_semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::cache::MetadataCache;
+use crate::remote::{self, FetchPolicy, RemoteResponse};
+
+/// Registry used when neither the host nor `JSR_URL` set one.
+const DEFAULT_REGISTRY: &str = "https://jsr.io/";
+
+/// Package version lists fetched recently, so importing several exports of
+/// a package does not refetch them.
+static PACKAGE_METADATA: LazyLock<MetadataCache> = LazyLock::new(Default::default);
+
+/// A parsed `jsr:@<scope>/<name>[@<range>][/<export>]` specifier.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct JsrSpecifier {
+    pub name: String,
+    pub version_req: Option<String>,
+    pub export: Option<String>,
+}
+
+impl JsrSpecifier {
+    pub fn parse(specifier: &str) -> Option<Self> {
+        let rest = specifier.strip_prefix("jsr:")?;
+        let rest = rest.strip_prefix('/').unwrap_or(rest);
+        if !rest.starts_with('@') {
+            return None;
+        }
+
+        let scope_end = rest.find('/')?;
+        let (package, export) = match rest[scope_end + 1..].find('/') {
+            Some(index) => {
+                let index = scope_end + 1 + index;
+                (&rest[..index], Some(&rest[index + 1..]))
+            }
+            None => (rest, None),
+        };
+        let (name, version_req) = match package[1..].find('@') {
+            Some(index) => (&package[..index + 1], Some(&package[index + 2..])),
+            None => (package, None),
+        };
+        if name.len() <= scope_end + 1 {
+            return None;
+        }
+
+        Some(Self {
+            name: name.to_string(),
+            version_req: version_req.filter(|req| !req.is_empty()).map(str::to_owned),
+            export: export
+                .filter(|export| !export.is_empty())
+                .map(str::to_owned),
+        })
+    }
+}
+
+/// Cached response metadata stored beside a module body.
+#[derive(Deserialize, Serialize)]
+struct CachedModule {
+    url: String,
+    content_type: Option<String>,
+}
+
+/// Resolves `jsr:` specifiers and caches registry modules on disk.
+#[derive(Clone)]
+pub(crate) struct JsrResolver {
+    registry: ModuleSpecifier,
+    cache_dir: PathBuf,
+    policy: FetchPolicy,
+}
+
+impl JsrResolver {
+    /// Creates a resolver. `registry` falls back to `JSR_URL` and then the
+    /// public registry. An explicitly configured `http:` registry is trusted
+    /// even when HTTP imports are disabled.
+    pub fn new(
+        cache_root: &Path,
+        registry: Option<&str>,
+        mut policy: FetchPolicy,
+    ) -> Result<Self, ModuleLoaderError> {
+        let registry = registry
+            .map(str::to_owned)
+            .or_else(|| std::env::var("JSR_URL").ok())
+            .filter(|registry| !registry.is_empty())
+            .unwrap_or_else(|| DEFAULT_REGISTRY.to_string());
+        let registry = if registry.ends_with('/') {
+            registry
+        } else {
+            format!("{registry}/")
+        };
+        let registry = ModuleSpecifier::parse(&registry).map_err(|error| {
+            ModuleLoaderError::type_error(format!("Invalid JSR registry URL {registry}: {error}"))
+        })?;
+        policy.allow_http |= registry.scheme() == "http";
+
+        Ok(Self {
+            cache_dir: cache_root.join("remote"),
+            registry,
+            policy,
+        })
+    }
+
+    /// Returns whether a URL points at a published file in the registry.
+    pub fn is_registry_module(&self, url: &ModuleSpecifier) -> bool {
+        url.as_str()
+            .strip_prefix(self.registry.as_str())
+            .is_some_and(|path| path.starts_with('@'))
+    }
+
+    /// The fetch policy for a remote URL, which admits the registry's own
+    /// scheme.
+    pub fn policy_for(&self, url: &ModuleSpecifier, policy: FetchPolicy) -> FetchPolicy {
+        if self.is_registry_module(url) {
+            self.policy.clone()
+        } else {
+            policy
+        }
+    }
+
+    /// Resolves a specifier to the URL of the file its export points to.
+    pub fn resolve(&self, specifier: &JsrSpecifier) -> Result<ModuleSpecifier, ModuleLoaderError> {
+        let name = &specifier.name;
+        let metadata = self.package_metadata(name)?;
+        let version =
+            select_version(&metadata, specifier.version_req.as_deref()).ok_or_else(|| {
+                ModuleLoaderError::type_error(format!(
+                    "No version of jsr package {name} matches {}",
+                    specifier.version_req.as_deref().unwrap_or("latest")
+                ))
+            })?;
+
+        let version_metadata = self.version_metadata(name, &version)?;
+        let export = match &specifier.export {
+            Some(export) => format!("./{export}"),
+            None => ".".to_string(),
+        };
+        let target = version_metadata["exports"][&export]
+            .as_str()
+            .ok_or_else(|| {
+                ModuleLoaderError::type_error(format!(
+                    "jsr package {name}@{version} does not export {export:?}"
+                ))
+            })?;
+
+        self.registry
+            .join(&format!(
+                "{name}/{version}/{}",
+                target.trim_start_matches("./")
+            ))
+            .map_err(|error| ModuleLoaderError::type_error(error.to_string()))
+    }
+
+    /// Returns a previously downloaded registry module.
+    pub fn cached_module(&self, url: &ModuleSpecifier) -> Option<RemoteResponse> {
+        if !self.is_registry_module(url) {
+            return None;
+        }
+
+        let path = self.module_cache_path(url);
+        let metadata = std::fs::read(path.with_extension("json")).ok()?;
+        let metadata = serde_json::from_slice::<CachedModule>(&metadata).ok()?;
+        Some(RemoteResponse {
+            url: ModuleSpecifier::parse(&metadata.url).ok()?,
+            content_type: metadata.content_type,
+            body: std::fs::read(path).ok()?,
+        })
+    }
+
+    /// Stores a registry module. A published file is first checked against
+    /// the checksum in its version's manifest and rejected on a mismatch.
+    /// Writing is best effort; a failure only means the module is
+    /// downloaded again next time.
+    pub fn cache_module(
+        &self,
+        url: &ModuleSpecifier,
+        response: &RemoteResponse,
+    ) -> Result<(), ModuleLoaderError> {
+        if !self.is_registry_module(url) {
+            return Ok(());
+        }
+
+        if let Some((name, version, file)) = self.registry_file(url) {
+            let metadata = self.version_metadata(name, version)?;
+            let file = urlencoding::decode(file)
+                .map_err(|error| ModuleLoaderError::type_error(error.to_string()))?;
+            verify_checksum(&metadata, &file, &response.body).map_err(|message| {
+                ModuleLoaderError::type_error(format!(
+                    "Integrity check failed for {url} in jsr package {name}@{version}: {message}"
+                ))
+            })?;
+        }
+
+        let path = self.module_cache_path(url);
+        let metadata = CachedModule {
+            url: response.url.to_string(),
+            content_type: response.content_type.clone(),
+        };
+        let result = write_file(&path, &response.body).and_then(|()| {
+            let metadata = serde_json::to_vec(&metadata).map_err(std::io::Error::other)?;
+            write_file(&path.with_extension("json"), &metadata)
+        });
+        if let Err(error) = result {
+            tracing::debug!("Failed to cache {url}: {error}");
+        }
+        Ok(())
+    }
+
+    /// Splits the URL of a published file into its package name, version,
+    /// and path within the package, such as `/mod.ts`. Package and version
+    /// metadata yield `None`.
+    fn registry_file<'a>(&self, url: &'a ModuleSpecifier) -> Option<(&'a str, &'a str, &'a str)> {
+        let path = url.as_str().strip_prefix(self.registry.as_str())?;
+        let path = path.split(['?', '#']).next()?;
+        let mut parts = path.splitn(4, '/');
+        let (scope, name, version, _) =
+            (parts.next()?, parts.next()?, parts.next()?, parts.next()?);
+        let name_end = scope.len() + 1 + name.len();
+        let version_end = name_end + 1 + version.len();
+        Some((&path[..name_end], version, &path[version_end..]))
+    }
+
+    fn module_cache_path(&self, url: &ModuleSpecifier) -> PathBuf {
+        let host = match url.port() {
+            Some(port) => format!("{}_{port}", url.host_str().unwrap_or_default()),
+            None => url.host_str().unwrap_or_default().to_string(),
+        };
+        let hash = hex::encode(Sha256::digest(url.as_str().as_bytes()));
+        self.cache_dir.join(url.scheme()).join(host).join(hash)
+    }
+
+    /// Fetches the version list, falling back to the cached copy when the
+    /// registry is unreachable.
+    fn package_metadata(&self, name: &str) -> Result<Arc<Value>, ModuleLoaderError> {
+        let url = self.registry_url(&format!("{name}/meta.json"))?;
+        if let Some(metadata) = PACKAGE_METADATA.get(url.as_str()) {
+            return Ok(metadata);
+        }
+
+        let response = match remote::fetch(&url, &self.policy) {
+            Ok(response) => {
+                self.cache_module(&url, &response)?;
+                response
+            }
+            Err(error) => self.cached_module(&url).ok_or(error)?,
+        };
+        let metadata = Arc::new(parse_json(&url, &response.body)?);
+        PACKAGE_METADATA.insert(url.to_string(), metadata.clone());
+        Ok(metadata)
+    }
+
+    /// Version metadata never changes once published, so the cached copy is
+    /// used whenever present.
+    fn version_metadata(&self, name: &str, version: &str) -> Result<Value, ModuleLoaderError> {
+        let url = self.registry_url(&format!("{name}/{version}_meta.json"))?;
+        let response = match self.cached_module(&url) {
+            Some(response) => response,
+            None => {
+                let response = remote::fetch(&url, &self.policy)?;
+                self.cache_module(&url, &response)?;
+                response
+            }
+        };
+
+        parse_json(&url, &response.body)
+    }
+
+    fn registry_url(&self, path: &str) -> Result<ModuleSpecifier, ModuleLoaderError> {
+        self.registry
+            .join(path)
+            .map_err(|error| ModuleLoaderError::type_error(error.to_string()))
+    }
+}
+
+/// Picks `latest` when it satisfies the range, otherwise the highest
+/// matching version that has not been yanked.
+fn select_version(metadata: &Value, version_req: Option<&str>) -> Option<String> {
+    let req = VersionReq::parse_from_specifier(version_req.unwrap_or("*")).ok()?;
+
+    if let Some(latest) = metadata["latest"]
+        .as_str()
+        .and_then(|latest| Version::parse_standard(latest).ok())
+        .filter(|latest| req.matches(latest))
+    {
+        return Some(latest.to_string());
+    }
+
+    metadata["versions"]
+        .as_object()?
+        .iter()
+        .filter(|(_, info)| !info["yanked"].as_bool().unwrap_or(false))
+        .filter_map(|(version, _)| Version::parse_standard(version).ok())
+        .filter(|version| req.matches(version))
+        .max()
+        .map(|version| version.to_string())
+}
+
+/// Checks a downloaded file against the `sha256-<hex>` checksum listed
+/// for it in the version's `manifest`.
+fn verify_checksum(version_metadata: &Value, file: &str, body: &[u8]) -> Result<(), String> {
+    let expected = version_metadata["manifest"][file]["checksum"]
+        .as_str()
+        .ok_or_else(|| format!("{file} is not listed in the version manifest"))?;
+    let actual = format!("sha256-{}", hex::encode(Sha256::digest(body)));
+    if expected != actual {
+        return Err(format!("expected {expected}, got {actual}"));
+    }
+    Ok(())
+}
+
+fn parse_json(url: &ModuleSpecifier, body: &[u8]) -> Result<Value, ModuleLoaderError> {
+    serde_json::from_slice(body).map_err(|error| {
+        ModuleLoaderError::type_error(format!("Invalid registry response from {url}: {error}"))
+    })
+}
+
+fn write_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let staging = path.with_extension(format!("tmp-{}", std::process::id()));
+    std::fs::write(&staging, contents)?;
+    std::fs::rename(&staging, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsrResolver, JsrSpecifier, select_version, verify_checksum};
+    use crate::remote::{DEFAULT_FETCH_TIMEOUT, FetchPolicy};
+    use deno_ast::ModuleSpecifier;
+    use serde_json::json;
+    use std::path::Path;
+
+    #[test]
+    fn parses_scoped_specifiers_with_ranges_and_exports() {
+        assert_eq!(
+            JsrSpecifier::parse("jsr:@std/path@^1.0/posix"),
+            Some(JsrSpecifier {
+                name: "@std/path".to_string(),
+                version_req: Some("^1.0".to_string()),
+                export: Some("posix".to_string()),
+            })
+        );
+        assert_eq!(
+            JsrSpecifier::parse("jsr:/@team/utils").map(|spec| spec.name),
+            Some("@team/utils".to_string())
+        );
+        assert_eq!(JsrSpecifier::parse("jsr:utils"), None);
+        assert_eq!(JsrSpecifier::parse("jsr:@team"), None);
+    }
+
+    #[test]
+    fn selects_latest_or_highest_unyanked_match() {
+        let metadata = json!({
+            "latest": "1.2.0",
+            "versions": {
+                "1.0.0": {},
+                "1.2.0": {},
+                "1.3.0": { "yanked": true },
+                "2.0.0": {},
+            },
+        });
+
+        assert_eq!(select_version(&metadata, None).as_deref(), Some("1.2.0"));
+        assert_eq!(
+            select_version(&metadata, Some("^1")).as_deref(),
+            Some("1.2.0")
+        );
+        assert_eq!(select_version(&metadata, Some("~1.3")), None);
+        assert_eq!(
+            select_version(&metadata, Some("2")).as_deref(),
+            Some("2.0.0")
+        );
+    }
+
+    #[test]
+    fn verifies_files_against_the_version_manifest() {
+        let metadata = json!({
+            "manifest": {
+                "/mod.ts": {
+                    "size": 5,
+                    "checksum": "sha256-2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824",
+                },
+            },
+        });
+
+        assert_eq!(verify_checksum(&metadata, "/mod.ts", b"hello"), Ok(()));
+        assert!(
+            verify_checksum(&metadata, "/mod.ts", b"hello!")
+                .unwrap_err()
+                .starts_with("expected sha256-2cf24dba")
+        );
+        assert!(verify_checksum(&metadata, "/other.ts", b"hello").is_err());
+    }
+
+    #[test]
+    fn splits_published_file_urls() {
+        let policy = FetchPolicy {
+            allow_http: false,
+            timeout: DEFAULT_FETCH_TIMEOUT,
+            auth_tokens: Default::default(),
+        };
+        let resolver =
+            JsrResolver::new(Path::new("cache"), Some("https://jsr.io"), policy).unwrap();
+        let file = |url: &str| {
+            let url = ModuleSpecifier::parse(url).unwrap();
+            resolver
+                .registry_file(&url)
+                .map(|(name, version, file)| format!("{name} {version} {file}"))
+        };
+
+        assert_eq!(
+            file("https://jsr.io/@std/path/1.0.8/posix/mod.ts").as_deref(),
+            Some("@std/path 1.0.8 /posix/mod.ts")
+        );
+        assert_eq!(file("https://jsr.io/@std/path/meta.json"), None);
+        assert_eq!(file("https://jsr.io/@std/path/1.0.8_meta.json"), None);
+    }
+}
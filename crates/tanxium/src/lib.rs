@@ -1,22 +1,55 @@
 //! An embeddable JavaScript/TypeScript runtime with Yasumu's runtime API.
 
+mod auth_tokens;
+mod bench_runner;
+mod bundle;
+mod cache;
+mod cjs;
+mod data_modules;
+mod env_file;
+mod js_error;
+mod jsr;
+mod module_graph;
 mod module_loader;
 mod node_services;
+mod npm;
 mod ops;
 mod permissions;
+mod remote;
+mod resolution;
 mod runtime;
 mod snapshot;
+mod standalone;
 mod state;
+mod test_runner;
+mod test_snapshots;
 mod types;
 mod version;
+mod watch;
 mod worker;
+mod workspace_config;
 mod yasumu_modules;
 
 #[allow(dead_code)]
 mod generated_runtime_contract;
 
+pub use auth_tokens::AuthTokens;
+pub use bench_runner::{
+    BenchFileReport, BenchOptions, BenchReport, BenchResult, discover_bench_files,
+};
+pub use bundle::Bundle;
+pub use env_file::read_env_file;
+pub use module_graph::{Dependency, ModuleGraph, ModuleInfo, ModuleKind};
 pub use permissions::install_permission_prompter;
+pub use resolution::TRACE_TARGET as RESOLUTION_TRACE_TARGET;
 pub use runtime::{Tanxium, TanxiumBuilder};
+pub use standalone::{CompileOptions, StandaloneArchive};
 pub use state::{
     PermissionPromptResponse, RuntimeContext, RuntimeEvent, RuntimeHost, RuntimeState,
 };
+pub use test_runner::{
+    SnapshotSummary, TestFileReport, TestOptions, TestOutcome, TestReport, TestResult, TestShard,
+    discover_test_files,
+};
+pub use watch::WatchOptions;
+pub use worker::ExecutionReport;
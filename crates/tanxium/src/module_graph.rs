@@ -0,0 +1,440 @@
+//! Static module graphs: every module an entrypoint would load, found by
+//! resolving and reading its imports without evaluating any code.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Component;
+use std::sync::Arc;
+
+use deno_ast::swc::ast::{
+    CallExpr, Callee, ExportAll, Expr, ImportDecl, KeyValueProp, Lit, NamedExport, ObjectLit, Prop,
+    PropName, PropOrSpread, Str,
+};
+use deno_ast::swc::ecma_visit::{Visit, VisitWith};
+use deno_ast::{MediaType, ModuleSpecifier, ParseParams};
+use deno_runtime::deno_core::{ModuleLoader, ResolutionKind};
+use serde::Serialize;
+
+use crate::module_loader::{TypescriptModuleLoader, YASUMU_INTERNAL_PREFIX, YASUMU_VIRTUAL_PREFIX};
+use crate::node_services;
+use crate::state::RuntimeState;
+
+/// Where a module in the graph comes from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ModuleKind {
+    /// A file in the workspace or elsewhere on disk.
+    Local,
+    /// A file inside a `node_modules` directory, including `npm:` packages
+    /// in the managed cache.
+    Package,
+    /// An `http:` or `https:` module, including `jsr:` packages.
+    Remote,
+    /// A built-in `yasumu:` or embedded runtime module.
+    Builtin,
+    /// A `yasumu:virtual/*` module registered by the host.
+    Virtual,
+    /// A `node:` built-in.
+    Node,
+    /// A `data:` or `blob:` URL.
+    Inline,
+}
+
+/// The modules reachable from an entrypoint, in breadth-first order.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleGraph {
+    /// The entrypoint's specifier.
+    pub root: String,
+    pub modules: Vec<ModuleInfo>,
+}
+
+impl ModuleGraph {
+    /// Looks up a module by its specifier as shown in the graph.
+    pub fn get(&self, specifier: &str) -> Option<&ModuleInfo> {
+        self.modules
+            .iter()
+            .find(|module| module.specifier == specifier)
+    }
+}
+
+/// A module in the graph.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleInfo {
+    /// The resolved specifier. Built-in modules are shown as `yasumu:<name>`.
+    pub specifier: String,
+    pub kind: ModuleKind,
+    /// The media type the module is loaded as, or the import attribute
+    /// type for text and bytes imports. `None` when it was not read.
+    pub media_type: Option<String>,
+    /// Size of the source in bytes.
+    pub size: Option<usize>,
+    /// For remote modules, whether the module was served from the cache.
+    pub cached: Option<bool>,
+    /// The URL that served a redirected remote module.
+    pub redirect: Option<String>,
+    pub dependencies: Vec<Dependency>,
+    /// Why the module could not be read or parsed.
+    pub error: Option<String>,
+}
+
+/// An import found in a module.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Dependency {
+    /// The specifier as written in the source.
+    pub specifier: String,
+    /// Where the specifier resolved, as shown in [`ModuleInfo::specifier`].
+    pub resolved: Option<String>,
+    /// Whether the import is a dynamic `import()` with a literal specifier.
+    pub dynamic: bool,
+    /// The `type` import attribute, such as `json` or `text`.
+    pub import_type: Option<String>,
+    /// Why the specifier could not be resolved.
+    pub error: Option<String>,
+}
+
+/// Builds the graph for an entrypoint with a loader configured like the
+/// main worker's.
+pub(crate) fn build(state: &Arc<RuntimeState>, root: ModuleSpecifier) -> ModuleGraph {
+    let loader = loader(state);
+
+    let mut modules = Vec::new();
+    let mut seen = HashSet::from([root.clone()]);
+    let mut queue = VecDeque::from([(root.clone(), None)]);
+    while let Some((specifier, import_type)) = queue.pop_front() {
+        let (module, resolved) = read_module(&loader, &specifier, import_type);
+        for (resolved, import_type) in resolved {
+            if seen.insert(resolved.clone()) {
+                queue.push_back((resolved, import_type));
+            }
+        }
+        modules.push(module);
+    }
+
+    ModuleGraph {
+        root: display_specifier(&root),
+        modules,
+    }
+}
+
+/// Creates a loader that reads modules outside of any worker.
+pub(crate) fn loader(state: &Arc<RuntimeState>) -> TypescriptModuleLoader {
+    TypescriptModuleLoader {
+        source_maps: Default::default(),
+        virtual_modules: Some(state.virtual_modules.clone()),
+        blob_store: None,
+        main_module_blob: None,
+        state: state.clone(),
+        pkg_json_resolver: node_services::create_pkg_json_resolver(),
+        workspace_config: Default::default(),
+        standalone: None,
+    }
+}
+
+/// Reads one module and resolves its imports, returning the modules to
+/// visit next with the import attribute type they were requested with.
+fn read_module(
+    loader: &TypescriptModuleLoader,
+    specifier: &ModuleSpecifier,
+    import_type: Option<String>,
+) -> (ModuleInfo, Vec<(ModuleSpecifier, Option<String>)>) {
+    let kind = module_kind(specifier);
+    let mut module = ModuleInfo {
+        specifier: display_specifier(specifier),
+        kind,
+        media_type: None,
+        size: None,
+        cached: None,
+        redirect: None,
+        dependencies: Vec::new(),
+        error: None,
+    };
+    // `node:` built-ins and embedded runtime modules are provided by Deno
+    // and never read through the loader.
+    if kind == ModuleKind::Node || specifier.scheme() == "ext" {
+        return (module, Vec::new());
+    }
+
+    let source = match loader.graph_source(specifier) {
+        Ok(source) => source,
+        Err(error) => {
+            module.error = Some(error.to_string());
+            return (module, Vec::new());
+        }
+    };
+    let is_raw = matches!(import_type.as_deref(), Some("text" | "bytes"));
+    module.media_type = Some(match &import_type {
+        Some(import_type) if is_raw => import_type.clone(),
+        _ => source.media_type,
+    });
+    module.size = Some(source.size);
+    module.cached = source.cached;
+    module.redirect = source.found_specifier.as_ref().map(ToString::to_string);
+
+    let Some((code, media_type)) = source.source.filter(|_| !is_raw) else {
+        return (module, Vec::new());
+    };
+    let referrer = source.found_specifier.as_ref().unwrap_or(specifier);
+    let dependencies = match collect_dependencies(referrer, code, media_type) {
+        Ok(dependencies) => dependencies,
+        Err(error) => {
+            module.error = Some(error);
+            return (module, Vec::new());
+        }
+    };
+
+    let mut resolved_modules = Vec::new();
+    let mut resolved_by_specifier = HashMap::new();
+    for mut dependency in dependencies {
+        let kind = if dependency.dynamic {
+            ResolutionKind::DynamicImport
+        } else {
+            ResolutionKind::Import
+        };
+        let resolved = resolved_by_specifier
+            .entry(dependency.specifier.clone())
+            .or_insert_with(|| {
+                loader
+                    .resolve(&dependency.specifier, referrer.as_str(), kind)
+                    .map_err(|error| error.to_string())
+            })
+            .clone();
+        match resolved {
+            Ok(resolved) => {
+                dependency.resolved = Some(display_specifier(&resolved));
+                resolved_modules.push((resolved, dependency.import_type.clone()));
+            }
+            Err(error) => dependency.error = Some(error),
+        }
+        module.dependencies.push(dependency);
+    }
+
+    (module, resolved_modules)
+}
+
+fn module_kind(specifier: &ModuleSpecifier) -> ModuleKind {
+    if let Some(internal) = specifier.as_str().strip_prefix(YASUMU_INTERNAL_PREFIX) {
+        return if internal.starts_with(YASUMU_VIRTUAL_PREFIX) {
+            ModuleKind::Virtual
+        } else {
+            ModuleKind::Builtin
+        };
+    }
+
+    match specifier.scheme() {
+        "ext" => ModuleKind::Builtin,
+        "node" => ModuleKind::Node,
+        "npm" => ModuleKind::Package,
+        "http" | "https" | "jsr" => ModuleKind::Remote,
+        "data" | "blob" => ModuleKind::Inline,
+        _ => match specifier.to_file_path() {
+            Ok(path)
+                if path
+                    .components()
+                    .any(|component| component == Component::Normal("node_modules".as_ref())) =>
+            {
+                ModuleKind::Package
+            }
+            _ => ModuleKind::Local,
+        },
+    }
+}
+
+/// Shows built-in and virtual modules by the specifier scripts import.
+fn display_specifier(specifier: &ModuleSpecifier) -> String {
+    match specifier.as_str().strip_prefix(YASUMU_INTERNAL_PREFIX) {
+        Some(specifier) => specifier.to_string(),
+        None => specifier.to_string(),
+    }
+}
+
+/// Finds the static imports, re-exports, and literal dynamic imports of an
+/// ES module. Type-only imports are erased by transpilation and skipped.
+fn collect_dependencies(
+    specifier: &ModuleSpecifier,
+    code: String,
+    media_type: MediaType,
+) -> Result<Vec<Dependency>, String> {
+    let parsed = deno_ast::parse_module(ParseParams {
+        specifier: specifier.clone(),
+        text: code.into(),
+        media_type,
+        capture_tokens: false,
+        scope_analysis: false,
+        maybe_syntax: None,
+    })
+    .map_err(|error| error.to_string())?;
+
+    let mut collector = DependencyCollector::default();
+    parsed.program().visit_with(&mut collector);
+    Ok(collector.dependencies)
+}
+
+#[derive(Default)]
+struct DependencyCollector {
+    dependencies: Vec<Dependency>,
+}
+
+impl DependencyCollector {
+    fn add(&mut self, source: &Str, attributes: Option<&ObjectLit>, dynamic: bool) {
+        if let Some(specifier) = string_value(source) {
+            self.dependencies.push(Dependency {
+                specifier,
+                resolved: None,
+                dynamic,
+                import_type: attributes.and_then(import_type),
+                error: None,
+            });
+        }
+    }
+}
+
+impl Visit for DependencyCollector {
+    fn visit_import_decl(&mut self, node: &ImportDecl) {
+        if !node.type_only {
+            self.add(&node.src, node.with.as_deref(), false);
+        }
+    }
+
+    fn visit_export_all(&mut self, node: &ExportAll) {
+        if !node.type_only {
+            self.add(&node.src, node.with.as_deref(), false);
+        }
+    }
+
+    fn visit_named_export(&mut self, node: &NamedExport) {
+        if let Some(source) = &node.src
+            && !node.type_only
+        {
+            self.add(source, node.with.as_deref(), false);
+        }
+    }
+
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        if let Callee::Import(_) = node.callee
+            && let Some(Expr::Lit(Lit::Str(source))) = node.args.first().map(|arg| &*arg.expr)
+        {
+            // import(specifier, { with: { type: "json" } })
+            let attributes = node
+                .args
+                .get(1)
+                .and_then(|options| match &*options.expr {
+                    Expr::Object(options) => property(options, "with"),
+                    _ => None,
+                })
+                .and_then(|attributes| match attributes {
+                    Expr::Object(attributes) => Some(attributes),
+                    _ => None,
+                });
+            self.add(source, attributes, true);
+        }
+
+        node.visit_children_with(self);
+    }
+}
+
+/// The value of a string literal with its escapes decoded. Literals holding
+/// lone surrogates have no `String` form and yield `None`.
+pub(crate) fn string_value(literal: &Str) -> Option<String> {
+    literal.value.as_atom().map(|value| value.to_string())
+}
+
+fn property<'a>(object: &'a ObjectLit, name: &str) -> Option<&'a Expr> {
+    object.props.iter().find_map(|prop| {
+        let PropOrSpread::Prop(prop) = prop else {
+            return None;
+        };
+        let Prop::KeyValue(KeyValueProp { key, value }) = &**prop else {
+            return None;
+        };
+        let matches = match key {
+            PropName::Ident(ident) => &*ident.sym == name,
+            PropName::Str(key) => string_value(key).as_deref() == Some(name),
+            _ => false,
+        };
+        matches.then_some(&**value)
+    })
+}
+
+fn import_type(attributes: &ObjectLit) -> Option<String> {
+    match property(attributes, "type")? {
+        Expr::Lit(Lit::Str(value)) => string_value(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ModuleKind, collect_dependencies, module_kind};
+    use deno_ast::{MediaType, ModuleSpecifier};
+
+    #[test]
+    fn collects_static_dynamic_and_attributed_imports() {
+        let specifier = ModuleSpecifier::parse("file:///workspace/entry.ts").unwrap();
+        let dependencies = collect_dependencies(
+            &specifier,
+            r#"
+            import type { Options } from "./types.ts";
+            import { helper } from "./helper.ts";
+            import data from "./data.json" with { type: "json" };
+            export * from "yasumu:test";
+            export { shape } from "./shape.ts";
+            import "./\u0065scaped.ts" with { "t\x79pe": 'j\x73on' };
+            const lazy = await import("npm:preact", { with: { type: "javascript" } });
+            const computed = await import(helper.path);
+            "#
+            .to_string(),
+            MediaType::TypeScript,
+        )
+        .unwrap();
+
+        let found = dependencies
+            .iter()
+            .map(|dependency| {
+                (
+                    dependency.specifier.as_str(),
+                    dependency.dynamic,
+                    dependency.import_type.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            [
+                ("./helper.ts", false, None),
+                ("./data.json", false, Some("json")),
+                ("yasumu:test", false, None),
+                ("./shape.ts", false, None),
+                ("./escaped.ts", false, Some("json")),
+                ("npm:preact", true, Some("javascript")),
+            ]
+        );
+    }
+
+    #[test]
+    fn classifies_modules_by_origin() {
+        let kind = |specifier: &str| module_kind(&ModuleSpecifier::parse(specifier).unwrap());
+
+        assert_eq!(kind("file:///workspace/entry.ts"), ModuleKind::Local);
+        assert_eq!(
+            kind("file:///workspace/node_modules/preact/dist/preact.mjs"),
+            ModuleKind::Package
+        );
+        assert_eq!(kind("npm:preact@^10/hooks"), ModuleKind::Package);
+        assert_eq!(
+            kind("file://yasumu_internal/yasumu:test"),
+            ModuleKind::Builtin
+        );
+        assert_eq!(
+            kind("file://yasumu_internal/yasumu:virtual/fixtures"),
+            ModuleKind::Virtual
+        );
+        assert_eq!(
+            kind("https://jsr.io/@std/path/1.0.0/mod.ts"),
+            ModuleKind::Remote
+        );
+        assert_eq!(kind("jsr:@std/path@^1/posix"), ModuleKind::Remote);
+        assert_eq!(kind("node:fs"), ModuleKind::Node);
+    }
+}
@@ -1,39 +1,55 @@
 use std::borrow::Cow;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
-
-use deno_ast::{
-    JsxAutomaticOptions, JsxRuntime, MediaType, ModuleSpecifier, ParseParams, SourceMapOption,
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
 };
+
+use deno_ast::{MediaType, ModuleSpecifier, ParseParams, SourceMapOption, TranspileOptions};
 use deno_core::ModuleLoadReferrer;
 use deno_runtime::deno_core::ModuleSourceCode;
 use deno_runtime::deno_core::ModuleType;
 use deno_runtime::deno_core::error::ModuleLoaderError;
 use deno_runtime::deno_core::futures::executor::block_on;
 use deno_runtime::deno_core::{
-    ModuleLoadOptions, ModuleLoadResponse, ModuleLoader, ModuleSource, ResolutionKind,
-    resolve_import,
+    ModuleLoadOptions, ModuleLoadResponse, ModuleLoader, ModuleSource, RequestedModuleType,
+    ResolutionKind, resolve_import,
 };
 use deno_runtime::deno_web::{Blob, BlobStore};
-use node_resolver::{NodeResolutionKind, PackageJsonResolver, ResolutionMode};
+use import_map::ImportMapErrorKind;
+use node_resolver::{
+    NodeResolutionKind, PackageJsonResolver, PackageJsonThreadLocalCache, ResolutionMode,
+};
 use sys_traits::impls::RealSys;
 use tracing::trace;
 
 use crate::{
-    node_services, snapshot::TANXIUM_RESIDUAL_LAZY_ESM, state::RuntimeState,
+    cache,
+    cjs::{self, ModuleFormat},
+    data_modules::{self, DataFormat},
+    jsr, node_services, npm, remote, resolution,
+    snapshot::TANXIUM_RESIDUAL_LAZY_ESM,
+    standalone::{EmbeddedKind, EmbeddedModule, StandaloneArchive},
+    state::RuntimeState,
+    workspace_config::{self, WorkspaceConfig},
     yasumu_modules::YASUMU_MODULES,
 };
 
 const YASUMU_MODULE_PREFIX: &str = "yasumu:";
-const YASUMU_INTERNAL_PREFIX: &str = "file://yasumu_internal/";
-const YASUMU_VIRTUAL_PREFIX: &str = "yasumu:virtual/";
+pub(crate) const YASUMU_INTERNAL_PREFIX: &str = "file://yasumu_internal/";
+pub(crate) const YASUMU_VIRTUAL_PREFIX: &str = "yasumu:virtual/";
 const TANXIUM_RUNTIME_PREFIX: &str = "ext:tanxium_rt/";
 
 type SourceMapStore = Rc<RefCell<HashMap<String, Vec<u8>>>>;
 type VirtualModulesStore = Arc<Mutex<HashMap<String, String>>>;
+/// The workspace configuration last loaded by a loader, keyed by the
+/// workspace directory it was read from.
+type WorkspaceConfigCache =
+    Rc<RefCell<Option<(Option<PathBuf>, Result<Rc<WorkspaceConfig>, String>)>>>;
 
-fn parse_data_url(specifier: &str) -> Option<(String, String)> {
+fn parse_data_url(specifier: &str) -> Option<(String, Vec<u8>)> {
     let rest = specifier.strip_prefix("data:")?;
     let comma = rest.find(',')?;
     let metadata = &rest[..comma];
@@ -48,17 +64,53 @@ fn parse_data_url(specifier: &str) -> Option<(String, String)> {
 
     let decoded = if is_base64 {
         use base64::Engine;
-        let bytes = base64::engine::general_purpose::STANDARD
+        base64::engine::general_purpose::STANDARD
             .decode(encoded)
-            .ok()?;
-        String::from_utf8(bytes).ok()?
+            .ok()?
     } else {
-        urlencoding::decode(encoded).ok()?.into_owned()
+        urlencoding::decode_binary(encoded.as_bytes()).into_owned()
     };
 
     Some((mime.to_string(), decoded))
 }
 
+fn parse_jsr_specifier(specifier: &str) -> Result<jsr::JsrSpecifier, ModuleLoaderError> {
+    jsr::JsrSpecifier::parse(specifier)
+        .ok_or_else(|| ModuleLoaderError::type_error(format!("Invalid jsr specifier: {specifier}")))
+}
+
+/// Resolves a `jsr:` specifier to itself. The package metadata is fetched
+/// when the module is loaded, so resolution never waits on the registry.
+fn deferred_jsr_specifier(specifier: &str) -> Result<ModuleSpecifier, ModuleLoaderError> {
+    parse_jsr_specifier(specifier)?;
+    ModuleSpecifier::parse(specifier).map_err(|error| {
+        ModuleLoaderError::type_error(format!("Invalid jsr specifier {specifier}: {error}"))
+    })
+}
+
+fn parse_npm_specifier(specifier: &str) -> Result<npm::NpmSpecifier, ModuleLoaderError> {
+    npm::NpmSpecifier::parse(specifier)
+        .ok_or_else(|| ModuleLoaderError::type_error(format!("Invalid npm specifier: {specifier}")))
+}
+
+/// Resolves an `npm:` specifier to itself. The package is installed when
+/// the module is loaded, so resolution never waits on the registry.
+fn deferred_npm_specifier(specifier: &str) -> Result<ModuleSpecifier, ModuleLoaderError> {
+    parse_npm_specifier(specifier)?;
+    ModuleSpecifier::parse(specifier).map_err(|error| {
+        ModuleLoaderError::type_error(format!("Invalid npm specifier {specifier}: {error}"))
+    })
+}
+
+/// Runs blocking work, such as registry downloads, on Tokio's blocking pool.
+async fn run_blocking<T: Send + 'static>(
+    work: impl FnOnce() -> Result<T, ModuleLoaderError> + Send + 'static,
+) -> Result<T, ModuleLoaderError> {
+    tokio::task::spawn_blocking(work)
+        .await
+        .map_err(|error| ModuleLoaderError::type_error(error.to_string()))?
+}
+
 fn resolve_tanxium_runtime_import(
     specifier: &str,
     referrer: &str,
@@ -115,6 +167,7 @@ fn media_type_from_content_type(content_type: Option<&str>) -> (MediaType, bool,
             (MediaType::Jsx, true, ModuleType::JavaScript)
         }
         Some("application/json") | Some("text/json") => (MediaType::Json, false, ModuleType::Json),
+        Some("application/wasm") => (MediaType::Wasm, false, ModuleType::Wasm),
         Some("application/ecmascript")
         | Some("application/javascript")
         | Some("text/ecmascript")
@@ -124,134 +177,418 @@ fn media_type_from_content_type(content_type: Option<&str>) -> (MediaType, bool,
     }
 }
 
-fn is_common_js_module(path: &Path) -> bool {
-    match path.extension().and_then(|extension| extension.to_str()) {
-        Some("cjs") => return true,
-        Some("mjs") => return false,
-        Some("js") => {}
-        _ => return false,
-    }
-
-    let mut directory = path.parent();
-    while let Some(current) = directory {
-        let package_json = current.join("package.json");
-        if let Ok(content) = std::fs::read_to_string(package_json) {
-            return serde_json::from_str::<serde_json::Value>(&content)
-                .ok()
-                .and_then(|package| package.get("type")?.as_str().map(str::to_owned))
-                .as_deref()
-                != Some("module");
+#[cfg(test)]
+mod tests {
+    use super::{FetchedModule, parse_data_url, remote, resolve_tanxium_runtime_import};
+    use deno_ast::{MediaType, ModuleSpecifier};
+    use deno_runtime::deno_core::ModuleType;
+
+    #[test]
+    fn resolves_relative_embedded_runtime_imports() {
+        let resolved = resolve_tanxium_runtime_import(
+            "../yasumu-request.ts",
+            "ext:tanxium_rt/modules/collection.ts",
+        )
+        .expect("Tanxium referrer should be handled")
+        .expect("relative import should resolve");
+
+        assert_eq!(resolved.as_str(), "ext:tanxium_rt/yasumu-request.ts");
+    }
+
+    #[test]
+    fn decodes_binary_data_urls() {
+        assert_eq!(
+            parse_data_url("data:application/octet-stream;base64,AP8=").unwrap(),
+            ("application/octet-stream".to_string(), vec![0x00, 0xff])
+        );
+        assert_eq!(
+            parse_data_url("data:text/plain,%FF%20ok").unwrap().1,
+            b"\xff ok".to_vec()
+        );
+    }
+
+    #[test]
+    fn loads_remote_wasm_by_content_type_or_extension() {
+        // The trailing byte makes the body invalid UTF-8.
+        let wasm = b"\0asm\x01\0\0\0\xff".to_vec();
+        let response = |url: &str, content_type: Option<&str>| remote::RemoteResponse {
+            url: ModuleSpecifier::parse(url).unwrap(),
+            content_type: content_type.map(str::to_owned),
+            body: wasm.clone(),
+        };
+
+        for (url, content_type) in [
+            ("https://cdn.example/hash", Some("application/wasm")),
+            (
+                "https://cdn.example/lib.wasm",
+                Some("application/octet-stream"),
+            ),
+        ] {
+            let requested = ModuleSpecifier::parse(url).unwrap();
+            let fetched =
+                FetchedModule::from_remote(&requested, response(url, content_type)).unwrap();
+            assert_eq!(fetched.module_type, ModuleType::Wasm);
+            assert_eq!(fetched.media_type, MediaType::Wasm);
+            assert_eq!(fetched.wasm.as_deref(), Some(wasm.as_slice()));
         }
-        directory = current.parent();
+
+        let requested = ModuleSpecifier::parse("https://cdn.example/lib.js").unwrap();
+        let fetched = FetchedModule::from_remote(
+            &requested,
+            response("https://cdn.example/lib.js", Some("text/javascript")),
+        );
+        assert!(
+            fetched.is_err(),
+            "binary bodies are not valid JavaScript sources"
+        );
     }
+}
 
-    false
+/// A module as read by [`TypescriptModuleLoader::graph_source`].
+pub(crate) struct GraphSource {
+    /// The URL that served the module when it differs from the requested one.
+    pub found_specifier: Option<ModuleSpecifier>,
+    pub media_type: String,
+    pub size: usize,
+    /// For remote modules, whether a cached copy was used.
+    pub cached: Option<bool>,
+    /// The original source of an ES module and the media type to parse it
+    /// as, to be scanned for imports.
+    pub source: Option<(String, MediaType)>,
 }
 
-/// Finds statically declared CommonJS property exports.
-///
-/// Node exposes these properties as synthetic named exports when a CommonJS
-/// module is imported from ESM. Supporting the common assignment forms here
-/// keeps Tanxium's ESM interop aligned with Node without evaluating the module
-/// during module loading.
-fn common_js_named_exports(code: &str) -> Vec<String> {
-    let bytes = code.as_bytes();
-    let mut exports = Vec::new();
-    let mut offset = 0;
-
-    while let Some(index) = code[offset..].find("exports.") {
-        let exports_start = offset + index;
-        let is_module_exports = exports_start >= "module.".len()
-            && &code[exports_start - "module.".len()..exports_start] == "module.";
-        let is_plain_exports = exports_start == 0
-            || !bytes[exports_start - 1].is_ascii_alphanumeric()
-                && bytes[exports_start - 1] != b'_'
-                && bytes[exports_start - 1] != b'$'
-                && bytes[exports_start - 1] != b'.';
-
-        if !is_module_exports && !is_plain_exports {
-            offset = exports_start + "exports.".len();
-            continue;
-        }
-
-        let property_start = exports_start + "exports.".len();
-        let Some(first) = bytes.get(property_start) else {
-            break;
-        };
+/// A module's source before transpilation.
+struct FetchedModule {
+    /// The URL that served the module when it differs from the requested one.
+    found_specifier: Option<ModuleSpecifier>,
+    code: String,
+    /// The binary of a WebAssembly module, whose `code` is empty.
+    wasm: Option<Vec<u8>>,
+    /// Whether `code` is JSON converted from a YAML, TOML, JSONC, or JSON5
+    /// file.
+    data: bool,
+    /// Whether `code` wraps a CommonJS module, whose `require()` calls
+    /// resolve against the file system when it runs.
+    common_js: bool,
+    media_type: MediaType,
+    module_type: ModuleType,
+    should_transpile: bool,
+}
+
+impl FetchedModule {
+    fn new(
+        code: String,
+        media_type: MediaType,
+        module_type: ModuleType,
+        should_transpile: bool,
+    ) -> Self {
+        Self {
+            found_specifier: None,
+            code,
+            wasm: None,
+            data: false,
+            common_js: false,
+            media_type,
+            module_type,
+            should_transpile,
+        }
+    }
+
+    /// A WebAssembly module, compiled and instantiated by `deno_core`.
+    fn wasm(bytes: Vec<u8>) -> Self {
+        Self {
+            wasm: Some(bytes),
+            ..Self::new(String::new(), MediaType::Wasm, ModuleType::Wasm, false)
+        }
+    }
+
+    /// A data file converted to JSON.
+    fn data(json: String) -> Self {
+        Self {
+            data: true,
+            ..Self::new(json, MediaType::Json, ModuleType::Json, false)
+        }
+    }
+
+    /// Data files are JSON modules when imported `with { type: "json" }`.
+    /// Plain imports get a JavaScript module whose default export is the
+    /// parsed value, since `deno_core` rejects JSON modules imported
+    /// without the attribute.
+    fn match_requested_type(&mut self, json_requested: bool) {
+        if self.data && !json_requested {
+            let json = serde_json::to_string(&self.code).unwrap();
+            self.code = format!("export default JSON.parse({json});");
+            self.media_type = MediaType::JavaScript;
+            self.module_type = ModuleType::JavaScript;
+        }
+    }
+
+    /// A module loaded through a deferred `npm:` or `jsr:` specifier, which
+    /// is registered under the URL it resolved to.
+    fn redirected_from(mut self, target: ModuleSpecifier) -> Self {
+        self.found_specifier.get_or_insert(target);
+        self
+    }
 
-        if !first.is_ascii_alphabetic() && *first != b'_' && *first != b'$' {
-            offset = property_start;
-            continue;
+    fn from_remote(
+        requested: &ModuleSpecifier,
+        response: remote::RemoteResponse,
+    ) -> Result<Self, ModuleLoaderError> {
+        let (media_type, should_transpile, module_type) =
+            media_type_from_content_type(response.content_type.as_deref());
+        let found_specifier = (response.url != *requested).then_some(response.url);
+
+        // Servers that do not know the `application/wasm` type fall back to
+        // the URL's extension.
+        let untyped = matches!(
+            response.content_type.as_deref(),
+            None | Some("application/octet-stream")
+        );
+        let url = found_specifier.as_ref().unwrap_or(requested);
+        if module_type == ModuleType::Wasm
+            || (untyped && MediaType::from_specifier(url) == MediaType::Wasm)
+        {
+            return Ok(Self {
+                found_specifier,
+                ..Self::wasm(response.body)
+            });
         }
 
-        let property_end = bytes[property_start..]
-            .iter()
-            .position(|byte| !byte.is_ascii_alphanumeric() && *byte != b'_' && *byte != b'$')
-            .map(|length| property_start + length)
-            .unwrap_or(bytes.len());
-        let property = &code[property_start..property_end];
+        let code = String::from_utf8(response.body).map_err(|error| {
+            ModuleLoaderError::type_error(format!(
+                "Remote module {requested} is not valid UTF-8: {error}"
+            ))
+        })?;
+        Ok(Self {
+            found_specifier,
+            ..Self::new(code, media_type, module_type, should_transpile)
+        })
+    }
 
-        if !exports.iter().any(|export| export == property) {
-            exports.push(property.to_string());
+    fn from_blob(bytes: Vec<u8>, content_type: &str) -> Result<Self, ModuleLoaderError> {
+        if content_type == "application/wasm" {
+            return Ok(Self::wasm(bytes));
         }
 
-        offset = property_end;
+        let code = String::from_utf8(bytes).map_err(|error| {
+            ModuleLoaderError::type_error(format!("Blob module is not valid UTF-8: {}", error))
+        })?;
+        let (media_type, should_transpile, module_type) =
+            media_type_from_content_type(Some(content_type));
+
+        Ok(Self::new(code, media_type, module_type, should_transpile))
     }
 
-    exports
+    fn from_file(path: &Path, code: String) -> Result<Self, ModuleLoaderError> {
+        let media_type = MediaType::from_path(path);
+        let (module_type, should_transpile) = match media_type {
+            MediaType::JavaScript | MediaType::Mjs | MediaType::Cjs => {
+                (ModuleType::JavaScript, false)
+            }
+            MediaType::Jsx
+            | MediaType::TypeScript
+            | MediaType::Mts
+            | MediaType::Cts
+            | MediaType::Dts
+            | MediaType::Dmts
+            | MediaType::Dcts
+            | MediaType::Tsx => (ModuleType::JavaScript, true),
+            MediaType::Json => (ModuleType::Json, false),
+            _ => {
+                return Err(ModuleLoaderError::type_error(format!(
+                    "Unsupported file extension: {:?}",
+                    path.extension()
+                )));
+            }
+        };
+
+        Ok(Self::new(code, media_type, module_type, should_transpile))
+    }
 }
 
-fn wrap_common_js_module(code: String, path: &Path) -> String {
-    let filename = serde_json::to_string(&path.to_string_lossy()).unwrap();
-    let dirname = serde_json::to_string(&path.parent().unwrap_or(path).to_string_lossy()).unwrap();
-    let named_exports = common_js_named_exports(&code)
-        .into_iter()
-        .map(|name| format!("export const {name} = module.exports.{name};"))
-        .collect::<Vec<_>>()
-        .join("\n");
-
-    format!(
-        r#"import "node:process";
-import {{ createRequire }} from "node:module";
-const require = createRequire(import.meta.url);
-const module = {{ exports: {{}} }};
-const exports = module.exports;
-const __filename = {filename};
-const __dirname = {dirname};
-(function (require, module, exports, __filename, __dirname) {{
-{code}
-}})(require, module, exports, __filename, __dirname);
-export default module.exports;
-{named_exports}"#,
-    )
+/// Transpiles TypeScript/JSX source and returns the emitted code with its
+/// separate source map.
+fn transpile(
+    specifier: ModuleSpecifier,
+    code: String,
+    media_type: MediaType,
+    options: &TranspileOptions,
+) -> Result<(String, Option<Vec<u8>>), ModuleLoaderError> {
+    let parsed = deno_ast::parse_module(ParseParams {
+        specifier,
+        text: code.into(),
+        media_type,
+        capture_tokens: false,
+        scope_analysis: false,
+        maybe_syntax: None,
+    })
+    .map_err(|e| ModuleLoaderError::type_error(e.to_string()))?;
+
+    let result = parsed
+        .transpile(
+            options,
+            &deno_ast::TranspileModuleOptions::default(),
+            &deno_ast::EmitOptions {
+                source_map: SourceMapOption::Separate,
+                inline_sources: true,
+                ..Default::default()
+            },
+        )
+        .map_err(|e| ModuleLoaderError::type_error(e.to_string()))?
+        .into_source();
+
+    Ok((
+        String::from_utf8(result.text.into_bytes()).unwrap(),
+        result.source_map.map(|map| map.into_bytes()),
+    ))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{common_js_named_exports, resolve_tanxium_runtime_import};
+fn module_file_path(module_specifier: &ModuleSpecifier) -> Result<PathBuf, ModuleLoaderError> {
+    module_specifier.to_file_path().map_err(|_| {
+        ModuleLoaderError::type_error("Could not convert module specifier to file path")
+    })
+}
 
-    #[test]
-    fn discovers_common_js_property_exports() {
-        let exports = common_js_named_exports(
-            "exports.answer = 42; module.exports.SMTPServer = SMTPServer; module.exports.answer = 43;",
-        );
+fn is_yasumu_internal(module_specifier: &ModuleSpecifier) -> bool {
+    module_specifier.scheme() == "file"
+        && module_specifier
+            .as_str()
+            .starts_with(YASUMU_INTERNAL_PREFIX)
+}
+
+/// Loads and analyzes modules on the local file system: format detection,
+/// CommonJS export analysis, and package lookups. It holds only
+/// thread-safe handles, so the loader can run it on the blocking pool.
+#[derive(Clone)]
+struct LocalModules {
+    pkg_json_resolver: Arc<PackageJsonResolver<RealSys>>,
+}
+
+impl LocalModules {
+    /// Builds a module from a local file, wrapping CommonJS sources as ESM.
+    fn load_file(&self, path: &Path, code: String) -> Result<FetchedModule, ModuleLoaderError> {
+        if let Some(format) = DataFormat::from_path(path) {
+            let json = data_modules::to_json(format, &code, &path.display().to_string())
+                .map_err(|message| ModuleLoaderError::new("SyntaxError", message))?;
+            return Ok(FetchedModule::data(json));
+        }
 
-        assert_eq!(exports, vec!["answer", "SMTPServer"]);
+        let mut fetched = FetchedModule::from_file(path, code)?;
+        if self.module_format(path, &fetched.code)? == ModuleFormat::Cjs {
+            let exports = self.common_js_exports(path, &fetched.code);
+            fetched.code = cjs::wrap_module(std::mem::take(&mut fetched.code), path, &exports);
+            fetched.common_js = true;
+        }
+
+        Ok(fetched)
     }
 
-    #[test]
-    fn resolves_relative_embedded_runtime_imports() {
-        let resolved = resolve_tanxium_runtime_import(
-            "../yasumu-request.ts",
-            "ext:tanxium_rt/modules/collection.ts",
+    /// Decides whether a local file is ESM or CommonJS. Package metadata comes
+    /// from the shared `package.json` cache; only `.js` files in packages
+    /// without a `"type"` are parsed to detect their syntax.
+    fn module_format(&self, path: &Path, code: &str) -> Result<ModuleFormat, ModuleLoaderError> {
+        let format = cjs::format_from_path(&self.pkg_json_resolver, path)
+            .map_err(|error| ModuleLoaderError::type_error(error.to_string()))?;
+        Ok(
+            format.unwrap_or_else(|| match ModuleSpecifier::from_file_path(path) {
+                Ok(specifier) => cjs::detect_format(&specifier, code),
+                Err(()) => ModuleFormat::Cjs,
+            }),
         )
-        .expect("Tanxium referrer should be handled")
-        .expect("relative import should resolve");
+    }
 
-        assert_eq!(resolved.as_str(), "ext:tanxium_rt/yasumu-request.ts");
+    /// Resolves a bare package specifier as if it were imported from a
+    /// module in `base_dir`.
+    fn resolve_package_in(
+        &self,
+        specifier: &str,
+        base_dir: &Path,
+        root_dir: Option<&Path>,
+        mode: ResolutionMode,
+    ) -> Result<ModuleSpecifier, ModuleLoaderError> {
+        let referrer =
+            ModuleSpecifier::from_file_path(base_dir.join("__yasumu_virtual_module__.ts"))
+                .map_err(|_| {
+                    ModuleLoaderError::type_error("Unable to create a package resolution referrer")
+                })?;
+
+        let node_resolver = node_services::create_node_resolver(
+            node_services::create_npm_resolver(self.pkg_json_resolver.clone(), root_dir),
+            self.pkg_json_resolver.clone(),
+        );
+
+        resolution::trace_package_lookup(&self.pkg_json_resolver, specifier, base_dir, mode);
+        node_resolver
+            .resolve_package(specifier, &referrer, mode, NodeResolutionKind::Execution)
+            .and_then(|resolution| resolution.into_url())
+            .map_err(|error| {
+                let searched = resolution::package_candidates(specifier, base_dir);
+                ModuleLoaderError::type_error(resolution::describe_failure(error, &searched))
+            })
+    }
+
+    /// Collects the named exports of a CommonJS module, following
+    /// `require()` re-exports the way Node does.
+    fn common_js_exports(&self, path: &Path, code: &str) -> Vec<String> {
+        let mut exports = Vec::new();
+        self.collect_common_js_exports(path, code, &mut exports, &mut HashSet::new());
+        exports
+    }
+
+    fn collect_common_js_exports(
+        &self,
+        path: &Path,
+        code: &str,
+        exports: &mut Vec<String>,
+        visited: &mut HashSet<PathBuf>,
+    ) {
+        if !visited.insert(path.to_path_buf()) {
+            return;
+        }
+        let Ok(specifier) = ModuleSpecifier::from_file_path(path) else {
+            return;
+        };
+
+        let analysis = cjs::analyze(&specifier, code);
+        for export in analysis.exports {
+            if !exports.contains(&export) {
+                exports.push(export);
+            }
+        }
+
+        for reexport in analysis.reexports {
+            let Some(target) = self.resolve_require(&reexport, path) else {
+                continue;
+            };
+            let Ok(code) = std::fs::read_to_string(&target) else {
+                continue;
+            };
+            if let Ok(ModuleFormat::Cjs) = self.module_format(&target, &code) {
+                self.collect_common_js_exports(&target, &code, exports, visited);
+            }
+        }
+    }
+
+    /// Resolves a `require()` specifier from a CommonJS module. Built-in
+    /// modules and unresolvable specifiers yield `None`.
+    fn resolve_require(&self, specifier: &str, from: &Path) -> Option<PathBuf> {
+        let dir = from.parent()?;
+        if specifier.starts_with("./") || specifier.starts_with("../") || specifier.starts_with('/')
+        {
+            return cjs::resolve_relative_require(&dir.join(specifier));
+        }
+        if specifier.contains(':') {
+            return None;
+        }
+
+        self.resolve_package_in(specifier, dir, None, ResolutionMode::Require)
+            .ok()?
+            .to_file_path()
+            .ok()
     }
 }
 
+#[derive(Clone)]
 pub struct TypescriptModuleLoader {
     pub source_maps: SourceMapStore,
     pub virtual_modules: Option<VirtualModulesStore>,
@@ -261,15 +598,35 @@ pub struct TypescriptModuleLoader {
     pub main_module_blob: Option<(ModuleSpecifier, Arc<Blob>)>,
     pub state: Arc<RuntimeState>,
     pub pkg_json_resolver: Arc<PackageJsonResolver<RealSys>>,
+    /// Each loader reads the configuration on first use, so new workers pick
+    /// up edits to `deno.json` while the main worker reloads it only when
+    /// the workspace changes.
+    pub workspace_config: WorkspaceConfigCache,
+    /// Modules embedded in a standalone executable, served instead of the
+    /// file system and network.
+    pub standalone: Option<Arc<StandaloneArchive>>,
 }
 
 impl TypescriptModuleLoader {
-    fn allows_http_imports(&self) -> bool {
-        self.state
+    fn local_modules(&self) -> LocalModules {
+        LocalModules {
+            pkg_json_resolver: self.pkg_json_resolver.clone(),
+        }
+    }
+
+    fn fetch_policy(&self) -> remote::FetchPolicy {
+        let context = self
+            .state
             .context
             .read()
-            .expect("runtime context lock poisoned")
-            .allow_http_imports
+            .expect("runtime context lock poisoned");
+        remote::FetchPolicy {
+            allow_http: context.allow_http_imports,
+            timeout: context
+                .module_fetch_timeout
+                .unwrap_or(remote::DEFAULT_FETCH_TIMEOUT),
+            auth_tokens: context.auth_tokens.clone(),
+        }
     }
 
     fn current_workspace_dir(&self) -> Option<std::path::PathBuf> {
@@ -281,6 +638,97 @@ impl TypescriptModuleLoader {
             .clone()
     }
 
+    fn workspace_config(&self) -> Result<Rc<WorkspaceConfig>, ModuleLoaderError> {
+        let (workspace_dir, import_map_json) = {
+            let context = self
+                .state
+                .context
+                .read()
+                .expect("runtime context lock poisoned");
+            (context.workspace_dir.clone(), context.import_map.clone())
+        };
+
+        let mut cache = self.workspace_config.borrow_mut();
+        let config = match cache.as_ref() {
+            Some((dir, config)) if *dir == workspace_dir => config.clone(),
+            _ => {
+                let config =
+                    WorkspaceConfig::load(workspace_dir.as_deref(), import_map_json.as_deref())
+                        .map(Rc::new);
+                *cache = Some((workspace_dir, config.clone()));
+                config
+            }
+        };
+
+        config.map_err(ModuleLoaderError::type_error)
+    }
+
+    /// Built-in Yasumu modules always use the default options; everything
+    /// else follows the workspace's compiler options.
+    fn transpile_options(
+        &self,
+        module_specifier: &ModuleSpecifier,
+    ) -> Result<TranspileOptions, ModuleLoaderError> {
+        let is_builtin = is_yasumu_internal(module_specifier)
+            && !module_specifier.as_str().starts_with(&format!(
+                "{}{}",
+                YASUMU_INTERNAL_PREFIX, YASUMU_VIRTUAL_PREFIX
+            ));
+        if is_builtin {
+            return Ok(workspace_config::default_transpile_options());
+        }
+
+        Ok(self.workspace_config()?.transpile_options.clone())
+    }
+
+    /// Applies the workspace import map. Specifiers it does not map fall
+    /// through to the regular resolution rules.
+    fn resolve_with_import_map(
+        &self,
+        specifier: &str,
+        referrer: &str,
+    ) -> Result<Option<ModuleSpecifier>, ModuleLoaderError> {
+        // Embedded runtime modules must not be affected by user mappings.
+        if referrer.starts_with(TANXIUM_RUNTIME_PREFIX)
+            || referrer.starts_with(YASUMU_INTERNAL_PREFIX)
+        {
+            return Ok(None);
+        }
+
+        let config = self.workspace_config()?;
+        let Some(import_map) = config.import_map.as_ref() else {
+            return Ok(None);
+        };
+        let Ok(referrer) = ModuleSpecifier::parse(referrer) else {
+            return Ok(None);
+        };
+
+        match import_map.resolve(specifier, &referrer) {
+            Ok(resolved) if resolved.scheme() == "yasumu" => {
+                let resolved = format!("{}{}", YASUMU_INTERNAL_PREFIX, resolved);
+                resolve_import(&resolved, referrer.as_str())
+                    .map(Some)
+                    .map_err(ModuleLoaderError::from_err)
+            }
+            Ok(resolved) if resolved.scheme() == "npm" => {
+                deferred_npm_specifier(resolved.as_str()).map(Some)
+            }
+            Ok(resolved) if resolved.scheme() == "jsr" => {
+                deferred_jsr_specifier(resolved.as_str()).map(Some)
+            }
+            Ok(resolved) => Ok(Some(resolved)),
+            Err(error)
+                if matches!(
+                    error.as_kind(),
+                    ImportMapErrorKind::UnmappedBareSpecifier(..)
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(ModuleLoaderError::type_error(error.to_string())),
+        }
+    }
+
     fn resolve_bare_package(&self, specifier: &str) -> Result<ModuleSpecifier, ModuleLoaderError> {
         let workspace_dir = self.current_workspace_dir();
         let base_dir = workspace_dir
@@ -290,41 +738,567 @@ impl TypescriptModuleLoader {
             .ok_or_else(|| {
                 ModuleLoaderError::type_error("Unable to determine a package resolution directory")
             })?;
-        let referrer =
-            ModuleSpecifier::from_file_path(base_dir.join("__yasumu_virtual_module__.ts"))
-                .map_err(|_| {
-                    ModuleLoaderError::type_error("Unable to create a package resolution referrer")
-                })?;
 
-        let node_resolver = node_services::create_node_resolver(
-            node_services::create_npm_resolver(
-                self.pkg_json_resolver.clone(),
-                workspace_dir.as_deref(),
+        self.local_modules().resolve_package_in(
+            specifier,
+            &base_dir,
+            workspace_dir.as_deref(),
+            ResolutionMode::Import,
+        )
+    }
+
+    fn npm_installer(&self) -> Result<npm::NpmInstaller, ModuleLoaderError> {
+        let (cache_root, registry) = {
+            let context = self
+                .state
+                .context
+                .read()
+                .expect("runtime context lock poisoned");
+            (cache::cache_root(&context), context.npm_registry.clone())
+        };
+
+        npm::NpmInstaller::new(&cache_root, registry.as_deref(), self.fetch_policy())
+    }
+
+    fn jsr_resolver(&self) -> Result<jsr::JsrResolver, ModuleLoaderError> {
+        let (cache_root, registry) = {
+            let context = self
+                .state
+                .context
+                .read()
+                .expect("runtime context lock poisoned");
+            (cache::cache_root(&context), context.jsr_registry.clone())
+        };
+
+        jsr::JsrResolver::new(&cache_root, registry.as_deref(), self.fetch_policy())
+    }
+
+    /// Resolves the registry URL named by a deferred `jsr:` specifier on
+    /// the calling thread.
+    fn resolve_jsr_sync(
+        &self,
+        specifier: &ModuleSpecifier,
+    ) -> Result<ModuleSpecifier, ModuleLoaderError> {
+        let jsr_specifier = parse_jsr_specifier(specifier.as_str())?;
+        self.jsr_resolver()?.resolve(&jsr_specifier)
+    }
+
+    /// Resolves the registry URL named by a deferred `jsr:` specifier.
+    /// Package metadata is fetched on Tokio's blocking pool, so registry
+    /// requests never stall the isolate thread.
+    async fn resolve_jsr(
+        &self,
+        specifier: &ModuleSpecifier,
+    ) -> Result<ModuleSpecifier, ModuleLoaderError> {
+        let jsr_specifier = parse_jsr_specifier(specifier.as_str())?;
+        let resolver = self.jsr_resolver()?;
+        run_blocking(move || resolver.resolve(&jsr_specifier)).await
+    }
+
+    /// Resolves the module named by a deferred `npm:` specifier on the
+    /// calling thread.
+    fn resolve_npm_sync(
+        &self,
+        specifier: &ModuleSpecifier,
+    ) -> Result<ModuleSpecifier, ModuleLoaderError> {
+        let npm_specifier = parse_npm_specifier(specifier.as_str())?;
+        let installed = self
+            .npm_installer()
+            .and_then(|installer| installer.install(&npm_specifier));
+        self.resolve_installed_npm(&npm_specifier, installed)
+    }
+
+    /// Resolves the module named by a deferred `npm:` specifier. The package
+    /// is installed on Tokio's blocking pool, so registry requests never
+    /// stall the isolate thread.
+    async fn resolve_npm(
+        &self,
+        specifier: &ModuleSpecifier,
+    ) -> Result<ModuleSpecifier, ModuleLoaderError> {
+        let npm_specifier = parse_npm_specifier(specifier.as_str())?;
+        let installed = match self.npm_installer() {
+            Ok(installer) => {
+                let npm_specifier = npm_specifier.clone();
+                run_blocking(move || installer.install(&npm_specifier)).await
+            }
+            Err(error) => Err(error),
+        };
+        self.resolve_installed_npm(&npm_specifier, installed)
+    }
+
+    /// Resolves an `npm:` specifier inside its installed store entry,
+    /// falling back to packages installed in the workspace.
+    fn resolve_installed_npm(
+        &self,
+        npm_specifier: &npm::NpmSpecifier,
+        installed: Result<PathBuf, ModuleLoaderError>,
+    ) -> Result<ModuleSpecifier, ModuleLoaderError> {
+        let package_specifier = npm_specifier.package_specifier();
+        match installed {
+            Ok(entry) => self.local_modules().resolve_package_in(
+                &package_specifier,
+                &entry,
+                Some(&entry),
+                ResolutionMode::Import,
             ),
-            self.pkg_json_resolver.clone(),
-        );
+            Err(error) => self
+                .resolve_bare_package(&package_specifier)
+                .map_err(|_| error),
+        }
+    }
 
-        node_resolver
-            .resolve_package(
+    /// Bare imports inside the npm store resolve against the importing
+    /// package's linked dependencies before the workspace.
+    fn resolve_bare_package_from(
+        &self,
+        specifier: &str,
+        referrer: &str,
+    ) -> Result<ModuleSpecifier, ModuleLoaderError> {
+        let store_entry = ModuleSpecifier::parse(referrer)
+            .ok()
+            .filter(|referrer| referrer.scheme() == "file")
+            .and_then(|referrer| referrer.to_file_path().ok())
+            .and_then(|path| self.npm_installer().ok()?.store_entry_of(&path));
+
+        if let Some(entry) = store_entry
+            && let Ok(resolved) = self.local_modules().resolve_package_in(
                 specifier,
-                &referrer,
+                &entry,
+                Some(&entry),
                 ResolutionMode::Import,
-                NodeResolutionKind::Execution,
             )
-            .and_then(|resolution| resolution.into_url())
-            .map_err(|error| ModuleLoaderError::type_error(error.to_string()))
+        {
+            return Ok(resolved);
+        }
+
+        self.resolve_bare_package(specifier)
     }
 
-    fn load_inner(
+    /// Resolves a specifier through the import map, built-in and registry
+    /// schemes, and package lookup, in that order.
+    fn resolve_specifier(
+        &self,
+        specifier: &str,
+        referrer: &str,
+    ) -> Result<ModuleSpecifier, ModuleLoaderError> {
+        if let Some(resolved) = resolve_tanxium_runtime_import(specifier, referrer) {
+            return resolved;
+        }
+
+        if let Some(resolved) = self.resolve_with_import_map(specifier, referrer)? {
+            return Ok(resolved);
+        }
+
+        if specifier.starts_with("data:") {
+            return ModuleSpecifier::parse(specifier)
+                .map_err(|e| ModuleLoaderError::type_error(e.to_string()));
+        }
+
+        if specifier.starts_with(YASUMU_MODULE_PREFIX) {
+            let resolved = format!("{}{}", YASUMU_INTERNAL_PREFIX, specifier);
+            return resolve_import(&resolved, referrer).map_err(ModuleLoaderError::from_err);
+        }
+
+        if specifier.starts_with("npm:") {
+            return deferred_npm_specifier(specifier);
+        }
+
+        if specifier.starts_with("jsr:") {
+            return deferred_jsr_specifier(specifier);
+        }
+
+        if !specifier.starts_with('.') && !specifier.starts_with('/') && !specifier.contains(':') {
+            return self.resolve_bare_package_from(specifier, referrer);
+        }
+
+        resolve_import(specifier, referrer).map_err(ModuleLoaderError::from_err)
+    }
+
+    /// Reads a module for [`crate::module_graph`] without evaluating it. Only
+    /// ES module sources are returned for import analysis; built-in Yasumu
+    /// modules, CommonJS, WebAssembly, and data files are leaves.
+    pub(crate) fn graph_source(
         &self,
         module_specifier: &ModuleSpecifier,
-    ) -> Result<ModuleSource, ModuleLoaderError> {
-        trace!("Loading module: {}", module_specifier);
+    ) -> Result<GraphSource, ModuleLoaderError> {
+        let cached = match module_specifier.scheme() {
+            "http" | "https" => Some(
+                self.jsr_resolver()?
+                    .cached_module(module_specifier)
+                    .is_some(),
+            ),
+            _ => None,
+        };
+
+        if module_specifier.scheme() == "file" && !is_yasumu_internal(module_specifier) {
+            let path = module_file_path(module_specifier)?;
+            let bytes = std::fs::read(&path).map_err(ModuleLoaderError::from_err)?;
+            let size = bytes.len();
+            let media_type = MediaType::from_path(&path);
+            let is_script = matches!(
+                media_type,
+                MediaType::JavaScript
+                    | MediaType::Jsx
+                    | MediaType::Mjs
+                    | MediaType::Cjs
+                    | MediaType::TypeScript
+                    | MediaType::Mts
+                    | MediaType::Cts
+                    | MediaType::Tsx
+            );
+            let source = match String::from_utf8(bytes) {
+                Ok(code) if is_script => (self.local_modules().module_format(&path, &code)?
+                    == ModuleFormat::Esm)
+                    .then_some((code, media_type)),
+                _ => None,
+            };
+
+            return Ok(GraphSource {
+                found_specifier: None,
+                media_type: match DataFormat::from_path(&path) {
+                    Some(format) => format.name().to_string(),
+                    None => media_type.to_string(),
+                },
+                size,
+                cached,
+                source,
+            });
+        }
 
-        let is_yasumu_internal = module_specifier.scheme() == "file"
-            && module_specifier
+        let is_builtin = is_yasumu_internal(module_specifier)
+            && !module_specifier
                 .as_str()
-                .starts_with(YASUMU_INTERNAL_PREFIX);
+                .starts_with(&format!("{YASUMU_INTERNAL_PREFIX}{YASUMU_VIRTUAL_PREFIX}"));
+        let fetched = self.fetch_sync(module_specifier)?;
+        let size = fetched.wasm.as_ref().map_or(fetched.code.len(), Vec::len);
+        let media_type = fetched.media_type;
+        let source = (!is_builtin && fetched.module_type == ModuleType::JavaScript)
+            .then_some((fetched.code, media_type));
+
+        Ok(GraphSource {
+            found_specifier: fetched.found_specifier,
+            media_type: media_type.to_string(),
+            size,
+            cached,
+            source,
+        })
+    }
+
+    /// Loads a module the way a worker would, for embedding in a
+    /// standalone executable. Sources are transpiled now, so the executable
+    /// needs neither the workspace configuration nor the packages'
+    /// `package.json` files. CommonJS modules are wrapped as ESM and marked,
+    /// since their `require()` calls still look for files on disk.
+    pub(crate) fn embed_module(
+        &self,
+        module_specifier: &ModuleSpecifier,
+        raw: bool,
+    ) -> Result<EmbeddedModule, ModuleLoaderError> {
+        if raw && matches!(module_specifier.scheme(), "npm" | "jsr") {
+            let target = match module_specifier.scheme() {
+                "npm" => self.resolve_npm_sync(module_specifier)?,
+                _ => self.resolve_jsr_sync(module_specifier)?,
+            };
+            let mut embedded = self.embed_module(&target, true)?;
+            embedded.redirect.get_or_insert_with(|| target.to_string());
+            return Ok(embedded);
+        }
+
+        if raw {
+            let (redirect, source) = match module_specifier.scheme() {
+                "http" | "https" => {
+                    let jsr = self.jsr_resolver()?;
+                    let response = match jsr.cached_module(module_specifier) {
+                        Some(response) => response,
+                        None => {
+                            let response = remote::fetch(
+                                module_specifier,
+                                &jsr.policy_for(module_specifier, self.fetch_policy()),
+                            )?;
+                            jsr.cache_module(module_specifier, &response)?;
+                            response
+                        }
+                    };
+                    let redirect =
+                        (response.url != *module_specifier).then(|| response.url.to_string());
+                    (redirect, response.body)
+                }
+                _ => (
+                    None,
+                    std::fs::read(module_file_path(module_specifier)?)
+                        .map_err(ModuleLoaderError::from_err)?,
+                ),
+            };
+            return Ok(EmbeddedModule {
+                kind: EmbeddedKind::Raw,
+                source,
+                source_map: None,
+                redirect,
+                common_js: false,
+            });
+        }
+
+        let mut fetched = self.fetch_sync(module_specifier)?;
+        let specifier = fetched
+            .found_specifier
+            .clone()
+            .unwrap_or_else(|| module_specifier.clone());
+        let source_map = if fetched.should_transpile {
+            let options = self.transpile_options(&specifier)?;
+            let (code, source_map) = transpile(
+                specifier,
+                std::mem::take(&mut fetched.code),
+                fetched.media_type,
+                &options,
+            )?;
+            fetched.code = code;
+            source_map
+        } else {
+            None
+        };
+
+        let (kind, source) = match fetched.wasm {
+            Some(bytes) => (EmbeddedKind::Wasm, bytes),
+            None if fetched.data => (EmbeddedKind::Data, fetched.code.into_bytes()),
+            None if fetched.module_type == ModuleType::Json => {
+                (EmbeddedKind::Json, fetched.code.into_bytes())
+            }
+            None => (EmbeddedKind::JavaScript, fetched.code.into_bytes()),
+        };
+        Ok(EmbeddedModule {
+            kind,
+            source,
+            source_map,
+            redirect: fetched
+                .found_specifier
+                .map(|specifier| specifier.to_string()),
+            common_js: fetched.common_js,
+        })
+    }
+
+    /// Looks a module up in the standalone archive, if there is one.
+    fn embedded_module(
+        &self,
+        module_specifier: &ModuleSpecifier,
+    ) -> Option<Result<FetchedModule, ModuleLoaderError>> {
+        let embedded = self.standalone.as_ref()?.module(module_specifier)?;
+        let found_specifier = match embedded.redirect.as_deref().map(ModuleSpecifier::parse) {
+            Some(Ok(found_specifier)) => Some(found_specifier),
+            Some(Err(error)) => return Some(Err(ModuleLoaderError::type_error(error.to_string()))),
+            None => None,
+        };
+        if let Some(source_map) = &embedded.source_map {
+            let specifier = found_specifier.as_ref().unwrap_or(module_specifier);
+            self.source_maps
+                .borrow_mut()
+                .insert(specifier.to_string(), source_map.clone());
+        }
+
+        let source = embedded.source.clone();
+        let fetched = match embedded.kind {
+            EmbeddedKind::Wasm => Ok(FetchedModule::wasm(source)),
+            EmbeddedKind::Raw => Err(ModuleLoaderError::type_error(format!(
+                "{module_specifier} was embedded for text and bytes imports only"
+            ))),
+            kind => String::from_utf8(source)
+                .map_err(|error| ModuleLoaderError::type_error(error.to_string()))
+                .map(|code| match kind {
+                    EmbeddedKind::Data => FetchedModule::data(code),
+                    EmbeddedKind::Json => {
+                        FetchedModule::new(code, MediaType::Json, ModuleType::Json, false)
+                    }
+                    _ => FetchedModule::new(
+                        code,
+                        MediaType::JavaScript,
+                        ModuleType::JavaScript,
+                        false,
+                    ),
+                }),
+        };
+        Some(fetched.map(|fetched| FetchedModule {
+            found_specifier,
+            ..fetched
+        }))
+    }
+
+    /// Reads a module without blocking the isolate thread. File and network
+    /// IO is awaited so independent modules in a graph load concurrently;
+    /// in-memory sources are delegated to [`Self::fetch_sync`].
+    async fn fetch_async(
+        &self,
+        module_specifier: &ModuleSpecifier,
+    ) -> Result<FetchedModule, ModuleLoaderError> {
+        if let Some(embedded) = self.embedded_module(module_specifier) {
+            return embedded;
+        }
+
+        match module_specifier.scheme() {
+            "file" if !is_yasumu_internal(module_specifier) => {
+                let path = module_file_path(module_specifier)?;
+                if MediaType::from_path(&path) == MediaType::Wasm {
+                    let bytes = tokio::fs::read(&path)
+                        .await
+                        .map_err(ModuleLoaderError::from_err)?;
+                    return Ok(FetchedModule::wasm(bytes));
+                }
+                // Reading `package.json` files and analyzing CommonJS
+                // re-exports touch the disk too, so the whole load runs on
+                // the blocking pool. Its threads outlive this worker, so
+                // they keep no `package.json` cache between loads.
+                let local_modules = self.local_modules();
+                run_blocking(move || {
+                    let code = std::fs::read_to_string(&path).map_err(ModuleLoaderError::from_err);
+                    let fetched = code.and_then(|code| local_modules.load_file(&path, code));
+                    PackageJsonThreadLocalCache::clear();
+                    fetched
+                })
+                .await
+            }
+
+            "http" | "https" => {
+                let response = self.fetch_remote(module_specifier).await?;
+                FetchedModule::from_remote(module_specifier, response)
+            }
+
+            "blob" => {
+                let blob = self.find_blob(module_specifier)?;
+                FetchedModule::from_blob(blob.read_all().await, &blob.media_type)
+            }
+
+            "npm" => {
+                let target = self.resolve_npm(module_specifier).await?;
+                let fetched = Box::pin(self.fetch_async(&target)).await?;
+                Ok(fetched.redirected_from(target))
+            }
+
+            "jsr" => {
+                let target = self.resolve_jsr(module_specifier).await?;
+                let fetched = Box::pin(self.fetch_async(&target)).await?;
+                Ok(fetched.redirected_from(target))
+            }
+
+            _ => self.fetch_sync(module_specifier),
+        }
+    }
+
+    /// Fetches a remote module, serving published registry modules from
+    /// the cache. Cache reads and writes run on the blocking pool.
+    async fn fetch_remote(
+        &self,
+        module_specifier: &ModuleSpecifier,
+    ) -> Result<remote::RemoteResponse, ModuleLoaderError> {
+        let jsr = self.jsr_resolver()?;
+        let policy = jsr.policy_for(module_specifier, self.fetch_policy());
+        if !jsr.is_registry_module(module_specifier) {
+            return remote::fetch_async(module_specifier, &policy).await;
+        }
+
+        let cached = {
+            let (jsr, specifier) = (jsr.clone(), module_specifier.clone());
+            run_blocking(move || Ok(jsr.cached_module(&specifier))).await?
+        };
+        if let Some(response) = cached {
+            return Ok(response);
+        }
+
+        let response = remote::fetch_async(module_specifier, &policy).await?;
+        let specifier = module_specifier.clone();
+        run_blocking(move || {
+            jsr.cache_module(&specifier, &response)?;
+            Ok(response)
+        })
+        .await
+    }
+
+    /// Reads the bytes behind a `with { type: "text" }` or
+    /// `with { type: "bytes" }` import, along with the URL that served them
+    /// when it differs from the requested one.
+    async fn fetch_raw(
+        &self,
+        module_specifier: &ModuleSpecifier,
+    ) -> Result<(Option<ModuleSpecifier>, Vec<u8>), ModuleLoaderError> {
+        if let Some(embedded) = self
+            .standalone
+            .as_ref()
+            .and_then(|archive| archive.module(module_specifier))
+        {
+            let found_specifier = embedded
+                .redirect
+                .as_deref()
+                .and_then(|redirect| ModuleSpecifier::parse(redirect).ok());
+            return Ok((found_specifier, embedded.source.clone()));
+        }
+
+        match module_specifier.scheme() {
+            "file" if !is_yasumu_internal(module_specifier) => {
+                let path = module_file_path(module_specifier)?;
+                let bytes = tokio::fs::read(&path)
+                    .await
+                    .map_err(ModuleLoaderError::from_err)?;
+                Ok((None, bytes))
+            }
+
+            "http" | "https" => {
+                let response = self.fetch_remote(module_specifier).await?;
+                let found_specifier = (response.url != *module_specifier).then_some(response.url);
+                Ok((found_specifier, response.body))
+            }
+
+            "blob" => {
+                let blob = self.find_blob(module_specifier)?;
+                Ok((None, blob.read_all().await))
+            }
+
+            "npm" | "jsr" => {
+                let target = match module_specifier.scheme() {
+                    "npm" => self.resolve_npm(module_specifier).await?,
+                    _ => self.resolve_jsr(module_specifier).await?,
+                };
+                let (found_specifier, bytes) = Box::pin(self.fetch_raw(&target)).await?;
+                Ok((found_specifier.or(Some(target)), bytes))
+            }
+
+            "data" => {
+                let (_, bytes) = parse_data_url(module_specifier.as_str()).ok_or_else(|| {
+                    ModuleLoaderError::type_error(format!("Invalid data URL: {}", module_specifier))
+                })?;
+                Ok((None, bytes))
+            }
+
+            _ => Err(ModuleLoaderError::type_error(format!(
+                "Text and bytes imports are not supported for {}",
+                module_specifier
+            ))),
+        }
+    }
+
+    fn find_blob(
+        &self,
+        module_specifier: &ModuleSpecifier,
+    ) -> Result<Arc<Blob>, ModuleLoaderError> {
+        self.main_module_blob
+            .as_ref()
+            .filter(|(specifier, _)| specifier == module_specifier)
+            .map(|(_, blob)| blob.clone())
+            .or_else(|| {
+                self.blob_store
+                    .as_ref()
+                    .and_then(|store| store.get_object_url(module_specifier.clone()))
+            })
+            .ok_or_else(|| {
+                ModuleLoaderError::type_error(format!("Blob URL not found: {}", module_specifier))
+            })
+    }
+
+    fn fetch_sync(
+        &self,
+        module_specifier: &ModuleSpecifier,
+    ) -> Result<FetchedModule, ModuleLoaderError> {
+        if let Some(embedded) = self.embedded_module(module_specifier) {
+            return embedded;
+        }
+
+        let is_yasumu_internal = is_yasumu_internal(module_specifier);
 
         let is_yasumu_virtual = is_yasumu_internal
             && module_specifier.as_str().starts_with(&format!(
@@ -332,7 +1306,7 @@ impl TypescriptModuleLoader {
                 YASUMU_INTERNAL_PREFIX, YASUMU_VIRTUAL_PREFIX
             ));
 
-        let (code, should_transpile, media_type, module_type) = match module_specifier.scheme() {
+        match module_specifier.scheme() {
             "data" => {
                 let (mime, decoded) =
                     parse_data_url(module_specifier.as_str()).ok_or_else(|| {
@@ -342,109 +1316,62 @@ impl TypescriptModuleLoader {
                         ))
                     })?;
 
+                let decoded = String::from_utf8(decoded).map_err(|error| {
+                    ModuleLoaderError::type_error(format!(
+                        "Data URL module is not valid UTF-8: {}",
+                        error
+                    ))
+                })?;
+
                 let (media_type, should_transpile) = if mime.contains("typescript") {
                     (MediaType::TypeScript, true)
                 } else {
                     (MediaType::JavaScript, false)
                 };
 
-                (
+                Ok(FetchedModule::new(
                     decoded,
-                    should_transpile,
                     media_type,
                     ModuleType::JavaScript,
-                )
+                    should_transpile,
+                ))
             }
 
             "blob" => {
-                let blob = self
-                    .main_module_blob
-                    .as_ref()
-                    .filter(|(specifier, _)| specifier == module_specifier)
-                    .map(|(_, blob)| blob.clone())
-                    .or_else(|| {
-                        self.blob_store
-                            .as_ref()
-                            .and_then(|store| store.get_object_url(module_specifier.clone()))
-                    })
-                    .ok_or_else(|| {
-                        ModuleLoaderError::type_error(format!(
-                            "Blob URL not found: {}",
-                            module_specifier
-                        ))
-                    })?;
+                let blob = self.find_blob(module_specifier)?;
+                FetchedModule::from_blob(block_on(blob.read_all()), &blob.media_type)
+            }
 
-                let code = String::from_utf8(block_on(blob.read_all())).map_err(|error| {
-                    ModuleLoaderError::type_error(format!(
-                        "Blob module is not valid UTF-8: {}",
-                        error
-                    ))
-                })?;
-                let (media_type, should_transpile, module_type) =
-                    media_type_from_content_type(Some(&blob.media_type));
+            "npm" => {
+                let target = self.resolve_npm_sync(module_specifier)?;
+                Ok(self.fetch_sync(&target)?.redirected_from(target))
+            }
 
-                (code, should_transpile, media_type, module_type)
+            "jsr" => {
+                let target = self.resolve_jsr_sync(module_specifier)?;
+                Ok(self.fetch_sync(&target)?.redirected_from(target))
             }
 
             "file" if !is_yasumu_internal => {
-                let path = module_specifier.to_file_path().map_err(|_| {
-                    ModuleLoaderError::type_error("Could not convert module specifier to file path")
-                })?;
-
-                let media_type = MediaType::from_path(&path);
-                let (module_type, should_transpile) = match media_type {
-                    MediaType::JavaScript | MediaType::Mjs | MediaType::Cjs => {
-                        (ModuleType::JavaScript, false)
-                    }
-                    MediaType::Jsx
-                    | MediaType::TypeScript
-                    | MediaType::Mts
-                    | MediaType::Cts
-                    | MediaType::Dts
-                    | MediaType::Dmts
-                    | MediaType::Dcts
-                    | MediaType::Tsx => (ModuleType::JavaScript, true),
-                    MediaType::Json => (ModuleType::Json, false),
-                    _ => {
-                        return Err(ModuleLoaderError::type_error(format!(
-                            "Unsupported file extension: {:?}",
-                            path.extension()
-                        )));
-                    }
-                };
-
+                let path = module_file_path(module_specifier)?;
+                if MediaType::from_path(&path) == MediaType::Wasm {
+                    let bytes = std::fs::read(&path).map_err(ModuleLoaderError::from_err)?;
+                    return Ok(FetchedModule::wasm(bytes));
+                }
                 let code = std::fs::read_to_string(&path).map_err(ModuleLoaderError::from_err)?;
-                let code = if is_common_js_module(&path) {
-                    wrap_common_js_module(code, &path)
-                } else {
-                    code
-                };
-
-                (code, should_transpile, media_type, module_type)
-            }
-
-            "http" if !self.allows_http_imports() => {
-                return Err(ModuleLoaderError::type_error(
-                    "HTTP imports are disabled; rerun with --allow-http-imports",
-                ));
+                self.local_modules().load_file(&path, code)
             }
 
             "http" | "https" => {
-                let url = module_specifier.as_str();
-                let response = ureq::get(url)
-                    .call()
-                    .map_err(|e| ModuleLoaderError::type_error(e.to_string()))?;
-
-                let content_type = response.header("Content-Type").map(str::to_owned);
-
-                let body = response
-                    .into_string()
-                    .map_err(ModuleLoaderError::from_err)?;
-
-                let (media_type, should_transpile, module_type) =
-                    media_type_from_content_type(content_type.as_deref());
+                let jsr = self.jsr_resolver()?;
+                if let Some(response) = jsr.cached_module(module_specifier) {
+                    return FetchedModule::from_remote(module_specifier, response);
+                }
 
-                (body, should_transpile, media_type, module_type)
+                let policy = jsr.policy_for(module_specifier, self.fetch_policy());
+                let response = remote::fetch(module_specifier, &policy)?;
+                jsr.cache_module(module_specifier, &response)?;
+                FetchedModule::from_remote(module_specifier, response)
             }
 
             "file" if is_yasumu_virtual => {
@@ -471,10 +1398,15 @@ impl TypescriptModuleLoader {
                         ))
                     })?;
 
-                (code, true, MediaType::TypeScript, ModuleType::JavaScript)
+                Ok(FetchedModule::new(
+                    code,
+                    MediaType::TypeScript,
+                    ModuleType::JavaScript,
+                    true,
+                ))
             }
 
-            "file" if is_yasumu_internal => {
+            "file" => {
                 let key = module_specifier
                     .as_str()
                     .strip_prefix(YASUMU_INTERNAL_PREFIX)
@@ -486,15 +1418,19 @@ impl TypescriptModuleLoader {
                     ))
                 })?;
 
-                (
+                Ok(FetchedModule::new(
                     source.to_string(),
-                    true,
                     MediaType::TypeScript,
                     ModuleType::JavaScript,
-                )
+                    true,
+                ))
             }
 
-            "ext" if module_specifier.as_str().starts_with("ext:tanxium_rt/") => {
+            "ext"
+                if module_specifier
+                    .as_str()
+                    .starts_with(TANXIUM_RUNTIME_PREFIX) =>
+            {
                 let source = TANXIUM_RESIDUAL_LAZY_ESM
                     .binary_search_by(|(specifier, _)| specifier.cmp(&module_specifier.as_str()))
                     .ok()
@@ -506,73 +1442,152 @@ impl TypescriptModuleLoader {
                         ))
                     })?;
 
-                (
+                Ok(FetchedModule::new(
                     source.to_string(),
-                    false,
                     MediaType::JavaScript,
                     ModuleType::JavaScript,
-                )
+                    false,
+                ))
             }
 
-            scheme => {
-                return Err(ModuleLoaderError::type_error(format!(
-                    "Unsupported module scheme: {}",
-                    scheme
-                )));
+            scheme => Err(ModuleLoaderError::type_error(format!(
+                "Unsupported module scheme: {}",
+                scheme
+            ))),
+        }
+    }
+
+    fn finish(
+        &self,
+        module_specifier: &ModuleSpecifier,
+        fetched: FetchedModule,
+        transpiled: Option<(String, Option<Vec<u8>>)>,
+    ) -> ModuleSource {
+        let specifier = fetched.found_specifier.as_ref().unwrap_or(module_specifier);
+
+        let code = match transpiled {
+            Some((code, source_map)) => {
+                if let Some(source_map) = source_map {
+                    self.source_maps
+                        .borrow_mut()
+                        .insert(specifier.to_string(), source_map);
+                }
+                code
             }
+            None => fetched.code,
         };
 
-        let code = if should_transpile {
-            let parsed = deno_ast::parse_module(ParseParams {
-                specifier: module_specifier.clone(),
-                text: code.into(),
-                media_type,
-                capture_tokens: false,
-                scope_analysis: false,
-                maybe_syntax: None,
+        let code = match fetched.wasm {
+            Some(bytes) => ModuleSourceCode::Bytes(bytes.into_boxed_slice().into()),
+            None => ModuleSourceCode::String(code.into()),
+        };
+        match &fetched.found_specifier {
+            Some(found_specifier) => ModuleSource::new_with_redirect(
+                fetched.module_type,
+                code,
+                module_specifier,
+                found_specifier,
+                None,
+            ),
+            None => ModuleSource::new(fetched.module_type, code, module_specifier, None),
+        }
+    }
+
+    /// Loads a text or bytes import verbatim: no transpilation, CommonJS
+    /// wrapping, or media type detection applies.
+    async fn load_raw(
+        &self,
+        module_specifier: &ModuleSpecifier,
+        module_type: ModuleType,
+    ) -> Result<ModuleSource, ModuleLoaderError> {
+        trace!("Loading raw module: {}", module_specifier);
+
+        let (found_specifier, bytes) = self.fetch_raw(module_specifier).await?;
+        let code = match module_type {
+            ModuleType::Text => {
+                let text = String::from_utf8(bytes).map_err(|error| {
+                    ModuleLoaderError::type_error(format!(
+                        "Text import {} is not valid UTF-8: {}",
+                        module_specifier, error
+                    ))
+                })?;
+                ModuleSourceCode::String(text.into())
+            }
+            _ => ModuleSourceCode::Bytes(bytes.into_boxed_slice().into()),
+        };
+
+        Ok(match &found_specifier {
+            Some(found_specifier) => ModuleSource::new_with_redirect(
+                module_type,
+                code,
+                module_specifier,
+                found_specifier,
+                None,
+            ),
+            None => ModuleSource::new(module_type, code, module_specifier, None),
+        })
+    }
+
+    /// Loads a module on the isolate thread. Used for synchronous loads such
+    /// as `require()` of an ES module.
+    fn load_sync(
+        &self,
+        module_specifier: &ModuleSpecifier,
+        json_requested: bool,
+    ) -> Result<ModuleSource, ModuleLoaderError> {
+        trace!("Loading module synchronously: {}", module_specifier);
+
+        let mut fetched = self.fetch_sync(module_specifier)?;
+        fetched.match_requested_type(json_requested);
+        let transpiled = if fetched.should_transpile {
+            let specifier = fetched
+                .found_specifier
+                .clone()
+                .unwrap_or_else(|| module_specifier.clone());
+            let options = self.transpile_options(&specifier)?;
+            Some(transpile(
+                specifier,
+                std::mem::take(&mut fetched.code),
+                fetched.media_type,
+                &options,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(self.finish(module_specifier, fetched, transpiled))
+    }
+
+    /// Loads a module without blocking the isolate thread. Transpilation runs
+    /// on Tokio's blocking pool so sibling modules are processed in parallel.
+    async fn load_async(
+        &self,
+        module_specifier: &ModuleSpecifier,
+        json_requested: bool,
+    ) -> Result<ModuleSource, ModuleLoaderError> {
+        trace!("Loading module: {}", module_specifier);
+
+        let mut fetched = self.fetch_async(module_specifier).await?;
+        fetched.match_requested_type(json_requested);
+        let transpiled = if fetched.should_transpile {
+            let specifier = fetched
+                .found_specifier
+                .clone()
+                .unwrap_or_else(|| module_specifier.clone());
+            let options = self.transpile_options(&specifier)?;
+            let code = std::mem::take(&mut fetched.code);
+            let media_type = fetched.media_type;
+            let transpiled = tokio::task::spawn_blocking(move || {
+                transpile(specifier, code, media_type, &options)
             })
-            .map_err(|e| ModuleLoaderError::type_error(e.to_string()))?;
-
-            let result = parsed
-                .transpile(
-                    &deno_ast::TranspileOptions {
-                        imports_not_used_as_values: deno_ast::ImportsNotUsedAsValues::Remove,
-                        decorators: deno_ast::DecoratorsTranspileOption::LegacyTypeScript {
-                            emit_metadata: true,
-                        },
-                        jsx: Some(JsxRuntime::Automatic(JsxAutomaticOptions {
-                            development: false,
-                            import_source: Some("yasumu:ui".to_string()),
-                        })),
-                        ..Default::default()
-                    },
-                    &deno_ast::TranspileModuleOptions::default(),
-                    &deno_ast::EmitOptions {
-                        source_map: SourceMapOption::Separate,
-                        inline_sources: true,
-                        ..Default::default()
-                    },
-                )
-                .map_err(|e| ModuleLoaderError::type_error(e.to_string()))?
-                .into_source();
-
-            if let Some(map) = result.source_map {
-                self.source_maps
-                    .borrow_mut()
-                    .insert(module_specifier.to_string(), map.into_bytes());
-            }
-
-            String::from_utf8(result.text.into_bytes()).unwrap()
+            .await
+            .map_err(|error| ModuleLoaderError::type_error(error.to_string()))??;
+            Some(transpiled)
         } else {
-            code
+            None
         };
 
-        Ok(ModuleSource::new(
-            module_type,
-            ModuleSourceCode::String(code.into()),
-            module_specifier,
-            None,
-        ))
+        Ok(self.finish(module_specifier, fetched, transpiled))
     }
 }
 
@@ -583,49 +1598,75 @@ impl ModuleLoader for TypescriptModuleLoader {
         referrer: &str,
         _kind: ResolutionKind,
     ) -> Result<ModuleSpecifier, ModuleLoaderError> {
-        if let Some(resolved) = resolve_tanxium_runtime_import(specifier, referrer) {
-            return resolved;
-        }
-
-        if specifier.starts_with("data:") {
-            return ModuleSpecifier::parse(specifier)
-                .map_err(|e| ModuleLoaderError::type_error(e.to_string()));
-        }
-
-        if specifier.starts_with(YASUMU_MODULE_PREFIX) {
-            let resolved = format!("{}{}", YASUMU_INTERNAL_PREFIX, specifier);
-            return resolve_import(&resolved, referrer).map_err(ModuleLoaderError::from_err);
+        if let Some(resolved) = self
+            .standalone
+            .as_ref()
+            .and_then(|archive| archive.resolution(referrer, specifier))
+        {
+            return Ok(resolved);
         }
 
-        if !specifier.starts_with('.') && !specifier.starts_with('/') && !specifier.contains(':') {
-            return self.resolve_bare_package(specifier);
+        let resolved = self.resolve_specifier(specifier, referrer);
+        match &resolved {
+            Ok(resolved) => tracing::debug!(
+                target: resolution::TRACE_TARGET,
+                "{specifier} from {referrer} resolved to {resolved}"
+            ),
+            Err(error) => tracing::debug!(
+                target: resolution::TRACE_TARGET,
+                "{specifier} from {referrer} failed to resolve: {error}"
+            ),
         }
-
-        resolve_import(specifier, referrer).map_err(ModuleLoaderError::from_err)
+        resolved
     }
 
     fn load(
         &self,
         module_specifier: &ModuleSpecifier,
         _maybe_referrer: Option<&ModuleLoadReferrer>,
-        _options: ModuleLoadOptions,
+        options: ModuleLoadOptions,
     ) -> ModuleLoadResponse {
-        ModuleLoadResponse::Sync(self.load_inner(module_specifier))
+        let json_requested = matches!(options.requested_module_type, RequestedModuleType::Json);
+        let raw_module_type = match options.requested_module_type {
+            RequestedModuleType::Text => Some(ModuleType::Text),
+            RequestedModuleType::Bytes => Some(ModuleType::Bytes),
+            _ => None,
+        };
+
+        if options.is_synchronous {
+            // `require()` cannot pass import attributes, so synchronous loads
+            // are always regular modules.
+            return ModuleLoadResponse::Sync(match raw_module_type {
+                Some(_) => Err(ModuleLoaderError::type_error(format!(
+                    "Text and bytes imports cannot be loaded synchronously: {}",
+                    module_specifier
+                ))),
+                None => self.load_sync(module_specifier, json_requested),
+            });
+        }
+
+        // The future owns a cheap clone of the loader: every field is a
+        // shared handle. Dropping the future (for example when the worker
+        // terminates) aborts any in-flight request.
+        let loader = self.clone();
+        let module_specifier = module_specifier.clone();
+        ModuleLoadResponse::Async(Box::pin(async move {
+            match raw_module_type {
+                Some(module_type) => loader.load_raw(&module_specifier, module_type).await,
+                None => loader.load_async(&module_specifier, json_requested).await,
+            }
+        }))
     }
 
     fn load_external_source_map(&self, source_map_url: &str) -> Option<Cow<'_, [u8]>> {
         let specifier = ModuleSpecifier::parse(source_map_url).ok()?;
 
         let source_map = match specifier.scheme() {
-            "data" => parse_data_url(source_map_url)?.1.into_bytes(),
+            "data" => parse_data_url(source_map_url)?.1,
             "file" => std::fs::read(specifier.to_file_path().ok()?).ok()?,
-            "http" if !self.allows_http_imports() => return None,
-            "http" | "https" => ureq::get(source_map_url)
-                .call()
-                .ok()?
-                .into_string()
-                .ok()?
-                .into_bytes(),
+            // Errors are formatted on the isolate thread, so remote source
+            // maps come from the registry cache rather than the network.
+            "http" | "https" => self.jsr_resolver().ok()?.cached_module(&specifier)?.body,
             _ => return None,
         };
 
@@ -640,13 +1681,39 @@ impl ModuleLoader for TypescriptModuleLoader {
             .map(Cow::Owned)
     }
 
+    /// Reads a line of an original source for error messages. Local files
+    /// and virtual modules, such as Yasumu hooks, are covered.
+    fn get_source_mapped_source_line(&self, file_name: &str, line_number: usize) -> Option<String> {
+        let full_prefix = format!("{}{}", YASUMU_INTERNAL_PREFIX, YASUMU_VIRTUAL_PREFIX);
+        let source = match file_name.strip_prefix(&full_prefix) {
+            Some(identifier) => self
+                .virtual_modules
+                .as_ref()?
+                .lock()
+                .ok()?
+                .get(identifier)
+                .cloned()?,
+            None => {
+                let path = ModuleSpecifier::parse(file_name)
+                    .ok()?
+                    .to_file_path()
+                    .ok()?;
+                std::fs::read_to_string(path).ok()?
+            }
+        };
+        // `split` rather than `lines`, which would drop a trailing empty line.
+        source
+            .split('\n')
+            .nth(line_number)
+            .map(|line| line.trim_end_matches('\r').to_string())
+    }
+
     fn source_map_source_exists(&self, source_url: &str) -> Option<bool> {
         let specifier = ModuleSpecifier::parse(source_url).ok()?;
 
         match specifier.scheme() {
             "file" => Some(specifier.to_file_path().ok()?.exists()),
-            "http" if !self.allows_http_imports() => None,
-            "http" | "https" => Some(ureq::head(source_url).call().is_ok()),
+            // Unknown, rather than a request on the isolate thread.
             _ => None,
         }
     }
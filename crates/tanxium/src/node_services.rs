@@ -10,6 +10,7 @@ use deno_runtime::deno_node::NodeResolver;
 use node_resolver::DenoIsBuiltInNodeModuleChecker;
 use node_resolver::NodeResolverOptions;
 use node_resolver::PackageJsonResolver;
+use node_resolver::PackageJsonThreadLocalCache;
 use node_resolver::cache::NodeResolutionSys;
 use node_resolver::errors::PackageJsonLoadError;
 use std::borrow::Cow;
@@ -21,16 +22,23 @@ use sys_traits::impls::RealSys;
 
 use deno_error::JsErrorBox;
 
+use crate::cjs::{self, ModuleFormat};
+use crate::resolution;
+
 pub struct TanxiumNodeRequireLoader {
     sys: RealSys,
     #[allow(dead_code)]
     in_npm_pkg_checker: DenoInNpmPackageChecker,
+    pkg_json_resolver: Arc<PackageJsonResolver<RealSys>>,
     /// Extra node_modules roots added when a real workspace is open.
     workspace_node_modules_dirs: Vec<PathBuf>,
 }
 
 impl TanxiumNodeRequireLoader {
-    pub fn new(workspace_dir: Option<&Path>) -> Self {
+    pub fn new(
+        workspace_dir: Option<&Path>,
+        pkg_json_resolver: Arc<PackageJsonResolver<RealSys>>,
+    ) -> Self {
         let sys = RealSys::default();
 
         // When a workspace directory is set, add both the Yasumu-namespaced
@@ -45,8 +53,9 @@ impl TanxiumNodeRequireLoader {
         };
 
         Self {
-            sys: sys.clone(),
+            sys,
             in_npm_pkg_checker: DenoInNpmPackageChecker::new(CreateInNpmPkgCheckerOptions::Byonm),
+            pkg_json_resolver,
             workspace_node_modules_dirs,
         }
     }
@@ -71,15 +80,21 @@ impl NodeRequireLoader for TanxiumNodeRequireLoader {
         }
     }
 
-    fn is_maybe_cjs(&self, _specifier: &ModuleSpecifier) -> Result<bool, PackageJsonLoadError> {
-        Ok(true)
+    /// Only files whose extension or package `"type"` makes them ESM are
+    /// ruled out; untyped `.js` files may still be CommonJS.
+    fn is_maybe_cjs(&self, specifier: &ModuleSpecifier) -> Result<bool, PackageJsonLoadError> {
+        let Ok(path) = specifier.to_file_path() else {
+            return Ok(false);
+        };
+
+        Ok(cjs::format_from_path(&self.pkg_json_resolver, &path)? != Some(ModuleFormat::Esm))
     }
 
     fn is_maybe_cjs_from_require(
         &self,
-        _specifier: &ModuleSpecifier,
+        specifier: &ModuleSpecifier,
     ) -> Result<bool, PackageJsonLoadError> {
-        Ok(true)
+        self.is_maybe_cjs(specifier)
     }
 
     fn resolve_require_node_module_paths(&self, from: &Path) -> Vec<String> {
@@ -95,6 +110,12 @@ impl NodeRequireLoader for TanxiumNodeRequireLoader {
             }
         }
 
+        tracing::debug!(
+            target: resolution::TRACE_TARGET,
+            "require() from {} searches: {}",
+            from.display(),
+            paths.join(", ")
+        );
         paths
     }
 }
@@ -129,9 +150,18 @@ pub fn create_node_resolver(
     ))
 }
 
+/// Creates the `package.json` resolver shared by module loading, format
+/// detection, and `require()`. Parsed files are cached per thread, and the
+/// calling thread's cache is cleared here, so each worker start and each
+/// module graph build, such as on a watch restart, observes the packages on
+/// disk at that time.
 pub fn create_pkg_json_resolver() -> Arc<PackageJsonResolver<RealSys>> {
+    PackageJsonThreadLocalCache::clear();
     let sys = RealSys::default();
-    Arc::new(PackageJsonResolver::new(sys, None))
+    Arc::new(PackageJsonResolver::new(
+        sys,
+        Some(Arc::new(PackageJsonThreadLocalCache)),
+    ))
 }
 
 /// Creates a BYONM npm resolver rooted at the active workspace. Resolution
@@ -0,0 +1,592 @@
+//! `npm:` specifiers backed by a managed package cache.
+//!
+//! Packages are installed into a content-addressed store at
+//! `<cache>/npm/store/<integrity>/node_modules/<name>`. Each package's
+//! dependencies are symlinked beside it, so Node resolution from inside the
+//! store finds exactly the versions the package was installed with.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+
+use base64::Engine;
+use deno_ast::ModuleSpecifier;
+use deno_runtime::deno_core::error::ModuleLoaderError;
+use deno_semver::{Version, VersionReq};
+use serde_json::Value;
+use sha1::Sha1;
+use sha2::{Digest, Sha512};
+
+use crate::cache::MetadataCache;
+use crate::remote::{self, FetchPolicy};
+
+/// Registry used when neither the host nor `NPM_CONFIG_REGISTRY` set one.
+const DEFAULT_REGISTRY: &str = "https://registry.npmjs.org/";
+
+/// Requests the abbreviated package document, which is much smaller than
+/// the full one and contains everything needed for installation.
+const PACKUMENT_ACCEPT: &str =
+    "application/vnd.npm.install-v1+json; q=1.0, application/json; q=0.8";
+
+/// Written once a store entry and its dependency links are complete.
+const COMPLETE_MARKER: &str = ".tanxium-complete";
+
+/// Package documents fetched recently, so resolving several packages that
+/// share dependencies does not refetch them.
+static PACKUMENTS: LazyLock<MetadataCache> = LazyLock::new(Default::default);
+
+/// A parsed `npm:<name>[@<range>][/<sub path>]` specifier.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct NpmSpecifier {
+    pub name: String,
+    pub version_req: Option<String>,
+    pub sub_path: Option<String>,
+}
+
+impl NpmSpecifier {
+    pub fn parse(specifier: &str) -> Option<Self> {
+        let rest = specifier.strip_prefix("npm:")?;
+        let rest = rest.strip_prefix('/').unwrap_or(rest);
+
+        // Scoped names contain one slash of their own.
+        let name_end = if rest.starts_with('@') {
+            let scope_end = rest.find('/')?;
+            rest[scope_end + 1..]
+                .find('/')
+                .map(|index| scope_end + 1 + index)
+        } else {
+            rest.find('/')
+        };
+        let (package, sub_path) = match name_end {
+            Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+            None => (rest, None),
+        };
+
+        if package.is_empty() {
+            return None;
+        }
+        let (name, version_req) = match package[1..].find('@') {
+            Some(index) => (&package[..index + 1], Some(&package[index + 2..])),
+            None => (package, None),
+        };
+        if name.is_empty() || name.ends_with('/') {
+            return None;
+        }
+
+        Some(Self {
+            name: name.to_string(),
+            version_req: version_req.filter(|req| !req.is_empty()).map(str::to_owned),
+            sub_path: sub_path.filter(|path| !path.is_empty()).map(str::to_owned),
+        })
+    }
+
+    /// The bare specifier handed to Node resolution, e.g. `preact/hooks`.
+    pub fn package_specifier(&self) -> String {
+        match &self.sub_path {
+            Some(sub_path) => format!("{}/{sub_path}", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Installs packages from a registry into the managed store.
+pub(crate) struct NpmInstaller {
+    registry: ModuleSpecifier,
+    store_dir: PathBuf,
+    registry_dir: PathBuf,
+    policy: FetchPolicy,
+}
+
+impl NpmInstaller {
+    /// Creates an installer. `registry` falls back to `NPM_CONFIG_REGISTRY`
+    /// and then the public registry. An explicitly configured `http:`
+    /// registry is trusted even when HTTP imports are disabled.
+    pub fn new(
+        cache_root: &Path,
+        registry: Option<&str>,
+        mut policy: FetchPolicy,
+    ) -> Result<Self, ModuleLoaderError> {
+        let registry = registry
+            .map(str::to_owned)
+            .or_else(|| std::env::var("NPM_CONFIG_REGISTRY").ok())
+            .filter(|registry| !registry.is_empty())
+            .unwrap_or_else(|| DEFAULT_REGISTRY.to_string());
+        let registry = registry_url(&registry)?;
+        policy.allow_http |= registry.scheme() == "http";
+
+        let npm_dir = cache_root.join("npm");
+        Ok(Self {
+            store_dir: npm_dir.join("store"),
+            registry_dir: npm_dir
+                .join("registry")
+                .join(registry.host_str().unwrap_or("local")),
+            registry,
+            policy,
+        })
+    }
+
+    /// Returns the store entry containing `path`, if it lies inside the
+    /// store.
+    pub fn store_entry_of(&self, path: &Path) -> Option<PathBuf> {
+        let relative = path.strip_prefix(&self.store_dir).ok()?;
+        let entry = relative.components().next()?;
+        Some(self.store_dir.join(entry))
+    }
+
+    /// Installs a package and its dependencies, returning the store entry
+    /// whose `node_modules` directory contains the package.
+    pub fn install(&self, specifier: &NpmSpecifier) -> Result<PathBuf, ModuleLoaderError> {
+        self.install_package(
+            &specifier.name,
+            specifier.version_req.as_deref(),
+            &mut HashMap::new(),
+        )
+    }
+
+    fn install_package(
+        &self,
+        name: &str,
+        version_req: Option<&str>,
+        installed: &mut HashMap<String, PathBuf>,
+    ) -> Result<PathBuf, ModuleLoaderError> {
+        validate_name(name).map_err(ModuleLoaderError::type_error)?;
+        let packument = self.packument(name)?;
+        let version = select_version(&packument, version_req).ok_or_else(|| {
+            ModuleLoaderError::type_error(format!(
+                "No version of npm package {name} matches {}",
+                version_req.unwrap_or("latest")
+            ))
+        })?;
+
+        let key = format!("{name}@{version}");
+        if let Some(entry) = installed.get(&key) {
+            return Ok(entry.clone());
+        }
+
+        let manifest = &packument["versions"][&version];
+        let address = content_address(&manifest["dist"]).ok_or_else(|| {
+            ModuleLoaderError::type_error(format!("npm package {key} has no integrity information"))
+        })?;
+        let entry = self.store_dir.join(address);
+        // Recorded before dependencies are visited so cycles terminate.
+        installed.insert(key.clone(), entry.clone());
+
+        if entry.join(COMPLETE_MARKER).is_file() {
+            return Ok(entry);
+        }
+
+        let package_dir = entry.join("node_modules").join(name);
+        if !package_dir.is_dir() {
+            self.download(&key, &manifest["dist"], &package_dir)?;
+        }
+
+        let dependencies = |field: &str| {
+            manifest
+                .get(field)
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+                .filter_map(|(name, req)| Some((name.clone(), req.as_str()?.to_string())))
+                .collect::<Vec<_>>()
+        };
+        let install_dependency =
+            |alias: &str, spec: &str, installed: &mut HashMap<String, PathBuf>| {
+                let (name, version_req) =
+                    dependency_target(alias, spec).map_err(ModuleLoaderError::type_error)?;
+                let dependency_entry = self.install_package(name, version_req, installed)?;
+                link_dependency(&entry, alias, name, &dependency_entry)
+                    .map_err(ModuleLoaderError::from_err)
+            };
+
+        // npm also lists optional dependencies under `dependencies`.
+        let optional = dependencies("optionalDependencies");
+        for (dependency, spec) in dependencies("dependencies") {
+            if optional.iter().any(|(name, _)| *name == dependency) {
+                continue;
+            }
+            install_dependency(&dependency, &spec, installed).map_err(|error| {
+                ModuleLoaderError::type_error(format!("While installing {key}: {error}"))
+            })?;
+        }
+
+        for (dependency, spec) in optional {
+            if let Err(error) = install_dependency(&dependency, &spec, installed) {
+                tracing::debug!("Skipping optional dependency {dependency} of {key}: {error}");
+            }
+        }
+
+        std::fs::write(entry.join(COMPLETE_MARKER), "").map_err(ModuleLoaderError::from_err)?;
+        Ok(entry)
+    }
+
+    /// Fetches a package document, falling back to the copy on disk when
+    /// the registry is unreachable.
+    fn packument(&self, name: &str) -> Result<Arc<Value>, ModuleLoaderError> {
+        let encoded = name.replace('/', "%2f");
+        let url = self.registry.join(&encoded).map_err(|error| {
+            ModuleLoaderError::type_error(format!("Invalid npm package name {name}: {error}"))
+        })?;
+
+        if let Some(packument) = PACKUMENTS.get(url.as_str()) {
+            return Ok(packument);
+        }
+
+        let cache_path = self.registry_dir.join(format!("{encoded}.json"));
+        let body = match remote::fetch_bytes(&url, &self.policy, Some(PACKUMENT_ACCEPT)) {
+            Ok(body) => {
+                write_atomically(&cache_path, &body).map_err(ModuleLoaderError::from_err)?;
+                body
+            }
+            Err(error) => std::fs::read(&cache_path).map_err(|_| error)?,
+        };
+
+        let packument = serde_json::from_slice::<Value>(&body).map_err(|error| {
+            ModuleLoaderError::type_error(format!("Invalid registry response for {name}: {error}"))
+        })?;
+        let packument = Arc::new(packument);
+        PACKUMENTS.insert(url.to_string(), packument.clone());
+        Ok(packument)
+    }
+
+    fn download(
+        &self,
+        key: &str,
+        dist: &Value,
+        package_dir: &Path,
+    ) -> Result<(), ModuleLoaderError> {
+        let tarball = dist["tarball"]
+            .as_str()
+            .and_then(|url| ModuleSpecifier::parse(url).ok())
+            .ok_or_else(|| {
+                ModuleLoaderError::type_error(format!("npm package {key} has no tarball URL"))
+            })?;
+
+        let bytes = remote::fetch_bytes(&tarball, &self.policy, None)?;
+        verify_integrity(&bytes, dist)
+            .map_err(|error| ModuleLoaderError::type_error(format!("{key}: {error}")))?;
+
+        // Extract beside the destination and rename into place so an
+        // interrupted install never leaves a partial package behind.
+        let staging =
+            self.store_dir
+                .join(format!(".staging-{}-{}", std::process::id(), cuid2::cuid()));
+        let result = extract_tarball(&bytes, &staging).and_then(|()| {
+            std::fs::create_dir_all(package_dir.parent().unwrap())?;
+            match std::fs::rename(&staging, package_dir) {
+                // Another process installed the same package first.
+                Err(_) if package_dir.is_dir() => Ok(()),
+                result => result,
+            }
+        });
+        let _ = std::fs::remove_dir_all(&staging);
+
+        result.map_err(|error| {
+            ModuleLoaderError::type_error(format!("Failed to extract {key}: {error}"))
+        })
+    }
+}
+
+fn registry_url(registry: &str) -> Result<ModuleSpecifier, ModuleLoaderError> {
+    let registry = if registry.ends_with('/') {
+        registry.to_string()
+    } else {
+        format!("{registry}/")
+    };
+
+    ModuleSpecifier::parse(&registry).map_err(|error| {
+        ModuleLoaderError::type_error(format!("Invalid npm registry URL {registry}: {error}"))
+    })
+}
+
+/// Checks a package name against npm's rules closely enough that it is
+/// safe to use as a path: an optional `@scope/` followed by a name, each
+/// made of URL-safe characters and not starting with `.` or `_`.
+fn validate_name(name: &str) -> Result<(), String> {
+    let (scope, package) = match name.strip_prefix('@') {
+        Some(scoped) => match scoped.split_once('/') {
+            Some((scope, package)) => (Some(scope), package),
+            None => (None, ""),
+        },
+        None => (None, name),
+    };
+    let is_valid = |part: &str| {
+        !part.is_empty()
+            && !part.starts_with(['.', '_'])
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-._~!*'()".contains(c))
+    };
+
+    if name.len() <= 214 && scope.is_none_or(is_valid) && is_valid(package) {
+        Ok(())
+    } else {
+        Err(format!("Invalid npm package name {name:?}"))
+    }
+}
+
+/// Resolves a `dependencies` entry to the package and version range to
+/// install. `npm:<name>@<range>` aliases install `<name>` under the alias;
+/// tarball URLs, git repositories, and local paths are not supported.
+fn dependency_target<'a>(
+    alias: &'a str,
+    spec: &'a str,
+) -> Result<(&'a str, Option<&'a str>), String> {
+    validate_name(alias)?;
+    if let Some(aliased) = spec.strip_prefix("npm:") {
+        let (name, version_req) = match aliased.get(1..).and_then(|rest| rest.find('@')) {
+            Some(index) => (&aliased[..index + 1], Some(&aliased[index + 2..])),
+            None => (aliased, None),
+        };
+        return Ok((name, version_req.filter(|req| !req.is_empty())));
+    }
+
+    let has_prefix = |prefixes: &[&str]| prefixes.iter().any(|prefix| spec.starts_with(prefix));
+    let kind = if has_prefix(&["http:", "https:"]) {
+        "a tarball URL"
+    } else if has_prefix(&["git+", "git:", "git@", "github:", "gitlab:", "bitbucket:"])
+        || (spec.contains('/') && !spec.contains(':') && !has_prefix(&[".", "/"]))
+    {
+        "a git repository"
+    } else if has_prefix(&["file:", "link:", "workspace:", ".", "/"]) {
+        "a local path"
+    } else {
+        return Ok((alias, Some(spec)));
+    };
+    Err(format!(
+        "Dependency {alias} on {kind} ({spec}) is not supported; only registry versions and npm: aliases can be installed"
+    ))
+}
+
+/// Picks the version for a range: a matching dist-tag, then `latest` when it
+/// satisfies the range, then the highest matching version.
+fn select_version(packument: &Value, version_req: Option<&str>) -> Option<String> {
+    let dist_tags = &packument["dist-tags"];
+    let version_req = version_req.unwrap_or("latest");
+    if let Some(version) = dist_tags[version_req].as_str() {
+        return Some(version.to_string());
+    }
+
+    let req = VersionReq::parse_from_npm(version_req).ok()?;
+    let latest = dist_tags["latest"]
+        .as_str()
+        .and_then(|latest| Version::parse_from_npm(latest).ok());
+    if let Some(latest) = latest.filter(|latest| req.matches(latest)) {
+        return Some(latest.to_string());
+    }
+
+    packument["versions"]
+        .as_object()?
+        .keys()
+        .filter_map(|version| Version::parse_from_npm(version).ok())
+        .filter(|version| req.matches(version))
+        .max()
+        .map(|version| version.to_string())
+}
+
+/// Names a store entry after the tarball's expected `sha512` or `sha1`
+/// digest. Other algorithms are ignored.
+fn content_address(dist: &Value) -> Option<String> {
+    if let Some((algorithm, digest)) = dist["integrity"]
+        .as_str()
+        .into_iter()
+        .flat_map(str::split_whitespace)
+        .filter_map(|integrity| integrity.split_once('-'))
+        .find(|(algorithm, _)| matches!(*algorithm, "sha512" | "sha1"))
+    {
+        let digest = base64::engine::general_purpose::STANDARD
+            .decode(digest)
+            .ok()?;
+        return Some(format!("{algorithm}-{}", hex::encode(digest)));
+    }
+
+    dist["shasum"]
+        .as_str()
+        .filter(|shasum| shasum.len() == 40 && shasum.chars().all(|c| c.is_ascii_hexdigit()))
+        .map(|shasum| format!("sha1-{}", shasum.to_ascii_lowercase()))
+}
+
+fn verify_integrity(bytes: &[u8], dist: &Value) -> Result<(), String> {
+    let expected = content_address(dist).ok_or("missing integrity information")?;
+    let actual = match expected.split_once('-').map(|(algorithm, _)| algorithm) {
+        Some("sha512") => format!("sha512-{}", hex::encode(Sha512::digest(bytes))),
+        Some("sha1") => format!("sha1-{}", hex::encode(Sha1::digest(bytes))),
+        _ => return Err(format!("unsupported integrity algorithm in {expected}")),
+    };
+
+    if actual == expected {
+        Ok(())
+    } else {
+        Err("tarball integrity check failed".to_string())
+    }
+}
+
+/// Unpacks an npm tarball, dropping its top-level directory. Only regular
+/// files and directories are written; links and entries that would escape
+/// the destination are skipped.
+fn extract_tarball(bytes: &[u8], destination: &Path) -> io::Result<()> {
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(bytes));
+    std::fs::create_dir_all(destination)?;
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        let relative = path.components().skip(1).collect::<PathBuf>();
+        let is_safe = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+        if relative.as_os_str().is_empty() || !is_safe {
+            continue;
+        }
+
+        let target = destination.join(relative);
+        let entry_type = entry.header().entry_type();
+        if entry_type.is_dir() {
+            std::fs::create_dir_all(&target)?;
+        } else if entry_type.is_file() {
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            entry.unpack(&target)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Links a dependency into a store entry under `alias`, which differs from
+/// the package's `name` for `npm:` aliases.
+fn link_dependency(
+    entry: &Path,
+    alias: &str,
+    name: &str,
+    dependency_entry: &Path,
+) -> io::Result<()> {
+    let link = entry.join("node_modules").join(alias);
+    if link.symlink_metadata().is_ok() {
+        return Ok(());
+    }
+    if let Some(parent) = link.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let target = dependency_entry.join("node_modules").join(name);
+    #[cfg(unix)]
+    let result = std::os::unix::fs::symlink(&target, &link);
+    // Junctions, unlike directory symlinks, need no special privileges.
+    #[cfg(windows)]
+    let result = junction::create(&target, &link);
+
+    match result {
+        Err(error) if error.kind() == io::ErrorKind::AlreadyExists => Ok(()),
+        result => result,
+    }
+}
+
+fn write_atomically(path: &Path, contents: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let staging = path.with_extension(format!("tmp-{}", std::process::id()));
+    std::fs::write(&staging, contents)?;
+    std::fs::rename(&staging, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        NpmSpecifier, content_address, dependency_target, select_version, validate_name,
+        verify_integrity,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn parses_scoped_and_unscoped_specifiers() {
+        assert_eq!(
+            NpmSpecifier::parse("npm:@scope/pkg@^1.2/sub/path.js"),
+            Some(NpmSpecifier {
+                name: "@scope/pkg".to_string(),
+                version_req: Some("^1.2".to_string()),
+                sub_path: Some("sub/path.js".to_string()),
+            })
+        );
+        assert_eq!(
+            NpmSpecifier::parse("npm:preact/hooks").map(|spec| spec.package_specifier()),
+            Some("preact/hooks".to_string())
+        );
+        assert_eq!(NpmSpecifier::parse("npm:"), None);
+    }
+
+    #[test]
+    fn selects_tags_latest_and_highest_matching_versions() {
+        let packument = json!({
+            "dist-tags": { "latest": "1.2.0", "next": "2.0.0-beta.1" },
+            "versions": { "1.0.0": {}, "1.2.0": {}, "1.3.0": {}, "2.0.0-beta.1": {} },
+        });
+
+        assert_eq!(select_version(&packument, None).as_deref(), Some("1.2.0"));
+        assert_eq!(
+            select_version(&packument, Some("^1.0.0")).as_deref(),
+            Some("1.2.0")
+        );
+        assert_eq!(
+            select_version(&packument, Some("~1.3")).as_deref(),
+            Some("1.3.0")
+        );
+        assert_eq!(
+            select_version(&packument, Some("next")).as_deref(),
+            Some("2.0.0-beta.1")
+        );
+        assert_eq!(select_version(&packument, Some("^3")), None);
+    }
+
+    #[test]
+    fn resolves_aliases_and_rejects_unsupported_dependencies() {
+        assert_eq!(
+            dependency_target("string-width-cjs", "npm:string-width@^4.2.0"),
+            Ok(("string-width", Some("^4.2.0")))
+        );
+        assert_eq!(
+            dependency_target("types", "npm:@types/node"),
+            Ok(("@types/node", None))
+        );
+        assert_eq!(
+            dependency_target("glob", "~10.4"),
+            Ok(("glob", Some("~10.4")))
+        );
+        for spec in [
+            "https://example.com/pkg.tgz",
+            "git+https://github.com/user/repo.git",
+            "user/repo",
+            "file:../pkg",
+        ] {
+            assert!(dependency_target("pkg", spec).is_err(), "{spec}");
+        }
+
+        assert!(validate_name("@scope/pkg").is_ok());
+        for name in [
+            "../evil",
+            "@scope/..",
+            "a\\b",
+            "@scope",
+            "a/b",
+            "_private",
+            "",
+        ] {
+            assert!(validate_name(name).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn rejects_tarballs_that_do_not_match_their_integrity() {
+        // sha1("hello")
+        let dist = json!({ "shasum": "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d" });
+
+        assert!(verify_integrity(b"hello", &dist).is_ok());
+        assert!(verify_integrity(b"tampered", &dist).is_err());
+        assert_eq!(
+            content_address(&json!({ "integrity": "../../x-AAAA" })),
+            None
+        );
+    }
+}
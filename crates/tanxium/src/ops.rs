@@ -1,7 +1,14 @@
+use crate::auth_tokens::AuthTokens;
 use crate::state::RuntimeEvent;
-use crate::types::RuntimeHostState;
+use crate::test_snapshots;
+use crate::types::{
+    HighResolutionClock, RuntimeHostState, SnapshotScope, SupervisedWorker, WorkerExit,
+};
 use cuid2::cuid;
 use deno_core::{OpState, op2};
+use deno_runtime::deno_permissions::{OpenAccessKind, PermissionsContainer};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 
 #[inline]
 fn get_runtime(state: &OpState) -> RuntimeHostState {
@@ -179,6 +186,19 @@ fn op_get_workspace_dir(state: &mut OpState) -> Option<String> {
         .map(|p| p.to_string_lossy().into_owned())
 }
 
+/// Replaces the credentials sent with remote module requests. The tokens are
+/// write-only; there is intentionally no getter.
+#[op2(fast)]
+fn op_set_module_auth_tokens(state: &mut OpState, #[string] tokens: &str) {
+    let runtime = get_runtime(state);
+    let mut guard = runtime
+        .state
+        .context
+        .write()
+        .expect("runtime context lock poisoned");
+    guard.auth_tokens = AuthTokens::parse(tokens);
+}
+
 #[op2]
 #[string]
 fn op_get_app_data_dir(state: &mut OpState) -> String {
@@ -207,6 +227,140 @@ fn op_show_confirmation_dialog_sync(
         .confirm(title, message, yes_label, no_label, cancel_label)
 }
 
+/// The environment scripts see when the host sets variables or hides the
+/// process environment, or `None` to use the process environment as is.
+#[op2]
+#[serde]
+fn op_get_env_overrides(state: &mut OpState) -> Option<HashMap<String, String>> {
+    let runtime = get_runtime(state);
+    let context = runtime
+        .state
+        .context
+        .read()
+        .expect("runtime context lock poisoned");
+    if context.env.is_empty() && !context.clear_env {
+        return None;
+    }
+
+    let mut env = HashMap::new();
+    if !context.clear_env {
+        env.extend(
+            std::env::vars_os().filter_map(|(key, value)| {
+                Some((key.into_string().ok()?, value.into_string().ok()?))
+            }),
+        );
+    }
+    env.extend(context.env.clone());
+    Some(env)
+}
+
+/// Resolution of `Yasumu.hrtime()` outside benchmark workers, in nanoseconds.
+const COARSE_HRTIME_NS: f64 = 100_000.0;
+
+/// Monotonic nanoseconds since the first call, for benchmarks. Workers with a
+/// [`HighResolutionClock`] read the platform clock at full resolution; every
+/// other script gets 100 microsecond steps, so it cannot serve as a precise
+/// timer for side-channel attacks.
+#[op2(fast)]
+fn op_hrtime(state: &mut OpState) -> f64 {
+    static ORIGIN: std::sync::LazyLock<std::time::Instant> =
+        std::sync::LazyLock::new(std::time::Instant::now);
+    let elapsed = ORIGIN.elapsed().as_nanos() as f64;
+    if state.has::<HighResolutionClock>() {
+        elapsed
+    } else {
+        (elapsed / COARSE_HRTIME_NS).floor() * COARSE_HRTIME_NS
+    }
+}
+
+/// Replaces Deno's `op_exit`, which ends the host process, so that
+/// `Deno.exit` only stops the main worker that called it. The supervised
+/// worker throws instead and is restarted as after a crash. Web workers have
+/// neither marker and keep ignoring the call.
+#[op2(fast)]
+fn op_tanxium_exit(state: &mut OpState) -> Result<(), std::io::Error> {
+    let code = state
+        .try_borrow::<deno_runtime::deno_os::ExitCode>()
+        .map(|code| code.get())
+        .unwrap_or_default();
+    if let Some(exit) = state.try_borrow::<WorkerExit>() {
+        (exit.0)(code);
+    } else if state.try_borrow::<SupervisedWorker>().is_some() {
+        return Err(std::io::Error::other(format!(
+            "Deno.exit({code}) cannot stop the supervised runtime; it is restarted as after a crash"
+        )));
+    }
+    Ok(())
+}
+
+/// Locates the snapshot file of `test` for a test worker. Only the running
+/// test file's own snapshots and named snapshots in the workspace are
+/// reachable, and the worker's permissions must allow the access.
+fn snapshot_path(
+    state: &OpState,
+    test: &str,
+    access: OpenAccessKind,
+    api_name: &str,
+) -> std::io::Result<std::path::PathBuf> {
+    let denied =
+        |message: String| std::io::Error::new(std::io::ErrorKind::PermissionDenied, message);
+    let scope = state.try_borrow::<SnapshotScope>().ok_or_else(|| {
+        denied(format!(
+            "{api_name} is only available to test files run by `tanxium test`"
+        ))
+    })?;
+
+    let runtime = get_runtime(state);
+    let workspace_dir = runtime
+        .state
+        .context
+        .read()
+        .expect("runtime context lock poisoned")
+        .workspace_dir
+        .clone();
+    let path = test_snapshots::snapshot_path(test, workspace_dir.as_deref())?;
+    if !test_snapshots::in_scope(&path, &scope.test_file, workspace_dir.as_deref()) {
+        return Err(denied(format!(
+            "{api_name} cannot access {}: test files may only use their own snapshots",
+            path.display()
+        )));
+    }
+
+    state
+        .borrow::<PermissionsContainer>()
+        .check_open(Cow::Borrowed(path.as_path()), access, Some(api_name))
+        .map_err(|error| denied(error.to_string()))?;
+    Ok(path)
+}
+
+/// Reads the snapshots stored for a test file or named test.
+#[op2]
+#[serde]
+fn op_read_snapshots(
+    state: &mut OpState,
+    #[string] test: &str,
+) -> Result<BTreeMap<String, String>, std::io::Error> {
+    let path = snapshot_path(state, test, OpenAccessKind::Read, "Yasumu.readSnapshots()")?;
+    test_snapshots::read(&path)
+}
+
+/// Replaces the snapshots stored for a test file or named test. An empty map
+/// removes the snapshot file.
+#[op2]
+fn op_write_snapshots(
+    state: &mut OpState,
+    #[string] test: &str,
+    #[serde] snapshots: BTreeMap<String, String>,
+) -> Result<(), std::io::Error> {
+    let path = snapshot_path(
+        state,
+        test,
+        OpenAccessKind::Write,
+        "Yasumu.writeSnapshots()",
+    )?;
+    test_snapshots::write(&path, &snapshots)
+}
+
 deno_core::extension!(
     tanxium_rt,
     ops = [
@@ -227,6 +381,11 @@ deno_core::extension!(
         op_show_confirmation_dialog_sync,
         op_set_workspace_dir,
         op_get_workspace_dir,
+        op_set_module_auth_tokens,
+        op_read_snapshots,
+        op_write_snapshots,
+        op_hrtime,
+        op_get_env_overrides,
     ],
     esm_entry_point = "ext:tanxium_rt/bootstrap.ts",
     esm = [
@@ -234,6 +393,7 @@ deno_core::extension!(
         "bootstrap.ts",
         "ui.ts",
         "patches.ts",
+        "env.ts",
         "utils.ts",
         "common.ts",
         "yasumu-request.ts",
@@ -241,6 +401,10 @@ deno_core::extension!(
         "message-queue.ts",
         "modules/collection.ts",
     ],
+    middleware = |op| match op.name {
+        "op_exit" => op.with_implementation_from(&op_tanxium_exit()),
+        _ => op,
+    },
     state = |state| {
         state.put::<deno_runtime::ops::bootstrap::SnapshotOptions>(
             deno_runtime::ops::bootstrap::SnapshotOptions::default(),
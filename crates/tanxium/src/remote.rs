@@ -0,0 +1,306 @@
+//! Redirect-aware fetching for remote `http:` and `https:` modules.
+
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use deno_ast::ModuleSpecifier;
+use deno_runtime::deno_core::error::ModuleLoaderError;
+
+use crate::auth_tokens::AuthTokens;
+use crate::version::TANXIUM_VERSION;
+
+/// Maximum number of redirects followed for a single remote request.
+const MAX_REDIRECTS: usize = 10;
+
+/// Per-request timeout used when the host does not configure one.
+pub(crate) const DEFAULT_FETCH_TIMEOUT: Duration = Duration::from_secs(60);
+
+thread_local! {
+    /// Each worker thread drives its own Tokio runtime, and pooled
+    /// connections are bound to the runtime that opened them, so the client
+    /// is kept per thread rather than shared process-wide. Redirects are
+    /// followed manually so every hop can be checked against the runtime's
+    /// HTTP import policy.
+    static CLIENT: reqwest::Client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .user_agent(format!("Tanxium/{TANXIUM_VERSION}"))
+        .build()
+        .expect("failed to build the remote module HTTP client");
+}
+
+/// Drives requests made from synchronous code, such as registry downloads
+/// on the blocking pool and `require()` of a remote module.
+static FETCH_RUNTIME: LazyLock<tokio::runtime::Runtime> = LazyLock::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("tanxium-fetch")
+        .enable_all()
+        .build()
+        .expect("failed to start the remote module fetch runtime")
+});
+
+/// Import policy applied to every remote request and redirect hop.
+#[derive(Clone, Debug)]
+pub(crate) struct FetchPolicy {
+    pub allow_http: bool,
+    pub timeout: Duration,
+    /// Credentials are looked up for every hop, so a redirect to another
+    /// host never forwards the original host's token.
+    pub auth_tokens: AuthTokens,
+}
+
+/// A remote response after all redirects have been followed.
+pub(crate) struct RemoteResponse {
+    /// The URL that actually served the body. Relative imports inside the
+    /// module must resolve against this URL rather than the requested one.
+    pub url: ModuleSpecifier,
+    pub content_type: Option<String>,
+    /// The raw body; module sources are decoded by the loader, so binary
+    /// modules such as WebAssembly arrive intact.
+    pub body: Vec<u8>,
+}
+
+/// Fetches a remote module, blocking the calling thread until it arrives.
+/// The request itself runs on a shared fetch runtime, so this may be called
+/// from any thread.
+pub(crate) fn fetch(
+    specifier: &ModuleSpecifier,
+    policy: &FetchPolicy,
+) -> Result<RemoteResponse, ModuleLoaderError> {
+    wait_for(specifier, policy, None)
+}
+
+/// Fetches a binary resource, such as registry metadata or a package
+/// tarball, blocking the calling thread.
+pub(crate) fn fetch_bytes(
+    specifier: &ModuleSpecifier,
+    policy: &FetchPolicy,
+    accept: Option<&str>,
+) -> Result<Vec<u8>, ModuleLoaderError> {
+    Ok(wait_for(specifier, policy, accept)?.body)
+}
+
+fn wait_for(
+    specifier: &ModuleSpecifier,
+    policy: &FetchPolicy,
+    accept: Option<&str>,
+) -> Result<RemoteResponse, ModuleLoaderError> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let (specifier, policy, accept) =
+        (specifier.clone(), policy.clone(), accept.map(str::to_owned));
+    FETCH_RUNTIME.spawn(async move {
+        let response = send(&specifier, &policy, accept.as_deref()).await;
+        let _ = sender.send(response);
+    });
+
+    receiver
+        .recv()
+        .map_err(|_| ModuleLoaderError::type_error("The remote module fetch was interrupted"))?
+}
+
+/// Fetches a remote module without blocking the runtime thread.
+///
+/// Dropping the returned future aborts the request.
+pub(crate) async fn fetch_async(
+    specifier: &ModuleSpecifier,
+    policy: &FetchPolicy,
+) -> Result<RemoteResponse, ModuleLoaderError> {
+    send(specifier, policy, None).await
+}
+
+async fn send(
+    specifier: &ModuleSpecifier,
+    policy: &FetchPolicy,
+    accept: Option<&str>,
+) -> Result<RemoteResponse, ModuleLoaderError> {
+    check_scheme(specifier, policy.allow_http)?;
+
+    let client = CLIENT.with(Clone::clone);
+    let mut url = specifier.clone();
+    for _ in 0..=MAX_REDIRECTS {
+        let mut request = client.get(url.as_str()).timeout(policy.timeout);
+        if let Some(accept) = accept {
+            request = request.header(reqwest::header::ACCEPT, accept);
+        }
+        if let Some(authorization) = policy.auth_tokens.authorization_for(&url) {
+            request = request.header(reqwest::header::AUTHORIZATION, authorization);
+        }
+        let response = request.send().await.map_err(|error| {
+            ModuleLoaderError::type_error(format!("Failed to fetch {url}: {error}"))
+        })?;
+
+        let status = response.status();
+        if status.is_redirection() {
+            let location = response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .ok_or_else(|| missing_location(&url))?;
+            url = redirect_target(&url, location, policy.allow_http)?;
+            continue;
+        }
+
+        if !status.is_success() {
+            return Err(ModuleLoaderError::type_error(format!(
+                "Import '{url}' failed: {status}"
+            )));
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(str::to_owned);
+        let body = response.bytes().await.map_err(|error| {
+            ModuleLoaderError::type_error(format!("Failed to read {url}: {error}"))
+        })?;
+
+        return Ok(RemoteResponse {
+            url,
+            content_type,
+            body: body.to_vec(),
+        });
+    }
+
+    Err(too_many_redirects(specifier))
+}
+
+fn missing_location(url: &ModuleSpecifier) -> ModuleLoaderError {
+    ModuleLoaderError::type_error(format!(
+        "Redirect from {url} did not include a Location header"
+    ))
+}
+
+fn too_many_redirects(specifier: &ModuleSpecifier) -> ModuleLoaderError {
+    ModuleLoaderError::type_error(format!(
+        "Too many redirects (more than {MAX_REDIRECTS}) while fetching {specifier}"
+    ))
+}
+
+fn check_scheme(url: &ModuleSpecifier, allow_http: bool) -> Result<(), ModuleLoaderError> {
+    match url.scheme() {
+        "https" => Ok(()),
+        "http" if allow_http => Ok(()),
+        "http" => Err(ModuleLoaderError::type_error(
+            "HTTP imports are disabled; rerun with --allow-http-imports",
+        )),
+        scheme => Err(ModuleLoaderError::type_error(format!(
+            "Unsupported remote module scheme: {scheme}"
+        ))),
+    }
+}
+
+/// Resolves a `Location` header against the current URL and applies the
+/// import policy to the redirect target.
+fn redirect_target(
+    current: &ModuleSpecifier,
+    location: &str,
+    allow_http: bool,
+) -> Result<ModuleSpecifier, ModuleLoaderError> {
+    let target = current.join(location).map_err(|error| {
+        ModuleLoaderError::type_error(format!(
+            "Invalid redirect location {location:?} from {current}: {error}"
+        ))
+    })?;
+
+    if current.scheme() == "https" && target.scheme() == "http" && !allow_http {
+        return Err(ModuleLoaderError::type_error(format!(
+            "Refusing to follow a redirect from HTTPS to HTTP ({current} -> {target}); \
+             rerun with --allow-http-imports"
+        )));
+    }
+
+    check_scheme(&target, allow_http)?;
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FetchPolicy, fetch, fetch_async, redirect_target};
+    use deno_ast::ModuleSpecifier;
+    use std::io::Read;
+    use std::net::{TcpListener, TcpStream};
+    use std::time::{Duration, Instant};
+
+    /// Accepts a single connection and never answers it.
+    fn stalled_server() -> (ModuleSpecifier, std::thread::JoinHandle<TcpStream>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/slow.ts", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || listener.accept().unwrap().0);
+        (ModuleSpecifier::parse(&url).unwrap(), server)
+    }
+
+    fn policy(timeout: Duration) -> FetchPolicy {
+        FetchPolicy {
+            allow_http: true,
+            timeout,
+            auth_tokens: Default::default(),
+        }
+    }
+
+    #[test]
+    fn resolves_relative_redirects_against_the_current_url() {
+        let current = ModuleSpecifier::parse("https://cdn.example/pkg@1/mod.ts").unwrap();
+        let target = redirect_target(&current, "../pkg@1.2.3/mod.ts", false).unwrap();
+
+        assert_eq!(target.as_str(), "https://cdn.example/pkg@1.2.3/mod.ts");
+    }
+
+    #[test]
+    fn refuses_https_to_http_downgrades_unless_allowed() {
+        let current = ModuleSpecifier::parse("https://cdn.example/mod.ts").unwrap();
+
+        assert!(redirect_target(&current, "http://cdn.example/mod.ts", false).is_err());
+        assert!(redirect_target(&current, "http://cdn.example/mod.ts", true).is_ok());
+        assert!(redirect_target(&current, "file:///etc/passwd", true).is_err());
+    }
+
+    #[tokio::test]
+    async fn times_out_requests_to_stalled_servers() {
+        let (url, _server) = stalled_server();
+        let started = Instant::now();
+
+        let error = fetch_async(&url, &policy(Duration::from_millis(200)))
+            .await
+            .err()
+            .expect("a stalled request should fail");
+
+        assert!(error.to_string().contains("Failed to fetch"));
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn blocking_fetches_can_be_made_inside_a_runtime() {
+        let (url, _server) = stalled_server();
+
+        // `require()` of a remote module fetches on the worker's runtime
+        // thread.
+        let error = fetch(&url, &policy(Duration::from_millis(200)))
+            .err()
+            .expect("a stalled request should fail");
+
+        assert!(error.to_string().contains("Failed to fetch"));
+    }
+
+    #[tokio::test]
+    async fn dropping_the_fetch_closes_the_connection() {
+        let (url, server) = stalled_server();
+
+        let fetch = fetch_async(&url, &policy(Duration::from_secs(60)));
+        assert!(
+            tokio::time::timeout(Duration::from_millis(200), fetch)
+                .await
+                .is_err()
+        );
+
+        let mut stream = server.join().unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let mut request = Vec::new();
+        // Reads the request and then hits EOF once the client hangs up,
+        // rather than the read timeout.
+        stream.read_to_end(&mut request).unwrap();
+        assert!(request.starts_with(b"GET /slow.ts"));
+    }
+}
@@ -0,0 +1,130 @@
+//! Diagnostics for package resolution.
+//!
+//! Every step is logged under the [`TRACE_TARGET`] tracing target, so
+//! embedders can follow a lookup with a filter such as
+//! `tanxium::resolution=debug` and the CLI with `--trace-resolution`.
+
+use std::path::{Path, PathBuf};
+
+use node_resolver::{PackageJsonResolver, ResolutionMode};
+use sys_traits::impls::RealSys;
+
+/// The tracing target resolution steps are logged under.
+pub const TRACE_TARGET: &str = "tanxium::resolution";
+
+/// Returns the package name of a bare specifier, without its subpath.
+pub(crate) fn package_name(specifier: &str) -> &str {
+    let mut separators = specifier.match_indices('/').map(|(index, _)| index);
+    let end = if specifier.starts_with('@') {
+        separators.nth(1)
+    } else {
+        separators.next()
+    };
+    end.map_or(specifier, |end| &specifier[..end])
+}
+
+/// Lists the directories a bare import from `base_dir` may resolve to,
+/// nearest first: `node_modules/<package>` in `base_dir` and each of its
+/// parents.
+pub(crate) fn package_candidates(specifier: &str, base_dir: &Path) -> Vec<PathBuf> {
+    let name = package_name(specifier);
+    base_dir
+        .ancestors()
+        .filter(|dir| dir.file_name().is_none_or(|name| name != "node_modules"))
+        .map(|dir| dir.join("node_modules").join(name))
+        .collect()
+}
+
+/// Logs each candidate directory of a package lookup and, for the first one
+/// that exists, the entrypoints its `package.json` declares.
+pub(crate) fn trace_package_lookup(
+    pkg_json_resolver: &PackageJsonResolver<RealSys>,
+    specifier: &str,
+    base_dir: &Path,
+    mode: ResolutionMode,
+) {
+    if !tracing::enabled!(target: TRACE_TARGET, tracing::Level::DEBUG) {
+        return;
+    }
+
+    let mode = match mode {
+        ResolutionMode::Import => "import",
+        ResolutionMode::Require => "require",
+    };
+    tracing::debug!(
+        target: TRACE_TARGET,
+        "Looking up package {specifier} ({mode}) from {}",
+        base_dir.display()
+    );
+
+    for candidate in package_candidates(specifier, base_dir) {
+        if !candidate.is_dir() {
+            tracing::debug!(target: TRACE_TARGET, "  {} (not found)", candidate.display());
+            continue;
+        }
+
+        tracing::debug!(target: TRACE_TARGET, "  {} (found)", candidate.display());
+        match pkg_json_resolver.load_package_json(&candidate.join("package.json")) {
+            Ok(Some(package_json)) => {
+                if let Some(exports) = &package_json.exports {
+                    let keys = exports.keys().map(String::as_str).collect::<Vec<_>>();
+                    tracing::debug!(
+                        target: TRACE_TARGET,
+                        "    exports: {}",
+                        keys.join(", ")
+                    );
+                } else if let Some(main) = &package_json.main {
+                    tracing::debug!(target: TRACE_TARGET, "    main: {main}");
+                }
+            }
+            Ok(None) => tracing::debug!(target: TRACE_TARGET, "    no package.json"),
+            Err(error) => tracing::debug!(target: TRACE_TARGET, "    {error}"),
+        }
+        break;
+    }
+}
+
+/// Appends the searched locations to a failed package lookup.
+pub(crate) fn describe_failure(error: impl std::fmt::Display, searched: &[PathBuf]) -> String {
+    if searched.is_empty() {
+        return error.to_string();
+    }
+
+    let searched = searched
+        .iter()
+        .map(|path| format!("\n  - {}", path.display()))
+        .collect::<String>();
+    format!("{error}\nSearched:{searched}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe_failure, package_candidates, package_name};
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn strips_subpaths_from_package_names() {
+        assert_eq!(package_name("preact"), "preact");
+        assert_eq!(package_name("preact/hooks"), "preact");
+        assert_eq!(package_name("@scope/pkg"), "@scope/pkg");
+        assert_eq!(package_name("@scope/pkg/sub/path"), "@scope/pkg");
+    }
+
+    #[test]
+    fn lists_node_modules_of_each_parent() {
+        let candidates = package_candidates("@scope/pkg/sub", Path::new("/work/app"));
+
+        assert_eq!(
+            candidates,
+            [
+                PathBuf::from("/work/app/node_modules/@scope/pkg"),
+                PathBuf::from("/work/node_modules/@scope/pkg"),
+                PathBuf::from("/node_modules/@scope/pkg"),
+            ]
+        );
+        assert_eq!(
+            describe_failure("Cannot find package", &candidates[..1]),
+            "Cannot find package\nSearched:\n  - /work/app/node_modules/@scope/pkg"
+        );
+    }
+}
@@ -1,17 +1,27 @@
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::Instant;
 
 use deno_runtime::deno_core::{ModuleSpecifier, error::AnyError};
 
+use crate::auth_tokens::AuthTokens;
+use crate::bench_runner::{self, BenchFileReport, BenchOptions, BenchReport};
+use crate::bundle::{self, Bundle};
+use crate::module_graph::{self, ModuleGraph};
+use crate::standalone::{self, CompileOptions, StandaloneArchive};
 use crate::state::{NoopHost, RuntimeContext, RuntimeHost, RuntimeState};
+use crate::test_runner::{self, TestFileReport, TestOptions, TestReport};
 use crate::version::TANXIUM_VERSION;
-use crate::worker::start_worker;
+use crate::watch::{FileWatcher, WatchOptions};
+use crate::worker::{ExecutionReport, WorkerRole, WorkerShutdown, start_worker};
 
 /// Configures an embeddable Tanxium runtime.
 pub struct TanxiumBuilder {
     context: RuntimeContext,
     host: Arc<dyn RuntimeHost>,
     main_worker_all_permissions: bool,
+    standalone: Option<Arc<StandaloneArchive>>,
 }
 
 impl TanxiumBuilder {
@@ -59,12 +69,129 @@ impl TanxiumBuilder {
         self
     }
 
+    /// Sets the per-request timeout for remote module fetches.
+    ///
+    /// Remote modules in a graph are fetched concurrently; a request that
+    /// exceeds this timeout fails the import instead of stalling the runtime.
+    pub fn module_fetch_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.context.module_fetch_timeout = Some(timeout);
+        self
+    }
+
+    /// Supplies per-host credentials for authenticated remote imports.
+    ///
+    /// Matching module, source-map, and existence-check requests receive a
+    /// bearer or basic `Authorization` header. Tokens are only sent over
+    /// HTTPS (or to loopback hosts) and are never exposed to scripts.
+    pub fn auth_tokens(mut self, tokens: AuthTokens) -> Self {
+        self.context.auth_tokens = tokens;
+        self
+    }
+
+    /// Supplies an import map applied to every worker.
+    ///
+    /// It replaces any map found in the workspace's `deno.json` or
+    /// `import_map.json`. Relative targets resolve against the workspace
+    /// directory.
+    pub fn import_map_json(mut self, json: impl Into<String>) -> Self {
+        self.context.import_map = Some(json.into());
+        self
+    }
+
+    /// Sets the directory used to cache `npm:` packages and `jsr:` modules.
+    ///
+    /// Defaults to `TANXIUM_DIR`, then `<app data>/cache`, then the platform
+    /// cache directory.
+    pub fn cache_dir(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.context.cache_dir = Some(path.into());
+        self
+    }
+
+    /// Sets the npm registry used for `npm:` specifiers, for example a local
+    /// mirror. Defaults to `NPM_CONFIG_REGISTRY` or `https://registry.npmjs.org/`.
+    pub fn npm_registry(mut self, url: impl Into<String>) -> Self {
+        self.context.npm_registry = Some(url.into());
+        self
+    }
+
+    /// Sets the JSR registry used for `jsr:` specifiers, for example a local
+    /// mirror. Defaults to `JSR_URL` or `https://jsr.io/`.
+    pub fn jsr_registry(mut self, url: impl Into<String>) -> Self {
+        self.context.jsr_registry = Some(url.into());
+        self
+    }
+
+    /// Sets the arguments scripts receive as `Deno.args`. `process.argv`
+    /// lists them after the runtime and script paths.
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.context.args = args.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets an environment variable for scripts, taking precedence over the
+    /// process environment.
+    ///
+    /// Once any variable is set, or [`Self::env_clear`] is called, each
+    /// worker gets a private copy of the environment: `Deno.env`,
+    /// `process.env`, and `Deno.Command` see these variables, while the host
+    /// process environment is never modified.
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.context.env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets several environment variables for scripts; see [`Self::env`].
+    pub fn envs<I, K, V>(mut self, vars: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.context.env.extend(
+            vars.into_iter()
+                .map(|(key, value)| (key.into(), value.into())),
+        );
+        self
+    }
+
+    /// Hides the host process environment from scripts, so that they only
+    /// see variables set with [`Self::env`].
+    pub fn env_clear(mut self) -> Self {
+        self.context.clear_env = true;
+        self
+    }
+
+    /// Runs modules from an archive embedded by [`Tanxium::compile`].
+    ///
+    /// The permission and HTTP import settings baked into the archive
+    /// replace the builder's, and its virtual modules are registered.
+    pub fn standalone(mut self, archive: StandaloneArchive) -> Self {
+        self.main_worker_all_permissions = archive.allow_all_permissions;
+        self.context.allow_http_imports = archive.allow_http_imports;
+        self.standalone = Some(Arc::new(archive));
+        self
+    }
+
     /// Builds an embeddable runtime instance.
     pub fn build(self) -> Result<Tanxium, AnyError> {
+        let state = Arc::new(RuntimeState::new(self.context));
+        if let Some(archive) = &self.standalone {
+            state
+                .virtual_modules
+                .lock()
+                .expect("virtual modules lock poisoned")
+                .extend(archive.virtual_modules());
+        }
+
         Ok(Tanxium {
-            state: Arc::new(RuntimeState::new(self.context)),
+            state,
             host: self.host,
             main_worker_all_permissions: self.main_worker_all_permissions,
+            standalone: self.standalone,
         })
     }
 }
@@ -75,6 +202,7 @@ pub struct Tanxium {
     state: Arc<RuntimeState>,
     host: Arc<dyn RuntimeHost>,
     main_worker_all_permissions: bool,
+    standalone: Option<Arc<StandaloneArchive>>,
 }
 
 impl Tanxium {
@@ -87,6 +215,7 @@ impl Tanxium {
             },
             host: Arc::new(NoopHost),
             main_worker_all_permissions: true,
+            standalone: None,
         }
     }
 
@@ -98,23 +227,257 @@ impl Tanxium {
             self.state.clone(),
             self.host.clone(),
             self.main_worker_all_permissions,
+            WorkerShutdown::default(),
+            self.standalone.clone(),
+            WorkerRole::Supervised,
         )
         .map(|_| ())
     }
 
     /// Runs a module and waits for its runtime thread to exit.
-    pub fn run_file_blocking(&self, file: impl AsRef<Path>) -> Result<(), AnyError> {
+    ///
+    /// Unlike [`Self::run_file`], a worker that fails is not restarted: the
+    /// uncaught error and the script's exit code are returned in the report.
+    /// `Deno.exit` stops the worker rather than the host process.
+    pub fn run_file_blocking(&self, file: impl AsRef<Path>) -> Result<ExecutionReport, AnyError> {
         let module = module_specifier_from_file(file)?;
         start_worker(
             module,
             self.state.clone(),
             self.host.clone(),
             self.main_worker_all_permissions,
+            WorkerShutdown::default(),
+            self.standalone.clone(),
+            WorkerRole::Script,
         )?
         .join()
         .map_err(|_| AnyError::msg("runtime thread panicked"))
     }
 
+    /// Runs a module and restarts it whenever a local file in its module
+    /// graph, or a path in `options`, changes. Blocks until the watcher fails.
+    ///
+    /// `on_restart` receives the changed paths before the running worker is
+    /// shut down. `on_exit` receives the report of each worker once it has
+    /// ended, either on its own or when it was shut down for a restart. A
+    /// worker that exits on its own is not restarted until the next change.
+    pub fn run_file_watching(
+        &self,
+        file: impl AsRef<Path>,
+        options: WatchOptions,
+        mut on_restart: impl FnMut(&[PathBuf]),
+        mut on_exit: impl FnMut(&ExecutionReport),
+    ) -> Result<(), AnyError> {
+        let module = module_specifier_from_file(file)?;
+        let mut watcher = FileWatcher::new(&options)?;
+        let mut finish = |worker: thread::JoinHandle<ExecutionReport>| {
+            let report = worker
+                .join()
+                .map_err(|_| AnyError::msg("runtime thread panicked"))?;
+            on_exit(&report);
+            Ok::<_, AnyError>(())
+        };
+
+        loop {
+            watcher.watch_graph(&self.state, &module);
+            let shutdown = WorkerShutdown::default();
+            let mut worker = Some(start_worker(
+                module.clone(),
+                self.state.clone(),
+                self.host.clone(),
+                self.main_worker_all_permissions,
+                shutdown.clone(),
+                self.standalone.clone(),
+                WorkerRole::Script,
+            )?);
+
+            let changed = watcher.wait_for_changes(|| {
+                match worker.take_if(|worker| worker.is_finished()) {
+                    Some(worker) => finish(worker),
+                    None => Ok(()),
+                }
+            })?;
+            on_restart(&changed);
+            shutdown.request();
+            if let Some(worker) = worker {
+                finish(worker)?;
+            }
+        }
+    }
+
+    /// Runs the entrypoint of the archive passed to
+    /// [`TanxiumBuilder::standalone`] and waits for its runtime thread to
+    /// exit, like [`Self::run_file_blocking`].
+    pub fn run_standalone_blocking(&self) -> Result<ExecutionReport, AnyError> {
+        let archive = self
+            .standalone
+            .as_ref()
+            .ok_or_else(|| AnyError::msg("no standalone archive was configured"))?;
+        start_worker(
+            ModuleSpecifier::parse(archive.entrypoint())?,
+            self.state.clone(),
+            self.host.clone(),
+            self.main_worker_all_permissions,
+            WorkerShutdown::default(),
+            self.standalone.clone(),
+            WorkerRole::Script,
+        )?
+        .join()
+        .map_err(|_| AnyError::msg("runtime thread panicked"))
+    }
+
+    /// Runs test files, each in a fresh main worker, and collects their
+    /// `yasumu:test` results.
+    ///
+    /// Up to [`TestOptions::jobs`] files run at once. Every test registered
+    /// by a file with `test` or `describe` runs unless excluded by
+    /// [`TestOptions::filter`]. `on_file` receives each file's report as
+    /// soon as it finishes; the returned report lists files in the order
+    /// given. Failures do not stop the run; check [`TestReport::success`].
+    pub fn test(
+        &self,
+        files: &[PathBuf],
+        options: &TestOptions,
+        mut on_file: impl FnMut(&TestFileReport),
+    ) -> Result<TestReport, AnyError> {
+        let started = Instant::now();
+        let files = match options.shard {
+            Some(shard) => shard.select(files),
+            None => files.to_vec(),
+        };
+        let pending = Mutex::new(files.iter().enumerate());
+        let (reports, finished) = mpsc::channel();
+
+        let mut file_reports = thread::scope(|scope| {
+            for _ in 0..options.jobs.clamp(1, files.len().max(1)) {
+                let (pending, reports) = (&pending, reports.clone());
+                scope.spawn(move || {
+                    loop {
+                        let Some((index, file)) =
+                            pending.lock().expect("test queue lock poisoned").next()
+                        else {
+                            break;
+                        };
+                        let report = module_specifier_from_file(file).and_then(|module| {
+                            test_runner::run_test_file(
+                                &self.state,
+                                &self.host,
+                                self.main_worker_all_permissions,
+                                self.standalone.clone(),
+                                file,
+                                &module,
+                                options,
+                            )
+                        });
+                        if reports.send((index, report)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(reports);
+
+            let mut file_reports = Vec::with_capacity(files.len());
+            for (index, report) in finished {
+                let report = report?;
+                on_file(&report);
+                file_reports.push((index, report));
+            }
+            Ok::<_, AnyError>(file_reports)
+        })?;
+
+        file_reports.sort_by_key(|(index, _)| *index);
+        Ok(TestReport {
+            files: file_reports.into_iter().map(|(_, report)| report).collect(),
+            duration: started.elapsed().as_secs_f64() * 1000.0,
+        })
+    }
+
+    /// Runs benchmark files one after another, each in a fresh main worker,
+    /// and collects their `yasumu:bench` measurements.
+    ///
+    /// Files never run in parallel, so they do not compete for the CPU.
+    /// `on_file` receives each file's report as soon as it finishes.
+    pub fn bench(
+        &self,
+        files: &[PathBuf],
+        options: &BenchOptions,
+        mut on_file: impl FnMut(&BenchFileReport),
+    ) -> Result<BenchReport, AnyError> {
+        let started = Instant::now();
+        let mut reports = Vec::with_capacity(files.len());
+        for file in files {
+            let module = module_specifier_from_file(file)?;
+            let report = bench_runner::run_bench_file(
+                &self.state,
+                &self.host,
+                self.main_worker_all_permissions,
+                self.standalone.clone(),
+                file,
+                &module,
+                options,
+            )?;
+            on_file(&report);
+            reports.push(report);
+        }
+        Ok(BenchReport {
+            files: reports,
+            duration: started.elapsed().as_secs_f64() * 1000.0,
+        })
+    }
+
+    /// Collects an entrypoint's module graph into an archive for a
+    /// standalone executable.
+    ///
+    /// Local files, packages, and remote modules are embedded already
+    /// transpiled, along with how each import resolved, so the executable
+    /// runs without the source tree, `node_modules`, or network access.
+    /// The runtime's current permission settings and virtual modules are
+    /// baked in.
+    pub fn compile(
+        &self,
+        file: impl AsRef<Path>,
+        options: CompileOptions,
+    ) -> Result<StandaloneArchive, AnyError> {
+        let module = module_specifier_from_file(file)?;
+        let include = options
+            .include
+            .iter()
+            .map(module_specifier_from_file)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut archive = standalone::build_archive(&self.state, module, &include)?;
+        archive.allow_all_permissions = self.main_worker_all_permissions;
+        archive.allow_http_imports = self
+            .state
+            .context
+            .read()
+            .expect("runtime context lock poisoned")
+            .allow_http_imports;
+        Ok(archive)
+    }
+
+    /// Bundles an entrypoint and its local, package, and remote imports into
+    /// a single ES module.
+    ///
+    /// Modules are transpiled exactly as [`Tanxium::run_file`] would load
+    /// them. `yasumu:`, `node:`, and virtual modules remain imports of the
+    /// bundle. Imported bindings stay live, as they do between ES modules.
+    pub fn bundle(&self, file: impl AsRef<Path>) -> Result<Bundle, AnyError> {
+        let module = module_specifier_from_file(file)?;
+        bundle::bundle(&self.state, module)
+    }
+
+    /// Builds the module graph of an entrypoint without executing it.
+    ///
+    /// Imports are resolved exactly as [`Tanxium::run_file`] would resolve
+    /// them, so `npm:` packages may be installed and remote modules fetched.
+    /// Failures are recorded on the affected module or dependency.
+    pub fn module_graph(&self, file: impl AsRef<Path>) -> Result<ModuleGraph, AnyError> {
+        let module = module_specifier_from_file(file)?;
+        Ok(module_graph::build(&self.state, module))
+    }
+
     /// Delivers a serialized host event to a running runtime.
     pub fn send_event(&self, event: impl Into<String>) {
         if let Some(sender) = self
@@ -1,5 +1,6 @@
 /// <reference types="./internal.d.ts" />
 import './patches.ts';
+import { setEnvProvider } from './env.ts';
 import { join } from 'node:path';
 
 import { tanxiumOps } from 'ext:tanxium_rt_ops/ops.js';
@@ -20,6 +21,10 @@ const {
   op_unregister_all_virtual_modules,
   op_set_workspace_dir,
   op_get_workspace_dir,
+  op_set_module_auth_tokens,
+  op_read_snapshots,
+  op_write_snapshots,
+  op_hrtime,
 } = tanxiumOps; // defined in resources/yasumu-scripts/yasumu-internal.d.ts
 
 import { MessageQueue } from './message-queue.ts';
@@ -222,6 +227,17 @@ class Yasumu {
     op_set_workspace_dir(path ?? '');
   }
 
+  /**
+   * Set the credentials sent with remote module imports, in the
+   * `DENO_AUTH_TOKENS` format (`token@host;user:pass@host:port`).
+   * Pass `null` to clear. The tokens cannot be read back from scripts.
+   * @param tokens The token list, or null
+   */
+  public static setModuleAuthTokens(tokens: string | null) {
+    if (isWorkerEnvironment()) return;
+    op_set_module_auth_tokens(tokens ?? '');
+  }
+
   /**
    * Get the active workspace directory, or `null` for a virtual workspace.
    * @returns The absolute workspace path, or null
@@ -231,6 +247,40 @@ class Yasumu {
     return dir ?? null;
   }
 
+  /**
+   * Read the snapshots stored for a test, keyed by snapshot name.
+   * Snapshots of a test file live in `__snapshots__/<file>.snap` next to it;
+   * other names are stored under the workspace's `yasumu/__snapshots__`.
+   * Only test files run by `tanxium test` may call this, for their own
+   * snapshots, and only with read permission for the file.
+   * @param test The test module URL or path, or a name for tests without a file
+   * @returns The stored snapshots
+   */
+  public static readSnapshots(test: string): Record<string, string> {
+    return op_read_snapshots(test);
+  }
+
+  /**
+   * Replace the snapshots stored for a test. An empty object removes the file.
+   * Like `readSnapshots`, this is limited to a test file's own snapshots and
+   * needs write permission for the file.
+   * @param test The test module URL or path, or a name for tests without a file
+   * @param snapshots The snapshots to store, keyed by snapshot name
+   */
+  public static writeSnapshots(test: string, snapshots: Record<string, string>) {
+    op_write_snapshots(test, snapshots);
+  }
+
+  /**
+   * Read a monotonic clock for benchmarks. Benchmark files run by
+   * `tanxium bench` read it at the platform's full resolution; everywhere
+   * else it advances in steps of 100 microseconds.
+   * @returns Nanoseconds since the clock was first read
+   */
+  public static hrtime(): number {
+    return op_hrtime();
+  }
+
   /**
    * Register a listener for when the Yasumu runtime is ready
    * @param listener The listener to register
@@ -295,6 +345,17 @@ class Yasumu {
   }
 }
 
+// Backs `Deno.env` and `process.env` with a custom source of variables, such
+// as the active workspace environment. The script worker preload takes this
+// once and deletes it, so the hooks it runs cannot replace or remove the
+// provider.
+Object.defineProperty(Yasumu, Symbol.for('yasumu.setEnvProvider'), {
+  value: setEnvProvider,
+  writable: false,
+  enumerable: false,
+  configurable: true,
+});
+
 Object.defineProperties(globalThis, {
   Yasumu: {
     value: Yasumu,
@@ -0,0 +1,142 @@
+/// <reference types="./internal.d.ts" />
+import { tanxiumOps } from 'ext:tanxium_rt_ops/ops.js';
+
+const { op_get_env_overrides } = tanxiumOps;
+
+/**
+ * A source of environment variables that replaces the process environment
+ * behind `Deno.env` and `process.env`.
+ */
+export interface EnvProvider {
+  get(key: string): string | undefined;
+  set(key: string, value: string): void;
+  delete(key: string): void;
+  has(key: string): boolean;
+  toObject(): Record<string, string>;
+}
+
+export interface EnvProviderOptions {
+  /**
+   * Process environment variables that stay visible behind the provider:
+   * `true` for all of them, or a list of names. Defaults to none.
+   */
+  allowHostEnv?: boolean | string[];
+}
+
+interface EnvLayer {
+  provider: EnvProvider;
+  allowHostEnv: boolean | Set<string>;
+}
+
+const hostEnv = {
+  get: Deno.env.get.bind(Deno.env),
+  set: Deno.env.set.bind(Deno.env),
+  delete: Deno.env.delete.bind(Deno.env),
+  has: Deno.env.has.bind(Deno.env),
+  toObject: Deno.env.toObject.bind(Deno.env),
+};
+
+// the environment scripts see without a provider: the process environment,
+// or a private copy of it when the embedder overrides or hides variables
+const overrides = op_get_env_overrides();
+const privateEnv = overrides ? new Map(Object.entries(overrides)) : null;
+
+const baseEnv: EnvProvider = privateEnv
+  ? {
+      get(key) {
+        // keeps the usual `--allow-env` permission checks
+        hostEnv.has(key);
+        return privateEnv.get(key);
+      },
+      set(key, value) {
+        hostEnv.has(key);
+        privateEnv.set(key, value);
+      },
+      delete(key) {
+        hostEnv.has(key);
+        privateEnv.delete(key);
+      },
+      has(key) {
+        hostEnv.has(key);
+        return privateEnv.has(key);
+      },
+      toObject() {
+        hostEnv.toObject();
+        return Object.fromEntries(privateEnv);
+      },
+    }
+  : hostEnv;
+
+let layer: EnvLayer | null = null;
+
+/**
+ * Backs `Deno.env` and `process.env` with `provider` until it is replaced or
+ * cleared with `null`. The process environment is hidden behind it, except
+ * for the variables allowed by `options.allowHostEnv`.
+ */
+export function setEnvProvider(provider: EnvProvider | null, options: EnvProviderOptions = {}) {
+  layer = provider && {
+    provider,
+    allowHostEnv: Array.isArray(options.allowHostEnv)
+      ? new Set(options.allowHostEnv)
+      : (options.allowHostEnv ?? false),
+  };
+}
+
+function isHostVisible(key: string): boolean {
+  if (!layer) return true;
+  const { allowHostEnv } = layer;
+  return typeof allowHostEnv === 'boolean' ? allowHostEnv : allowHostEnv.has(key);
+}
+
+function validateKey(key: string) {
+  if (key === '' || key.includes('=') || key.includes('\0')) {
+    throw new TypeError(`Key contains invalid characters: ${JSON.stringify(key)}`);
+  }
+}
+
+function hostEntries(): [string, string][] {
+  if (layer?.allowHostEnv === false) return [];
+  return Object.entries(baseEnv.toObject()).filter(([key]) => isHostVisible(key));
+}
+
+const env: EnvProvider = {
+  get(key) {
+    validateKey(key);
+    if (!layer) return baseEnv.get(key);
+    if (layer.provider.has(key)) return layer.provider.get(key);
+    return isHostVisible(key) ? baseEnv.get(key) : undefined;
+  },
+  set(key, value) {
+    validateKey(key);
+    (layer?.provider ?? baseEnv).set(key, String(value));
+  },
+  delete(key) {
+    validateKey(key);
+    (layer?.provider ?? baseEnv).delete(key);
+  },
+  has(key) {
+    validateKey(key);
+    if (!layer) return baseEnv.has(key);
+    return layer.provider.has(key) || (isHostVisible(key) && baseEnv.has(key));
+  },
+  toObject() {
+    if (!layer) return baseEnv.toObject();
+    return { ...Object.fromEntries(hostEntries()), ...layer.provider.toObject() };
+  },
+};
+
+Object.assign(Deno.env, env);
+
+// child processes inherit the environment scripts see rather than the host's
+function commandOptions(options?: Deno.CommandOptions): Deno.CommandOptions | undefined {
+  if ((!privateEnv && !layer) || options?.clearEnv) return options;
+  return { ...options, env: { ...env.toObject(), ...options?.env }, clearEnv: true };
+}
+
+const HostCommand = Deno.Command;
+Deno.Command = class Command extends HostCommand {
+  constructor(command: string | URL, options?: Deno.CommandOptions) {
+    super(command, commandOptions(options));
+  }
+};
@@ -4,6 +4,7 @@ export const YASUMU_RUNTIME_API_VERSION = 1 as const;
 export const YASUMU_SCRIPT_HOOKS = ['onRequest', 'onResponse', 'onTest', 'onEmail'] as const;
 export const YASUMU_VIRTUAL_MODULES = [
   'yasumu:test',
+  'yasumu:bench',
   'yasumu:workspace',
   'yasumu:runtime',
   'yasumu:env',
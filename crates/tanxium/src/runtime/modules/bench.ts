@@ -0,0 +1,209 @@
+declare const Yasumu: {
+  hrtime(): number;
+};
+
+export type BenchFunction = () => unknown;
+
+export interface BenchOptions {
+  /**
+   * Time spent calling the function before measuring, in milliseconds.
+   * Defaults to 100.
+   */
+  warmup?: number;
+  /**
+   * Minimum time spent measuring, in milliseconds. Defaults to 500.
+   */
+  time?: number;
+  /**
+   * Minimum number of samples to take, however long they run. Defaults to 10.
+   */
+  samples?: number;
+  /**
+   * Skip this benchmark.
+   */
+  skip?: boolean;
+}
+
+export interface BenchResult {
+  name: string;
+  result: 'pass' | 'fail' | 'skip';
+  error: string | null;
+  /**
+   * Timed calls across all samples.
+   */
+  iterations: number;
+  /**
+   * Samples taken. Each sample times a batch of calls sized so that it runs
+   * well above the clock's resolution.
+   */
+  samples: number;
+  /**
+   * Per-call times in nanoseconds.
+   */
+  mean: number;
+  min: number;
+  max: number;
+  p50: number;
+  p99: number;
+  opsPerSecond: number;
+}
+
+export interface RunBenchOptions {
+  /**
+   * Only run benchmarks whose name contains this string or matches this
+   * pattern.
+   */
+  filter?: string | RegExp;
+  /**
+   * Called before each benchmark runs.
+   */
+  onBenchStart?: (bench: { name: string }) => void | Promise<void>;
+  /**
+   * Called with the result of each benchmark as soon as it finishes.
+   */
+  onBenchFinish?: (result: BenchResult) => void | Promise<void>;
+}
+
+export interface BenchRunResult {
+  results: BenchResult[];
+}
+
+interface BenchEntry {
+  name: string;
+  fn: BenchFunction;
+  options: BenchOptions;
+}
+
+/**
+ * A batch runs for at least this long, in nanoseconds, so clock resolution
+ * and the cost of reading the clock stay negligible.
+ */
+const MIN_BATCH_TIME = 10_000;
+
+let activeBenches: BenchEntry[] | undefined;
+
+/**
+ * Registers a benchmark. Benchmarks only run inside `runBench`, which
+ * `tanxium bench` calls for each file; elsewhere this does nothing.
+ */
+export function bench(name: string, fn: BenchFunction, options: BenchOptions = {}): void {
+  activeBenches?.push({ name, fn, options });
+}
+
+export async function runBench(
+  fn: () => void | Promise<void>,
+  options: RunBenchOptions = {},
+): Promise<BenchRunResult> {
+  const benches: BenchEntry[] = [];
+  const previous = activeBenches;
+  activeBenches = benches;
+  try {
+    await fn();
+  } finally {
+    activeBenches = previous;
+  }
+
+  const results: BenchResult[] = [];
+  for (const entry of benches) {
+    if (!matchesFilter(entry.name, options.filter)) continue;
+    await options.onBenchStart?.({ name: entry.name });
+    const result = await executeBench(entry);
+    await options.onBenchFinish?.(result);
+    results.push(result);
+  }
+  return { results };
+}
+
+function matchesFilter(name: string, filter: string | RegExp | undefined): boolean {
+  if (filter === undefined) return true;
+  return typeof filter === 'string' ? name.includes(filter) : filter.test(name);
+}
+
+async function executeBench(entry: BenchEntry): Promise<BenchResult> {
+  if (entry.options.skip) return emptyResult(entry.name, 'skip', null);
+  try {
+    return await measure(entry);
+  } catch (error) {
+    return emptyResult(entry.name, 'fail', error instanceof Error ? (error.stack ?? error.message) : String(error));
+  }
+}
+
+async function measure({ name, fn, options }: BenchEntry): Promise<BenchResult> {
+  const warmup = (options.warmup ?? 100) * 1e6;
+  const time = (options.time ?? 500) * 1e6;
+  const minSamples = Math.max(1, options.samples ?? 10);
+  const first = fn();
+  if (isPromiseLike(first)) await first;
+  const run = isPromiseLike(first) ? runAsyncBatch : runBatch;
+
+  // Warm up while doubling the batch until it runs long enough to time.
+  let batch = 1;
+  const warmupStart = Yasumu.hrtime();
+  for (;;) {
+    const elapsed = await run(fn, batch);
+    const warmedUp = Yasumu.hrtime() - warmupStart >= warmup;
+    if (elapsed >= MIN_BATCH_TIME && warmedUp) break;
+    if (elapsed < MIN_BATCH_TIME) batch *= 2;
+  }
+
+  const times: number[] = [];
+  const start = Yasumu.hrtime();
+  while (times.length < minSamples || Yasumu.hrtime() - start < time) {
+    times.push((await run(fn, batch)) / batch);
+  }
+
+  times.sort((a, b) => a - b);
+  const mean = times.reduce((sum, value) => sum + value, 0) / times.length;
+  return {
+    name,
+    result: 'pass',
+    error: null,
+    iterations: times.length * batch,
+    samples: times.length,
+    mean,
+    min: times[0],
+    max: times[times.length - 1],
+    p50: percentile(times, 0.5),
+    p99: percentile(times, 0.99),
+    opsPerSecond: mean > 0 ? 1e9 / mean : 0,
+  };
+}
+
+function runBatch(fn: BenchFunction, batch: number): number {
+  const start = Yasumu.hrtime();
+  for (let i = 0; i < batch; i++) fn();
+  return Yasumu.hrtime() - start;
+}
+
+async function runAsyncBatch(fn: BenchFunction, batch: number): Promise<number> {
+  const start = Yasumu.hrtime();
+  for (let i = 0; i < batch; i++) await fn();
+  return Yasumu.hrtime() - start;
+}
+
+/**
+ * Nearest-rank percentile of sorted samples.
+ */
+function percentile(sorted: number[], p: number): number {
+  return sorted[Math.min(sorted.length - 1, Math.max(0, Math.ceil(p * sorted.length) - 1))];
+}
+
+function emptyResult(name: string, result: BenchResult['result'], error: string | null): BenchResult {
+  return {
+    name,
+    result,
+    error,
+    iterations: 0,
+    samples: 0,
+    mean: 0,
+    min: 0,
+    max: 0,
+    p50: 0,
+    p99: 0,
+    opsPerSecond: 0,
+  };
+}
+
+function isPromiseLike(value: unknown): value is PromiseLike<unknown> {
+  return typeof value === 'object' && value !== null && 'then' in value;
+}
@@ -20,6 +20,11 @@
     op_show_confirmation_dialog_sync,
     op_set_workspace_dir,
     op_get_workspace_dir,
+    op_set_module_auth_tokens,
+    op_read_snapshots,
+    op_write_snapshots,
+    op_hrtime,
+    op_get_env_overrides,
   } = core.ops;
 
   // `loadExtScript` temporarily restores Deno's captured bootstrap namespace,
@@ -45,6 +50,11 @@
       op_show_confirmation_dialog_sync,
       op_set_workspace_dir,
       op_get_workspace_dir,
+      op_set_module_auth_tokens,
+      op_read_snapshots,
+      op_write_snapshots,
+      op_hrtime,
+      op_get_env_overrides,
     }),
   };
 })();
@@ -0,0 +1,328 @@
+//! Standalone executables: an entrypoint's module graph embedded in a copy
+//! of the running binary.
+//!
+//! The archive is appended to the executable, followed by a trailer holding
+//! its length and a magic marker, so a binary can find its own archive
+//! without any cooperation from the platform's executable format:
+//!
+//! ```text
+//! [executable][archive JSON][archive length: u64 LE][MAGIC]
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use deno_ast::ModuleSpecifier;
+use deno_runtime::deno_core::error::AnyError;
+use serde::{Deserialize, Serialize};
+
+use crate::module_graph::{self, ModuleKind};
+use crate::state::RuntimeState;
+
+const MAGIC: &[u8; 8] = b"TNXMSA01";
+const TRAILER_LEN: u64 = 16;
+
+/// Modules, resolutions, and settings embedded in a standalone executable.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StandaloneArchive {
+    pub(crate) entrypoint: String,
+    /// Module sources keyed by specifier.
+    pub(crate) modules: BTreeMap<String, EmbeddedModule>,
+    /// Resolved specifiers keyed by referrer, then by the specifier as
+    /// written, so packages resolve without a `node_modules` directory.
+    pub(crate) resolutions: BTreeMap<String, BTreeMap<String, String>>,
+    /// `yasumu:virtual/*` modules registered when the archive was built.
+    pub(crate) virtual_modules: BTreeMap<String, String>,
+    pub(crate) allow_all_permissions: bool,
+    pub(crate) allow_http_imports: bool,
+}
+
+/// How an embedded module is handed to the runtime.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum EmbeddedKind {
+    /// Transpiled JavaScript.
+    JavaScript,
+    Json,
+    /// JSON converted from a YAML, TOML, JSONC, or JSON5 file.
+    Data,
+    Wasm,
+    /// The unprocessed bytes of a `text` or `bytes` import.
+    Raw,
+}
+
+/// A module as loaded when the archive was built.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EmbeddedModule {
+    pub kind: EmbeddedKind,
+    #[serde(with = "base64_bytes")]
+    pub source: Vec<u8>,
+    #[serde(default, with = "base64_bytes::option")]
+    pub source_map: Option<Vec<u8>>,
+    /// The URL that served a remote module when it differs from the
+    /// requested one.
+    pub redirect: Option<String>,
+    /// Whether the module wraps CommonJS, whose `require()` calls look for
+    /// files on disk when it runs. Never true in an archive.
+    #[serde(skip)]
+    pub common_js: bool,
+}
+
+/// Configures [`crate::Tanxium::compile`].
+#[derive(Clone, Debug, Default)]
+pub struct CompileOptions {
+    /// Extra modules to embed with their dependencies, for example ones
+    /// only reached through computed dynamic imports.
+    pub include: Vec<PathBuf>,
+}
+
+impl StandaloneArchive {
+    /// Reads the archive embedded in the running executable, if any.
+    pub fn from_current_exe() -> Result<Option<Self>, AnyError> {
+        let mut file = File::open(std::env::current_exe()?)?;
+        let Some(length) = archive_len(&mut file)? else {
+            return Ok(None);
+        };
+
+        file.seek(SeekFrom::End(-((TRAILER_LEN + length) as i64)))?;
+        let mut archive = vec![0; length as usize];
+        file.read_exact(&mut archive)?;
+        Ok(Some(serde_json::from_slice(&archive)?))
+    }
+
+    /// Writes a copy of the running executable with this archive appended.
+    /// An archive already embedded in the running executable is replaced.
+    pub fn write_executable(&self, output: &Path) -> Result<(), AnyError> {
+        let current_exe = std::env::current_exe()?;
+        let mut file = File::open(&current_exe)?;
+        let embedded = archive_len(&mut file)?.map_or(0, |length| length + TRAILER_LEN);
+        let executable_len = file.metadata()?.len() - embedded;
+
+        let mut executable = Vec::with_capacity(executable_len as usize);
+        file.seek(SeekFrom::Start(0))?;
+        file.take(executable_len).read_to_end(&mut executable)?;
+
+        let archive = serde_json::to_vec(self)?;
+        executable.extend_from_slice(&archive);
+        executable.extend_from_slice(&(archive.len() as u64).to_le_bytes());
+        executable.extend_from_slice(MAGIC);
+
+        std::fs::write(output, executable)?;
+        std::fs::set_permissions(output, std::fs::metadata(&current_exe)?.permissions())?;
+        Ok(())
+    }
+
+    /// The specifier of the module the executable runs.
+    pub fn entrypoint(&self) -> &str {
+        &self.entrypoint
+    }
+
+    pub(crate) fn module(&self, specifier: &ModuleSpecifier) -> Option<&EmbeddedModule> {
+        self.modules.get(specifier.as_str())
+    }
+
+    pub(crate) fn resolution(&self, referrer: &str, specifier: &str) -> Option<ModuleSpecifier> {
+        let resolved = self.resolutions.get(referrer)?.get(specifier)?;
+        ModuleSpecifier::parse(resolved).ok()
+    }
+
+    pub(crate) fn virtual_modules(&self) -> HashMap<String, String> {
+        self.virtual_modules
+            .iter()
+            .map(|(name, source)| (name.clone(), source.clone()))
+            .collect()
+    }
+}
+
+/// Reads the length of an archive appended to `file`, if it has one.
+fn archive_len(file: &mut File) -> Result<Option<u64>, AnyError> {
+    let file_len = file.metadata()?.len();
+    if file_len < TRAILER_LEN {
+        return Ok(None);
+    }
+
+    let mut trailer = [0; TRAILER_LEN as usize];
+    file.seek(SeekFrom::End(-(TRAILER_LEN as i64)))?;
+    file.read_exact(&mut trailer)?;
+    if &trailer[8..] != MAGIC {
+        return Ok(None);
+    }
+
+    let length = u64::from_le_bytes(trailer[..8].try_into().unwrap());
+    if length > file_len - TRAILER_LEN {
+        return Err(AnyError::msg("The embedded module archive is truncated"));
+    }
+    Ok(Some(length))
+}
+
+/// Builds an archive holding every local, package, and remote module
+/// reachable from `root` and the included files. Built-in, `node:`, and
+/// inline modules are provided by the runtime and are not embedded.
+pub(crate) fn build_archive(
+    state: &Arc<RuntimeState>,
+    root: ModuleSpecifier,
+    include: &[ModuleSpecifier],
+) -> Result<StandaloneArchive, AnyError> {
+    let loader = module_graph::loader(state);
+    let mut archive = StandaloneArchive {
+        entrypoint: root.to_string(),
+        virtual_modules: state
+            .virtual_modules
+            .lock()
+            .expect("virtual modules lock poisoned")
+            .iter()
+            .map(|(name, source)| (name.clone(), source.clone()))
+            .collect(),
+        ..Default::default()
+    };
+
+    for root in std::iter::once(root).chain(include.iter().cloned()) {
+        let graph = module_graph::build(state, root);
+        for module in &graph.modules {
+            if let Some(error) = &module.error {
+                return Err(AnyError::msg(format!(
+                    "Unable to embed {}: {error}",
+                    module.specifier
+                )));
+            }
+            if !is_embedded(module.kind) || archive.modules.contains_key(&module.specifier) {
+                continue;
+            }
+
+            let specifier = ModuleSpecifier::parse(&module.specifier)?;
+            let raw = matches!(module.media_type.as_deref(), Some("text" | "bytes"));
+            let embedded = loader
+                .embed_module(&specifier, raw)
+                .map_err(|error| AnyError::msg(format!("Unable to embed {specifier}: {error}")))?;
+            if embedded.common_js {
+                return Err(AnyError::msg(format!(
+                    "Unable to embed {specifier}: CommonJS modules cannot be embedded in a standalone executable; import an ES module build instead"
+                )));
+            }
+            archive.modules.insert(module.specifier.clone(), embedded);
+
+            for dependency in &module.dependencies {
+                if let Some(error) = dependency.error.as_ref().filter(|_| !dependency.dynamic) {
+                    return Err(AnyError::msg(format!(
+                        "Unable to resolve {} from {}: {error}",
+                        dependency.specifier, module.specifier
+                    )));
+                }
+                let Some(resolved) = &dependency.resolved else {
+                    continue;
+                };
+                if !graph
+                    .get(resolved)
+                    .is_some_and(|target| is_embedded(target.kind))
+                {
+                    continue;
+                }
+
+                for referrer in std::iter::once(&module.specifier).chain(&module.redirect) {
+                    archive
+                        .resolutions
+                        .entry(referrer.clone())
+                        .or_default()
+                        .insert(dependency.specifier.clone(), resolved.clone());
+                }
+            }
+        }
+    }
+
+    Ok(archive)
+}
+
+fn is_embedded(kind: ModuleKind) -> bool {
+    matches!(
+        kind,
+        ModuleKind::Local | ModuleKind::Package | ModuleKind::Remote
+    )
+}
+
+/// Serializes binary sources as base64 strings.
+mod base64_bytes {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD.decode(encoded).map_err(serde::de::Error::custom)
+    }
+
+    pub mod option {
+        use super::STANDARD;
+        use base64::Engine;
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            bytes: &Option<Vec<u8>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match bytes {
+                Some(bytes) => super::serialize(bytes, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Vec<u8>>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|encoded| STANDARD.decode(encoded).map_err(serde::de::Error::custom))
+                .transpose()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EmbeddedKind, EmbeddedModule, MAGIC, StandaloneArchive, archive_len};
+    use std::io::Write;
+
+    #[test]
+    fn finds_an_archive_appended_to_an_executable() {
+        let mut archive = StandaloneArchive {
+            entrypoint: "file:///app/main.ts".to_string(),
+            ..Default::default()
+        };
+        archive.modules.insert(
+            "file:///app/main.ts".to_string(),
+            EmbeddedModule {
+                kind: EmbeddedKind::Wasm,
+                source: vec![0, 0xff],
+                source_map: None,
+                redirect: None,
+                common_js: false,
+            },
+        );
+        let json = serde_json::to_vec(&archive).unwrap();
+
+        let path = std::env::temp_dir().join(format!("tanxium-standalone-{}", std::process::id()));
+        let mut file = std::fs::File::create(&path).unwrap();
+        file.write_all(b"executable").unwrap();
+        file.write_all(&json).unwrap();
+        file.write_all(&(json.len() as u64).to_le_bytes()).unwrap();
+        file.write_all(MAGIC).unwrap();
+        drop(file);
+
+        let mut file = std::fs::File::open(&path).unwrap();
+        assert_eq!(archive_len(&mut file).unwrap(), Some(json.len() as u64));
+        let decoded: StandaloneArchive = serde_json::from_slice(&json).unwrap();
+        assert_eq!(decoded.modules["file:///app/main.ts"].source, vec![0, 0xff]);
+
+        std::fs::write(&path, b"plain executable without an archive").unwrap();
+        let mut file = std::fs::File::open(&path).unwrap();
+        assert_eq!(archive_len(&mut file).unwrap(), None);
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -1,7 +1,9 @@
+use crate::auth_tokens::AuthTokens;
 use std::{
     collections::HashMap,
     path::PathBuf,
     sync::{Arc, Mutex, RwLock},
+    time::Duration,
 };
 
 /// Thread-safe backing store for `yasumu:virtual/*` modules.
@@ -17,6 +19,33 @@ pub struct RuntimeContext {
     pub dev_mode: bool,
     pub ready: bool,
     pub allow_http_imports: bool,
+    /// Per-request timeout for remote module fetches. `None` uses the
+    /// runtime default of 60 seconds.
+    pub module_fetch_timeout: Option<Duration>,
+    /// Per-host credentials for remote module requests. Never exposed to
+    /// JavaScript.
+    pub auth_tokens: AuthTokens,
+    /// Import map JSON supplied by the host. Takes precedence over one
+    /// found in the workspace.
+    pub import_map: Option<String>,
+    /// Root of the package and remote module cache. `None` uses
+    /// `TANXIUM_DIR`, the app data directory, or the platform cache
+    /// directory.
+    pub cache_dir: Option<PathBuf>,
+    /// npm registry used for `npm:` specifiers. `None` uses
+    /// `NPM_CONFIG_REGISTRY` or the public registry.
+    pub npm_registry: Option<String>,
+    /// JSR registry used for `jsr:` specifiers. `None` uses `JSR_URL` or
+    /// `https://jsr.io/`.
+    pub jsr_registry: Option<String>,
+    /// Arguments exposed as `Deno.args` and after the script in
+    /// `process.argv`.
+    pub args: Vec<String>,
+    /// Environment variables set for scripts on top of the process
+    /// environment, without changing the host process.
+    pub env: HashMap<String, String>,
+    /// Hides the process environment from scripts, leaving only `env`.
+    pub clear_env: bool,
     pub rpc_port: Option<u16>,
     pub echo_server_port: Option<u16>,
     pub mcp_server_port: Option<u16>,
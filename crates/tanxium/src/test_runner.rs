@@ -0,0 +1,559 @@
+//! Test files run through [`crate::Tanxium::test`].
+//!
+//! Each file is imported by a generated entry module inside `runTest` from
+//! `yasumu:test`, in a fresh main worker. The entry posts each test's start
+//! and result back as renderer messages, which [`MessageHost`] intercepts
+//! before the embedder's host sees them, so the runner always knows which
+//! test is running when it has to terminate a worker.
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+
+use base64::Engine;
+use deno_runtime::deno_core::{ModuleSpecifier, error::AnyError};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::standalone::StandaloneArchive;
+use crate::state::{PermissionPromptResponse, RuntimeEvent, RuntimeHost, RuntimeState};
+use crate::worker::{WorkerRole, WorkerShutdown, start_worker};
+
+const TEST_SUFFIXES: [&str; 2] = [".test", "_test"];
+const SOURCE_EXTENSIONS: [&str; 8] = ["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// Configures [`crate::Tanxium::test`].
+#[derive(Clone, Debug, Default)]
+pub struct TestOptions {
+    /// Only run tests whose full name, suites and test name joined with
+    /// ` > `, contains this string. A value wrapped in slashes, such as
+    /// `/^api/`, is a regular expression.
+    pub filter: Option<String>,
+    /// How many files run at once, each on its own worker thread. `0` and
+    /// `1` run files one after another.
+    pub jobs: usize,
+    /// Runs only this shard's share of the files.
+    pub shard: Option<TestShard>,
+    /// Fails a test, and terminates its worker, when it runs longer than
+    /// this. The file's remaining tests do not run.
+    pub test_timeout: Option<Duration>,
+    /// Terminates a file's worker when the whole file runs longer than this.
+    pub file_timeout: Option<Duration>,
+    /// Overwrites mismatched snapshots and removes obsolete ones instead of
+    /// failing. New snapshots are written either way.
+    pub update_snapshots: bool,
+}
+
+/// One of `count` disjoint slices of the test files, for splitting a run
+/// across CI machines. Written `index/count`, with `index` starting at 1.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TestShard {
+    pub index: usize,
+    pub count: usize,
+}
+
+impl TestShard {
+    /// Picks every `count`-th file, starting at `index`. Every file is in
+    /// exactly one shard as long as each machine sees the same file list.
+    pub fn select(&self, files: &[PathBuf]) -> Vec<PathBuf> {
+        files
+            .iter()
+            .skip(self.index - 1)
+            .step_by(self.count)
+            .cloned()
+            .collect()
+    }
+}
+
+impl FromStr for TestShard {
+    type Err = String;
+
+    fn from_str(shard: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid shard {shard:?}; expected INDEX/COUNT, such as 1/3");
+        let (index, count) = shard.split_once('/').ok_or_else(invalid)?;
+        let index = index.trim().parse::<usize>().map_err(|_| invalid())?;
+        let count = count.trim().parse::<usize>().map_err(|_| invalid())?;
+        if index == 0 || index > count {
+            return Err(invalid());
+        }
+        Ok(Self { index, count })
+    }
+}
+
+/// The outcome of a single test.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TestOutcome {
+    Pass,
+    Fail,
+    Skip,
+}
+
+/// A test as reported by `yasumu:test`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestResult {
+    /// Enclosing `describe` blocks, outermost first.
+    #[serde(default)]
+    pub suite: Vec<String>,
+    pub test: String,
+    pub result: TestOutcome,
+    /// The assertion or thrown error of a failed test.
+    pub error: Option<String>,
+    /// In milliseconds.
+    pub duration: f64,
+}
+
+impl TestResult {
+    /// The suites and test name joined with ` > `.
+    pub fn full_name(&self) -> String {
+        full_name(&self.suite, &self.test)
+    }
+}
+
+/// Snapshot changes made while running a file.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotSummary {
+    /// Snapshots written for the first time.
+    pub added: usize,
+    /// Mismatched snapshots overwritten with `update_snapshots`.
+    pub updated: usize,
+    /// Stored snapshots no test checked. Filtered runs do not report them.
+    pub obsolete: Vec<String>,
+}
+
+/// The tests of one file.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestFileReport {
+    pub path: PathBuf,
+    pub results: Vec<TestResult>,
+    /// Why the file failed outside of a test, for example because it could
+    /// not be imported, threw an uncaught error, or timed out.
+    pub error: Option<String>,
+    pub snapshots: SnapshotSummary,
+    /// In milliseconds, including worker startup.
+    pub duration: f64,
+}
+
+impl TestFileReport {
+    pub fn count(&self, outcome: TestOutcome) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.result == outcome)
+            .count()
+    }
+
+    pub fn success(&self) -> bool {
+        self.error.is_none() && self.count(TestOutcome::Fail) == 0
+    }
+}
+
+/// The tests of every file in a run.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestReport {
+    pub files: Vec<TestFileReport>,
+    /// In milliseconds.
+    pub duration: f64,
+}
+
+impl TestReport {
+    /// Counts tests with `outcome` across all files.
+    pub fn count(&self, outcome: TestOutcome) -> usize {
+        self.files.iter().map(|file| file.count(outcome)).sum()
+    }
+
+    /// Whether every file loaded and no test failed.
+    pub fn success(&self) -> bool {
+        self.files.iter().all(TestFileReport::success)
+    }
+}
+
+fn full_name(suite: &[String], test: &str) -> String {
+    let mut name = suite.join(" > ");
+    if !name.is_empty() {
+        name.push_str(" > ");
+    }
+    name.push_str(test);
+    name
+}
+
+/// Finds test files: `*.test.ts`, `*_test.ts`, and their JavaScript and
+/// JSX equivalents, below each directory in `paths`. Files named
+/// explicitly are included whatever their name. `node_modules` and hidden
+/// directories are skipped.
+pub fn discover_test_files(paths: &[PathBuf]) -> Result<Vec<PathBuf>, AnyError> {
+    discover_files(paths, is_test_file)
+}
+
+/// Finds the files below `paths` whose names pass `matches`, along with
+/// files named explicitly.
+pub(crate) fn discover_files(
+    paths: &[PathBuf],
+    matches: fn(&str) -> bool,
+) -> Result<Vec<PathBuf>, AnyError> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            collect_files(path, matches, &mut files)?;
+        } else if path.is_file() {
+            files.push(path.clone());
+        } else {
+            return Err(AnyError::msg(format!(
+                "No such file or directory: {}",
+                path.display()
+            )));
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+fn collect_files(
+    dir: &Path,
+    matches: fn(&str) -> bool,
+    files: &mut Vec<PathBuf>,
+) -> Result<(), AnyError> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_dir() {
+            if name != "node_modules" && !name.starts_with('.') {
+                collect_files(&path, matches, files)?;
+            }
+        } else if matches(&name) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn is_test_file(name: &str) -> bool {
+    has_source_suffix(name, &TEST_SUFFIXES)
+}
+
+/// Whether `name` is a script whose stem ends with one of `suffixes`, such
+/// as `api.test.ts` for `.test`.
+pub(crate) fn has_source_suffix(name: &str, suffixes: &[&str]) -> bool {
+    let Some((stem, extension)) = name.rsplit_once('.') else {
+        return false;
+    };
+    SOURCE_EXTENSIONS.contains(&extension)
+        && suffixes
+            .iter()
+            .any(|suffix| stem.len() > suffix.len() && stem.ends_with(suffix))
+}
+
+/// Writes a name filter as a JavaScript expression: a regular expression
+/// when wrapped in slashes, otherwise a string.
+pub(crate) fn filter_expression(filter: Option<&str>) -> Result<String, AnyError> {
+    Ok(match filter {
+        Some(filter) if filter.len() > 1 && filter.starts_with('/') && filter.ends_with('/') => {
+            format!(
+                "new RegExp({})",
+                serde_json::to_string(&filter[1..filter.len() - 1])?
+            )
+        }
+        Some(filter) => serde_json::to_string(filter)?,
+        None => "undefined".to_string(),
+    })
+}
+
+/// Builds the entry module that runs one test file and reports back.
+fn entry_module(module: &ModuleSpecifier, options: &TestOptions) -> Result<String, AnyError> {
+    let filter = filter_expression(options.filter.as_deref())?;
+    let module = serde_json::to_string(module.as_str())?;
+    Ok(format!(
+        r#"import {{ runTest }} from "yasumu:test";
+const errors = [];
+const onError = (event) => {{
+  event.preventDefault();
+  const error = "reason" in event ? event.reason : event.error;
+  errors.push(String(error?.stack ?? error));
+}};
+globalThis.addEventListener("error", onError);
+globalThis.addEventListener("unhandledrejection", onError);
+try {{
+  const {{ snapshots }} = await runTest(() => import({module}), {{
+    filter: {filter},
+    snapshot: {{ file: {module}, update: {update} }},
+    // Waiting for delivery means the runner knows about a test before it
+    // can block the isolate.
+    onTestStart: ({{ suite, test }}) =>
+      Yasumu.postMessage({{ type: "tanxium:test-start", suite, test }}),
+    onTestFinish: (result) => Yasumu.postMessage({{ type: "tanxium:test-finish", result }}),
+  }});
+  Yasumu.postMessage({{ type: "tanxium:test-done", errors, snapshots }});
+}} catch (error) {{
+  Yasumu.postMessage({{ type: "tanxium:test-done", error: String(error?.stack ?? error), errors }});
+}}
+"#,
+        update = options.update_snapshots,
+    ))
+}
+
+/// Progress posted by the entry module.
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum RunnerMessage {
+    #[serde(rename = "tanxium:test-start")]
+    Start {
+        #[serde(default)]
+        suite: Vec<String>,
+        test: String,
+    },
+    #[serde(rename = "tanxium:test-finish")]
+    Finish { result: TestResult },
+    #[serde(rename = "tanxium:test-done")]
+    Done {
+        error: Option<String>,
+        #[serde(default)]
+        errors: Vec<String>,
+        #[serde(default)]
+        snapshots: SnapshotSummary,
+    },
+}
+
+/// Forwards events to the embedder's host, except the entry module's
+/// progress messages, which are posted with `Yasumu.postMessage` and
+/// parse as `M`.
+pub(crate) struct MessageHost<M> {
+    pub(crate) inner: Arc<dyn RuntimeHost>,
+    pub(crate) messages: Sender<M>,
+}
+
+impl<M: DeserializeOwned + Send + 'static> RuntimeHost for MessageHost<M> {
+    fn emit_event(&self, event: RuntimeEvent) {
+        if let RuntimeEvent::Renderer(json) = &event
+            && let Ok(mut event) = serde_json::from_str::<serde_json::Value>(json)
+            && event["type"] == "message"
+            && let Ok(message) = serde_json::from_value(event["payload"].take())
+        {
+            let _ = self.messages.send(message);
+            return;
+        }
+        self.inner.emit_event(event);
+    }
+
+    fn confirm(&self, title: &str, message: &str, yes: &str, no: &str, cancel: &str) -> bool {
+        self.inner.confirm(title, message, yes, no, cancel)
+    }
+
+    fn prompt_permission(
+        &self,
+        message: &str,
+        name: &str,
+        api_name: Option<&str>,
+        is_unary: bool,
+    ) -> PermissionPromptResponse {
+        self.inner
+            .prompt_permission(message, name, api_name, is_unary)
+    }
+}
+
+/// The test a worker is running.
+struct RunningTest {
+    suite: Vec<String>,
+    test: String,
+    started: Instant,
+}
+
+impl RunningTest {
+    fn full_name(&self) -> String {
+        full_name(&self.suite, &self.test)
+    }
+}
+
+/// Runs one test file in a fresh worker and waits for it to finish,
+/// terminating the worker when a timeout in `options` expires.
+pub(crate) fn run_test_file(
+    state: &Arc<RuntimeState>,
+    host: &Arc<dyn RuntimeHost>,
+    main_worker_all_permissions: bool,
+    standalone: Option<Arc<StandaloneArchive>>,
+    path: &Path,
+    module: &ModuleSpecifier,
+    options: &TestOptions,
+) -> Result<TestFileReport, AnyError> {
+    let started = Instant::now();
+    let entry = base64::engine::general_purpose::STANDARD.encode(entry_module(module, options)?);
+    let entry = ModuleSpecifier::parse(&format!("data:text/javascript;base64,{entry}"))?;
+
+    let (messages, receiver) = mpsc::channel();
+    let test_host = Arc::new(MessageHost {
+        inner: host.clone(),
+        messages,
+    });
+    let shutdown = WorkerShutdown::default();
+    let worker = start_worker(
+        entry,
+        state.clone(),
+        test_host,
+        main_worker_all_permissions,
+        shutdown.clone(),
+        standalone,
+        WorkerRole::Test(
+            module
+                .to_file_path()
+                .unwrap_or_else(|()| path.to_path_buf()),
+        ),
+    )?;
+
+    let file_deadline = options.file_timeout.map(|timeout| started + timeout);
+    let mut running: Option<RunningTest> = None;
+    let mut results = Vec::new();
+    let mut snapshots = SnapshotSummary::default();
+    let error = loop {
+        let test_deadline = running
+            .as_ref()
+            .zip(options.test_timeout)
+            .map(|(test, timeout)| test.started + timeout);
+        let deadline = file_deadline.into_iter().chain(test_deadline).min();
+        // The sender is dropped with the worker, so an exit without a
+        // report ends the wait.
+        let message = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match message {
+            Ok(RunnerMessage::Start { suite, test }) => {
+                running = Some(RunningTest {
+                    suite,
+                    test,
+                    started: Instant::now(),
+                });
+            }
+            Ok(RunnerMessage::Finish { result }) => {
+                running = None;
+                results.push(result);
+            }
+            Ok(RunnerMessage::Done {
+                error,
+                errors,
+                snapshots: summary,
+            }) => {
+                snapshots = summary;
+                break error.or_else(|| {
+                    (!errors.is_empty()).then(|| format!("Uncaught error:\n{}", errors.join("\n")))
+                });
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let test_timed_out = test_deadline.is_some_and(|test| Some(test) == deadline);
+                let (timeout, scope) = if test_timed_out {
+                    (options.test_timeout, "Test")
+                } else {
+                    (options.file_timeout, "Test file")
+                };
+                let timeout = timeout.unwrap_or_default().as_millis();
+                let Some(test) = running.take() else {
+                    break Some(format!(
+                        "{scope} timed out after {timeout}ms while loading; the worker was terminated"
+                    ));
+                };
+
+                let name = test.full_name();
+                results.push(TestResult {
+                    suite: test.suite,
+                    test: test.test,
+                    result: TestOutcome::Fail,
+                    error: Some(format!("{scope} timed out after {timeout}ms")),
+                    duration: test.started.elapsed().as_secs_f64() * 1000.0,
+                });
+                break Some(format!(
+                    "{scope} timed out after {timeout}ms while running \"{name}\"; the worker was terminated"
+                ));
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                break Some(match running.take() {
+                    Some(test) => {
+                        format!("The worker exited while running \"{}\"", test.full_name())
+                    }
+                    None => "The test file exited before reporting its results".to_string(),
+                });
+            }
+        }
+    };
+
+    // Also stops timers left running by the tests.
+    shutdown.request();
+    worker
+        .join()
+        .map_err(|_| AnyError::msg("runtime thread panicked"))?;
+
+    Ok(TestFileReport {
+        path: path.to_path_buf(),
+        results,
+        error,
+        snapshots,
+        duration: started.elapsed().as_secs_f64() * 1000.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TestShard, discover_test_files, is_test_file};
+    use std::path::PathBuf;
+
+    #[test]
+    fn recognizes_test_file_names() {
+        assert!(is_test_file("api.test.ts"));
+        assert!(is_test_file("api_test.mjs"));
+        assert!(is_test_file("view.test.tsx"));
+        assert!(!is_test_file("api.ts"));
+        assert!(!is_test_file("test.ts"));
+        assert!(!is_test_file("api.test.json"));
+    }
+
+    #[test]
+    fn discovers_tests_outside_node_modules() {
+        let root = std::env::temp_dir().join(format!("tanxium-discover-{}", std::process::id()));
+        for dir in ["src/nested", "node_modules/pkg", ".cache"] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        for file in [
+            "src/a.test.ts",
+            "src/nested/b_test.js",
+            "src/lib.ts",
+            "node_modules/pkg/c.test.ts",
+            ".cache/d.test.ts",
+        ] {
+            std::fs::write(root.join(file), "").unwrap();
+        }
+
+        let files = discover_test_files(&[root.clone(), root.join("src/lib.ts")]).unwrap();
+
+        assert_eq!(
+            files,
+            [
+                root.join("src/a.test.ts"),
+                root.join("src/lib.ts"),
+                root.join("src/nested/b_test.js"),
+            ]
+        );
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn splits_files_into_disjoint_shards() {
+        let files = ["a", "b", "c", "d", "e"].map(PathBuf::from);
+
+        let shard = "2/2".parse::<TestShard>().unwrap();
+        assert_eq!(shard, TestShard { index: 2, count: 2 });
+        assert_eq!(shard.select(&files), ["b", "d"].map(PathBuf::from));
+        assert_eq!(
+            "1/2".parse::<TestShard>().unwrap().select(&files),
+            ["a", "c", "e"].map(PathBuf::from)
+        );
+        assert!("0/2".parse::<TestShard>().is_err());
+        assert!("3/2".parse::<TestShard>().is_err());
+        assert!("1".parse::<TestShard>().is_err());
+    }
+}
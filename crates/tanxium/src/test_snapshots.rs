@@ -0,0 +1,217 @@
+//! Snapshot files for `toMatchSnapshot` in `yasumu:test`.
+//!
+//! Snapshots of a test file live in `__snapshots__/<file>.snap` next to it.
+//! Tests without a file on disk, such as scripts stored in a workspace,
+//! use `<workspace>/yasumu/__snapshots__/<name>.snap` instead. Each entry is
+//! a template literal keyed by the test's full name and a counter:
+//!
+//! ```text
+//! // Tanxium snapshot v1
+//!
+//! exports[`api > lists users 1`] = `
+//! [
+//!   "ada",
+//! ]
+//! `;
+//! ```
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use deno_core::ModuleSpecifier;
+
+const HEADER: &str = "// Tanxium snapshot v1\n";
+const SNAPSHOT_DIR: &str = "__snapshots__";
+
+/// Locates the snapshot file for `test`: a test module's URL or path, or a
+/// name for a test without a file.
+pub(crate) fn snapshot_path(test: &str, workspace_dir: Option<&Path>) -> io::Result<PathBuf> {
+    let file = match ModuleSpecifier::parse(test) {
+        Ok(url) if url.scheme() == "file" => url.to_file_path().ok(),
+        Ok(_) => None,
+        Err(_) => Some(PathBuf::from(test)).filter(|path| path.is_absolute()),
+    };
+    if let Some(path) = file.as_deref().and_then(file_snapshot_path) {
+        return Ok(path);
+    }
+
+    let workspace_dir = workspace_dir.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Snapshots for {test:?} need a test file or an open workspace"),
+        )
+    })?;
+    let name = test
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect::<String>();
+    Ok(workspace_snapshot_dir(workspace_dir).join(format!("{name}.snap")))
+}
+
+/// Whether a test worker running `test_file` may use the snapshot file at
+/// `path`: only the test file's own snapshots and named snapshots in the
+/// workspace are reachable.
+pub(crate) fn in_scope(path: &Path, test_file: &Path, workspace_dir: Option<&Path>) -> bool {
+    file_snapshot_path(test_file).as_deref() == Some(path)
+        || workspace_dir.is_some_and(|dir| path.parent() == Some(&workspace_snapshot_dir(dir)))
+}
+
+fn file_snapshot_path(file: &Path) -> Option<PathBuf> {
+    let mut name = file.file_name()?.to_os_string();
+    name.push(".snap");
+    Some(file.parent()?.join(SNAPSHOT_DIR).join(name))
+}
+
+fn workspace_snapshot_dir(workspace_dir: &Path) -> PathBuf {
+    workspace_dir.join("yasumu").join(SNAPSHOT_DIR)
+}
+
+/// Reads the snapshots in `path`; a missing file has none.
+pub(crate) fn read(path: &Path) -> io::Result<BTreeMap<String, String>> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => parse(&contents).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {error}", path.display()),
+            )
+        }),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Writes `snapshots` to `path`, removing the file when there are none.
+pub(crate) fn write(path: &Path, snapshots: &BTreeMap<String, String>) -> io::Result<()> {
+    if snapshots.is_empty() {
+        return match std::fs::remove_file(path) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        };
+    }
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, serialize(snapshots))
+}
+
+fn serialize(snapshots: &BTreeMap<String, String>) -> String {
+    let mut contents = HEADER.to_string();
+    for (name, value) in snapshots {
+        contents.push_str(&format!(
+            "\nexports[`{}`] = `\n{}\n`;\n",
+            escape(name),
+            escape(value)
+        ));
+    }
+    contents
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+}
+
+fn parse(contents: &str) -> Result<BTreeMap<String, String>, String> {
+    let mut snapshots = BTreeMap::new();
+    let mut rest = contents;
+    while let Some(start) = rest.find("exports[`") {
+        let (name, after) = read_template(&rest[start + "exports[`".len()..])?;
+        let after = after
+            .strip_prefix("] = `")
+            .ok_or("expected \"] = `\" after a snapshot name")?;
+        let (value, after) = read_template(after)?;
+        let value = value.strip_prefix('\n').unwrap_or(&value);
+        let value = value.strip_suffix('\n').unwrap_or(value);
+        snapshots.insert(name, value.to_string());
+        rest = after;
+    }
+    Ok(snapshots)
+}
+
+/// Reads an escaped template literal body up to its closing backtick.
+fn read_template(text: &str) -> Result<(String, &str), String> {
+    let mut value = String::new();
+    let mut chars = text.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '`' => return Ok((value, &text[index + 1..])),
+            '\\' => match chars.next() {
+                Some((_, escaped @ ('\\' | '`' | '$'))) => value.push(escaped),
+                Some((_, other)) => {
+                    value.push('\\');
+                    value.push(other);
+                }
+                None => break,
+            },
+            _ => value.push(c),
+        }
+    }
+    Err("unterminated template literal".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{in_scope, parse, serialize, snapshot_path};
+    use std::collections::BTreeMap;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn round_trips_snapshots_with_special_characters() {
+        let snapshots = BTreeMap::from([
+            (
+                "api > lists users 1".to_string(),
+                "[\n  \"ada\",\n]".to_string(),
+            ),
+            (
+                "quotes `and` ${braces} 1".to_string(),
+                "\"C:\\\\path\" `tick` ${x}\n".to_string(),
+            ),
+        ]);
+
+        let contents = serialize(&snapshots);
+
+        assert!(contents.contains("exports[`api > lists users 1`] = `\n[\n  \"ada\",\n]\n`;"));
+        assert_eq!(parse(&contents).unwrap(), snapshots);
+    }
+
+    #[test]
+    fn stores_snapshots_next_to_the_test_or_in_the_workspace() {
+        let file = if cfg!(windows) {
+            "file:///C:/app/tests/api.test.ts"
+        } else {
+            "file:///app/tests/api.test.ts"
+        };
+        let expected = if cfg!(windows) {
+            PathBuf::from("C:\\app\\tests\\__snapshots__\\api.test.ts.snap")
+        } else {
+            PathBuf::from("/app/tests/__snapshots__/api.test.ts.snap")
+        };
+        assert_eq!(snapshot_path(file, None).unwrap(), expected);
+
+        assert_eq!(
+            snapshot_path("Create user / hook", Some(Path::new("/work"))).unwrap(),
+            Path::new("/work/yasumu/__snapshots__/Create_user___hook.snap")
+        );
+        assert!(snapshot_path("Create user", None).is_err());
+    }
+
+    #[test]
+    fn limits_test_workers_to_their_own_snapshots() {
+        let test_file = Path::new("/app/tests/api.test.ts");
+        let workspace = Some(Path::new("/work"));
+        let own = Path::new("/app/tests/__snapshots__/api.test.ts.snap");
+        let named = snapshot_path("Create user", workspace).unwrap();
+        let other = Path::new("/etc/__snapshots__/passwd.snap");
+
+        assert!(in_scope(own, test_file, workspace));
+        assert!(in_scope(&named, test_file, workspace));
+        assert!(!in_scope(other, test_file, workspace));
+        assert!(!in_scope(&named, test_file, None));
+    }
+}
@@ -1,7 +1,26 @@
 use crate::state::{RuntimeHost, RuntimeState};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 pub struct RuntimeHostState {
     pub host: Arc<dyn RuntimeHost>,
     pub state: Arc<RuntimeState>,
 }
+
+/// Stops the main worker with an exit code when a script calls `Deno.exit`.
+/// Only script, test, and benchmark workers have one.
+pub struct WorkerExit(pub Box<dyn Fn(i32) + Send + Sync>);
+
+/// Marks the supervised worker, where `Deno.exit` throws so the worker is
+/// restarted, or reported as failed, like after any other crash.
+pub struct SupervisedWorker;
+
+/// The test file run by a test worker, whose snapshots it may read and
+/// write. Other workers have no access to snapshots.
+pub struct SnapshotScope {
+    pub test_file: PathBuf,
+}
+
+/// Lets `Yasumu.hrtime()` read the clock at full resolution. Only benchmark
+/// workers have it.
+pub struct HighResolutionClock;
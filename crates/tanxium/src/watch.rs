@@ -0,0 +1,233 @@
+//! File watching for [`crate::Tanxium::run_file_watching`].
+//!
+//! The watcher follows every local file in the entrypoint's module graph,
+//! which is rebuilt on each restart so newly added imports are picked up,
+//! plus any extra paths or glob patterns supplied by the caller.
+
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use deno_core::ModuleSpecifier;
+use deno_runtime::deno_core::error::AnyError;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::module_graph::{self, ModuleKind};
+use crate::state::RuntimeState;
+
+/// How often [`FileWatcher::wait_for_changes`] polls while waiting.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Configures [`crate::Tanxium::run_file_watching`].
+#[derive(Clone, Debug)]
+pub struct WatchOptions {
+    /// Extra files, directories, or glob patterns to watch, relative to the
+    /// current directory. The entrypoint's local modules are always watched.
+    pub paths: Vec<String>,
+    /// How long changes must settle before the worker restarts, so one save
+    /// touching several files causes a single restart.
+    pub debounce: Duration,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            paths: Vec::new(),
+            debounce: Duration::from_millis(200),
+        }
+    }
+}
+
+/// Paths and glob patterns supplied through [`WatchOptions::paths`].
+struct ExtraPaths {
+    globs: GlobSet,
+    literals: Vec<PathBuf>,
+    /// Directories to watch so the patterns can match.
+    roots: Vec<(PathBuf, RecursiveMode)>,
+}
+
+impl ExtraPaths {
+    fn new(patterns: &[String], cwd: &Path) -> Result<Self, AnyError> {
+        let mut globs = GlobSetBuilder::new();
+        let mut literals = Vec::new();
+        let mut roots = Vec::new();
+
+        for pattern in patterns {
+            let path = cwd.join(pattern);
+            if is_glob(pattern) {
+                globs.add(Glob::new(&path.to_string_lossy())?);
+                let root = path
+                    .components()
+                    .take_while(|component| !is_glob(&component.as_os_str().to_string_lossy()))
+                    .collect::<PathBuf>();
+                roots.push((root, RecursiveMode::Recursive));
+            } else if path.is_dir() {
+                roots.push((path.clone(), RecursiveMode::Recursive));
+                literals.push(path);
+            } else {
+                roots.push((parent_dir(&path), RecursiveMode::NonRecursive));
+                literals.push(path);
+            }
+        }
+
+        Ok(Self {
+            globs: globs.build()?,
+            literals,
+            roots,
+        })
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        self.globs.is_match(path)
+            || self
+                .literals
+                .iter()
+                .any(|literal| path.starts_with(literal))
+    }
+}
+
+/// Watches an entrypoint's local module graph and extra paths.
+pub(crate) struct FileWatcher {
+    watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<notify::Event>>,
+    extra: ExtraPaths,
+    debounce: Duration,
+    graph_files: HashSet<PathBuf>,
+    graph_dirs: HashSet<PathBuf>,
+}
+
+impl FileWatcher {
+    pub(crate) fn new(options: &WatchOptions) -> Result<Self, AnyError> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        let extra = ExtraPaths::new(&options.paths, &std::env::current_dir()?)?;
+        for (root, mode) in &extra.roots {
+            if let Err(error) = watcher.watch(root, *mode) {
+                tracing::warn!("Unable to watch {}: {error}", root.display());
+            }
+        }
+
+        Ok(Self {
+            watcher,
+            events,
+            extra,
+            debounce: options.debounce,
+            graph_files: HashSet::new(),
+            graph_dirs: HashSet::new(),
+        })
+    }
+
+    /// Rebuilds the module graph of `root` and watches the directory of
+    /// each local module. Directories are watched rather than files so
+    /// editors that save by replacing a file, and imports of files that do
+    /// not exist yet, are still noticed.
+    pub(crate) fn watch_graph(&mut self, state: &Arc<RuntimeState>, root: &ModuleSpecifier) {
+        let graph = module_graph::build(state, root.clone());
+        self.graph_files = graph
+            .modules
+            .iter()
+            .filter(|module| module.kind == ModuleKind::Local)
+            .filter_map(|module| ModuleSpecifier::parse(&module.specifier).ok())
+            .chain([root.clone()])
+            .filter_map(|specifier| specifier.to_file_path().ok())
+            .collect();
+
+        let dirs = self
+            .graph_files
+            .iter()
+            .map(|path| parent_dir(path))
+            .collect::<HashSet<_>>();
+        for removed in self.graph_dirs.difference(&dirs) {
+            let _ = self.watcher.unwatch(removed);
+        }
+        for added in dirs.difference(&self.graph_dirs) {
+            if let Err(error) = self.watcher.watch(added, RecursiveMode::NonRecursive) {
+                tracing::warn!("Unable to watch {}: {error}", added.display());
+            }
+        }
+        self.graph_dirs = dirs;
+    }
+
+    /// Blocks until a watched file changes, then returns every path that
+    /// changed before the debounce period elapsed. `poll` is called
+    /// periodically while nothing has changed.
+    pub(crate) fn wait_for_changes(
+        &self,
+        mut poll: impl FnMut() -> Result<(), AnyError>,
+    ) -> Result<Vec<PathBuf>, AnyError> {
+        let mut changed = BTreeSet::new();
+        loop {
+            let event = if changed.is_empty() {
+                match self.events.recv_timeout(POLL_INTERVAL) {
+                    Ok(event) => event,
+                    Err(RecvTimeoutError::Timeout) => {
+                        poll()?;
+                        continue;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => {
+                        return Err(AnyError::msg("file watcher stopped"));
+                    }
+                }
+            } else {
+                match self.events.recv_timeout(self.debounce) {
+                    Ok(event) => event,
+                    Err(RecvTimeoutError::Timeout) => return Ok(changed.into_iter().collect()),
+                    Err(RecvTimeoutError::Disconnected) => {
+                        return Err(AnyError::msg("file watcher stopped"));
+                    }
+                }
+            };
+
+            match event {
+                Ok(event) if !matches!(event.kind, EventKind::Access(_)) => {
+                    changed.extend(event.paths.into_iter().filter(|path| self.is_watched(path)))
+                }
+                Ok(_) => {}
+                Err(error) => tracing::warn!("File watcher error: {error}"),
+            }
+        }
+    }
+
+    fn is_watched(&self, path: &Path) -> bool {
+        self.graph_files.contains(path) || self.extra.matches(path)
+    }
+}
+
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?', '[', '{'])
+}
+
+fn parent_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(parent) if parent.components().next().is_some() => parent.to_path_buf(),
+        _ => Component::CurDir.as_os_str().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExtraPaths;
+    use notify::RecursiveMode;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn matches_globs_and_paths_below_literal_directories() {
+        let extra = ExtraPaths::new(
+            &["config/**/*.yaml".to_string(), "fixtures".to_string()],
+            Path::new("/work"),
+        )
+        .unwrap();
+
+        assert!(extra.matches(Path::new("/work/config/env/dev.yaml")));
+        assert!(!extra.matches(Path::new("/work/config/env/dev.json")));
+        assert!(extra.matches(Path::new("/work/fixtures/users.json")));
+        assert!(!extra.matches(Path::new("/work/src/main.ts")));
+        assert_eq!(
+            extra.roots[0],
+            (PathBuf::from("/work/config"), RecursiveMode::Recursive)
+        );
+    }
+}
@@ -1,15 +1,19 @@
+use crate::js_error::{format_error, format_js_error};
 use crate::module_loader::TypescriptModuleLoader;
 use crate::node_services;
 use crate::ops::tanxium_runtime_extensions;
 use crate::snapshot::{TANXIUM_RESIDUAL_LAZY_ESM, TANXIUM_RESIDUAL_LAZY_JS, TANXIUM_SNAPSHOT};
+use crate::standalone::StandaloneArchive;
 use crate::state::{RuntimeEvent, RuntimeHost, RuntimeState};
-use crate::types::RuntimeHostState;
+use crate::types::{
+    HighResolutionClock, RuntimeHostState, SnapshotScope, SupervisedWorker, WorkerExit,
+};
 use crate::version::{DENO_VERSION, TANXIUM_VERSION};
 use deno_resolver::npm::{DenoInNpmPackageChecker, NpmResolver};
 use deno_runtime::UNSTABLE_FEATURES;
 use deno_runtime::colors;
 use deno_runtime::deno_core::{
-    CompiledWasmModuleStore, ModuleSpecifier, SharedArrayBufferStore, error::AnyError,
+    CompiledWasmModuleStore, ModuleSpecifier, SharedArrayBufferStore, error::AnyError, v8,
 };
 use deno_runtime::deno_fs::{FileSystem, RealFs};
 use deno_runtime::deno_io::Stdio;
@@ -26,9 +30,10 @@ use node_resolver::PackageJsonResolver;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use sys_traits::impls::RealSys;
 use tracing::{error, info, warn};
 
@@ -75,6 +80,81 @@ fn compute_backoff(attempt: u32) -> Duration {
     Duration::from_millis(capped + jitter)
 }
 
+/// How a main worker started with [`crate::Tanxium::run_file_blocking`]
+/// ended.
+#[derive(Clone, Debug)]
+pub struct ExecutionReport {
+    /// The code passed to `Deno.exit`, or `Deno.exitCode` when the script
+    /// finished on its own. An uncaught error exits with 1.
+    pub exit_code: i32,
+    /// The uncaught error, with its JavaScript stack, that ended the script.
+    pub error: Option<String>,
+    pub duration: Duration,
+    /// How many times the worker was restarted after failing.
+    pub restarts: u32,
+}
+
+impl ExecutionReport {
+    /// Whether the script finished without an error and exited with 0.
+    pub fn success(&self) -> bool {
+        self.exit_code == 0 && self.error.is_none()
+    }
+}
+
+/// Stops a main worker from another thread, for example to restart it.
+///
+/// Pending async work is dropped with the worker, and the isolate is
+/// terminated so synchronous code such as an infinite loop is interrupted
+/// too. A request made before the worker has started stops it as soon as it
+/// does.
+#[derive(Clone, Default)]
+pub(crate) struct WorkerShutdown {
+    requested: Arc<AtomicBool>,
+    notify: Arc<tokio::sync::Notify>,
+    isolate: Arc<Mutex<Option<v8::IsolateHandle>>>,
+    exit_code: Arc<Mutex<Option<i32>>>,
+}
+
+impl WorkerShutdown {
+    /// Stops the worker on behalf of `Deno.exit`.
+    fn exit(&self, code: i32) {
+        *self.exit_code.lock().expect("exit code lock poisoned") = Some(code);
+        self.request();
+    }
+
+    fn exit_code(&self) -> Option<i32> {
+        *self.exit_code.lock().expect("exit code lock poisoned")
+    }
+
+    pub(crate) fn request(&self) {
+        self.requested.store(true, Ordering::SeqCst);
+        if let Some(isolate) = self
+            .isolate
+            .lock()
+            .expect("isolate handle lock poisoned")
+            .as_ref()
+        {
+            isolate.terminate_execution();
+        }
+        self.notify.notify_one();
+    }
+
+    fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+
+    fn attach(&self, isolate: v8::IsolateHandle) {
+        if self.is_requested() {
+            isolate.terminate_execution();
+        }
+        *self.isolate.lock().expect("isolate handle lock poisoned") = Some(isolate);
+    }
+
+    async fn requested(&self) {
+        self.notify.notified().await;
+    }
+}
+
 struct WorkerSharedState {
     blob_store: Arc<BlobStore>,
     broadcast_channel: InMemoryBroadcastChannel,
@@ -87,9 +167,20 @@ struct WorkerSharedState {
     pkg_json_resolver: Arc<PackageJsonResolver<RealSys>>,
     virtual_modules: Arc<Mutex<HashMap<String, String>>>,
     workspace_dir: Option<std::path::PathBuf>,
+    shutdown: WorkerShutdown,
+    standalone: Option<Arc<StandaloneArchive>>,
 }
 
 impl WorkerSharedState {
+    fn script_args(&self) -> Vec<String> {
+        self.state
+            .context
+            .read()
+            .expect("runtime context lock poisoned")
+            .args
+            .clone()
+    }
+
     fn create_web_worker_callback(
         self: &Arc<Self>,
         stdio: Stdio,
@@ -108,6 +199,8 @@ impl WorkerSharedState {
                     .map(|blob| (args.main_module.clone(), blob)),
                 state: shared.state.clone(),
                 pkg_json_resolver: shared.pkg_json_resolver.clone(),
+                workspace_config: Default::default(),
+                standalone: shared.standalone.clone(),
             });
 
             let permission_desc_parser =
@@ -119,6 +212,7 @@ impl WorkerSharedState {
 
             let node_require_loader = Rc::new(node_services::TanxiumNodeRequireLoader::new(
                 shared.workspace_dir.as_deref(),
+                shared.pkg_json_resolver.clone(),
             ));
             let node_services = Some(node_services::create_node_init_services(
                 node_require_loader,
@@ -158,7 +252,7 @@ impl WorkerSharedState {
                 wait_for_page_wait_for_debugger: false,
                 bootstrap: BootstrapOptions {
                     deno_version: DENO_VERSION.to_string(),
-                    args: vec![],
+                    args: shared.script_args(),
                     cpu_count: thread::available_parallelism()
                         .map(|p| p.get())
                         .unwrap_or(1),
@@ -170,7 +264,7 @@ impl WorkerSharedState {
                     unstable_features: enabled_unstable_feature_ids(),
                     user_agent,
                     inspect: false,
-                    is_standalone: false,
+                    is_standalone: shared.standalone.is_some(),
                     auto_serve: false,
                     has_node_modules_dir: true,
                     mode: deno_runtime::WorkerExecutionMode::Worker,
@@ -185,7 +279,7 @@ impl WorkerSharedState {
                 unsafely_ignore_certificate_errors: None,
                 seed: None,
                 create_web_worker_cb,
-                format_js_error_fn: None,
+                format_js_error_fn: Some(Arc::new(format_js_error)),
                 worker_type: args.worker_type,
                 stdio: stdio.clone(),
                 cache_storage_dir: None,
@@ -222,6 +316,7 @@ async fn initialize_worker(
     shared: &Arc<WorkerSharedState>,
     host: Arc<dyn RuntimeHost>,
     main_worker_all_permissions: bool,
+    role: &WorkerRole,
 ) -> Result<MainWorker, AnyError> {
     let permission_desc_parser =
         Arc::new(RuntimePermissionDescriptorParser::<RealSys>::new(RealSys));
@@ -240,6 +335,7 @@ async fn initialize_worker(
 
     let node_require_loader = Rc::new(node_services::TanxiumNodeRequireLoader::new(
         shared.workspace_dir.as_deref(),
+        shared.pkg_json_resolver.clone(),
     ));
     let node_services = Some(node_services::create_node_init_services(
         node_require_loader,
@@ -261,6 +357,8 @@ async fn initialize_worker(
                 main_module_blob: None,
                 state: shared.state.clone(),
                 pkg_json_resolver: shared.pkg_json_resolver.clone(),
+                workspace_config: Default::default(),
+                standalone: shared.standalone.clone(),
             }),
             permissions,
             bundle_provider: Default::default(),
@@ -284,8 +382,10 @@ async fn initialize_worker(
             residual_lazy_esm_sources: TANXIUM_RESIDUAL_LAZY_ESM,
             bootstrap: BootstrapOptions {
                 deno_version: DENO_VERSION.to_string(),
+                args: shared.script_args(),
                 user_agent,
                 unstable_features: enabled_unstable_feature_ids(),
+                is_standalone: shared.standalone.is_some(),
                 // Enable Deno's Node compatibility bootstrap for main workers.
                 // Without this, CommonJS modules that use `createRequire()`
                 // cannot initialize their Node built-ins.
@@ -294,12 +394,17 @@ async fn initialize_worker(
                 ..Default::default()
             },
             stdio: stdio.clone(),
+            enable_raw_imports: true,
             enable_stack_trace_arg_in_ops: true,
             create_web_worker_cb,
+            format_js_error_fn: Some(Arc::new(format_js_error)),
             ..Default::default()
         },
     );
 
+    shared
+        .shutdown
+        .attach(worker.js_runtime.v8_isolate().thread_safe_handle());
     worker
         .js_runtime
         .op_state()
@@ -308,6 +413,39 @@ async fn initialize_worker(
             host,
             state: shared.state.clone(),
         });
+    if *role == WorkerRole::Supervised {
+        worker
+            .js_runtime
+            .op_state()
+            .borrow_mut()
+            .put(SupervisedWorker);
+    } else {
+        let shutdown = shared.shutdown.clone();
+        worker
+            .js_runtime
+            .op_state()
+            .borrow_mut()
+            .put(WorkerExit(Box::new(move |code| shutdown.exit(code))));
+    }
+    match role {
+        WorkerRole::Test(test_file) => {
+            worker
+                .js_runtime
+                .op_state()
+                .borrow_mut()
+                .put(SnapshotScope {
+                    test_file: test_file.clone(),
+                });
+        }
+        WorkerRole::Bench => {
+            worker
+                .js_runtime
+                .op_state()
+                .borrow_mut()
+                .put(HighResolutionClock);
+        }
+        WorkerRole::Supervised | WorkerRole::Script => {}
+    }
 
     worker
         .execute_side_module(&tanxium_bootstrap_specifier())
@@ -393,14 +531,44 @@ async fn run_worker_event_loop(
     }
 }
 
+/// What a worker started by [`start_worker`] runs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum WorkerRole {
+    /// The host's script. A failure restarts it with backoff, up to five
+    /// times, before a [`RuntimeEvent::Failure`] is emitted.
+    Supervised,
+    /// A script whose failure is only reported.
+    Script,
+    /// A test file, the only worker that may read and write snapshots, and
+    /// only its own. Like benchmark files, it never receives host events, so
+    /// parallel files do not take [`crate::Tanxium::send_event`] away from
+    /// the host's script.
+    Test(std::path::PathBuf),
+    /// A benchmark file, the only worker whose `Yasumu.hrtime()` is not
+    /// coarsened.
+    Bench,
+}
+
+/// Starts a main worker on its own runtime thread. The thread returns how
+/// the worker ended once it exits.
 pub(crate) fn start_worker(
     main_module: ModuleSpecifier,
     state: Arc<RuntimeState>,
     host: Arc<dyn RuntimeHost>,
     main_worker_all_permissions: bool,
-) -> Result<std::thread::JoinHandle<()>, AnyError> {
+    shutdown: WorkerShutdown,
+    standalone: Option<Arc<StandaloneArchive>>,
+    role: WorkerRole,
+) -> Result<std::thread::JoinHandle<ExecutionReport>, AnyError> {
     let handle = thread::spawn(move || {
         info!("Starting Deno runtime thread");
+        let started = Instant::now();
+        let mut report = ExecutionReport {
+            exit_code: 0,
+            error: None,
+            duration: Duration::ZERO,
+            restarts: 0,
+        };
 
         let runtime = match tokio::runtime::Builder::new_current_thread()
             .enable_all()
@@ -409,11 +577,13 @@ pub(crate) fn start_worker(
             Ok(rt) => rt,
             Err(e) => {
                 error!("Failed to create tokio runtime: {}", e);
-                return;
+                report.exit_code = 1;
+                report.error = Some(format!("Failed to create tokio runtime: {e}"));
+                return report;
             }
         };
 
-        runtime.block_on(async move {
+        runtime.block_on(async {
             let pkg_json_resolver = node_services::create_pkg_json_resolver();
 
             let workspace_dir = state
@@ -445,80 +615,113 @@ pub(crate) fn start_worker(
                 pkg_json_resolver,
                 virtual_modules,
                 workspace_dir,
+                shutdown: shutdown.clone(),
+                standalone,
             });
 
-            const MAX_RETRIES: u32 = 5;
-            let mut retry_count = 0u32;
-
-            loop {
-                if retry_count > 0 {
-                    let delay = compute_backoff(retry_count - 1);
-                    warn!(
-                        "Reinitializing worker (attempt {}/{}) after {:?}",
-                        retry_count + 1,
-                        MAX_RETRIES + 1,
-                        delay
-                    );
-                    tokio::time::sleep(delay).await;
-                }
+            let run = async {
+                const MAX_RETRIES: u32 = 5;
+                let mut retry_count = 0u32;
+
+                loop {
+                    if retry_count > 0 {
+                        let delay = compute_backoff(retry_count - 1);
+                        warn!(
+                            "Reinitializing worker (attempt {}/{}) after {:?}",
+                            retry_count + 1,
+                            MAX_RETRIES + 1,
+                            delay
+                        );
+                        tokio::time::sleep(delay).await;
+                        report.restarts = retry_count;
+                    }
 
-                match initialize_worker(
-                    &main_module,
-                    &shared,
-                    host.clone(),
-                    main_worker_all_permissions,
-                )
-                .await
-                {
-                    Ok(mut worker) => {
-                        let (event_sender, mut event_receiver) =
-                            tokio::sync::mpsc::unbounded_channel();
-                        *state
-                            .event_sender
-                            .lock()
-                            .expect("event sender lock poisoned") = Some(event_sender);
-
-                        match run_worker_event_loop(&mut worker, &mut event_receiver).await {
-                            Ok(_) => {
-                                info!("Worker event loop completed normally");
-                                break;
-                            }
-                            Err(e) => {
-                                let msg = e.to_string();
-                                if msg.contains("Event receiver channel closed") {
-                                    info!("Renderer channel closed — shutting down");
-                                    break;
+                    let failure = match initialize_worker(
+                        &main_module,
+                        &shared,
+                        host.clone(),
+                        main_worker_all_permissions,
+                        &role,
+                    )
+                    .await
+                    {
+                        Ok(mut worker) => {
+                            let (event_sender, mut event_receiver) =
+                                tokio::sync::mpsc::unbounded_channel();
+                            // Test and benchmark workers hold their own
+                            // sender, which keeps the channel open without
+                            // ever delivering an event.
+                            let _private_sender = match role {
+                                WorkerRole::Test(_) | WorkerRole::Bench => Some(event_sender),
+                                WorkerRole::Supervised | WorkerRole::Script => {
+                                    *state
+                                        .event_sender
+                                        .lock()
+                                        .expect("event sender lock poisoned") = Some(event_sender);
+                                    None
                                 }
+                            };
 
-                                error!("Worker error: {}", msg);
-                                retry_count += 1;
-
-                                if retry_count > MAX_RETRIES {
-                                    error!(
-                                        "Exceeded max retries ({}) — showing crash dialog",
-                                        MAX_RETRIES
-                                    );
-                                    host.emit_event(RuntimeEvent::Failure(msg));
+                            match run_worker_event_loop(&mut worker, &mut event_receiver).await {
+                                Ok(_) => {
+                                    info!("Worker event loop completed normally");
+                                    report.exit_code = worker.exit_code();
                                     break;
                                 }
+                                Err(e) => {
+                                    let msg = e.to_string();
+                                    if shutdown.is_requested() {
+                                        break;
+                                    }
+                                    if msg.contains("Event receiver channel closed") {
+                                        info!("Renderer channel closed — shutting down");
+                                        break;
+                                    }
+
+                                    error!("Worker error: {}", msg);
+                                    format_error(&e)
+                                }
                             }
                         }
-                    }
-                    Err(e) => {
-                        let message = format!("Failed to initialize worker: {e}");
-                        error!("{message}");
-                        retry_count += 1;
-                        if retry_count > MAX_RETRIES {
-                            error!("Exceeded max retries — giving up");
-                            host.emit_event(RuntimeEvent::Failure(message));
-                            break;
+                        Err(_) if shutdown.is_requested() => break,
+                        Err(e) => {
+                            error!("Failed to initialize worker: {e}");
+                            format_error(&e)
                         }
+                    };
+
+                    report.exit_code = 1;
+                    if role != WorkerRole::Supervised {
+                        report.error = Some(failure);
+                        break;
+                    }
+                    retry_count += 1;
+                    if retry_count > MAX_RETRIES {
+                        error!(
+                            "Exceeded max retries ({}) — showing crash dialog",
+                            MAX_RETRIES
+                        );
+                        host.emit_event(RuntimeEvent::Failure(failure.clone()));
+                        report.error = Some(failure);
+                        break;
                     }
                 }
+            };
+
+            tokio::select! {
+                _ = run => {}
+                _ = shutdown.requested() => info!("Worker shut down on request"),
             }
 
             info!("Deno runtime thread exiting");
         });
+
+        if let Some(code) = shutdown.exit_code() {
+            report.exit_code = code;
+            report.error = None;
+        }
+        report.duration = started.elapsed();
+        report
     });
 
     Ok(handle)
@@ -0,0 +1,356 @@
+//! Workspace configuration read from `deno.json`, `deno.jsonc`,
+//! `tsconfig.json`, and `import_map.json`.
+
+use std::path::{Path, PathBuf};
+
+use deno_ast::{
+    DecoratorsTranspileOption, ImportsNotUsedAsValues, JsxAutomaticOptions, JsxClassicOptions,
+    JsxRuntime, ModuleSpecifier, TranspileOptions,
+};
+use import_map::ImportMap;
+use serde_json::Value;
+
+/// Configuration files probed in the workspace root, in priority order.
+const CONFIG_FILE_NAMES: [&str; 2] = ["deno.json", "deno.jsonc"];
+
+/// Standalone import map used when the workspace has no `deno.json`.
+const IMPORT_MAP_FILE_NAME: &str = "import_map.json";
+
+/// Compiler options are read from here when `deno.json` has none.
+const TSCONFIG_FILE_NAME: &str = "tsconfig.json";
+
+/// JSX import source used by Yasumu scripts unless the workspace sets one.
+const DEFAULT_JSX_IMPORT_SOURCE: &str = "yasumu:ui";
+
+/// Settings resolved once per workspace and shared by module resolution and
+/// transpilation.
+#[derive(Debug)]
+pub(crate) struct WorkspaceConfig {
+    pub import_map: Option<ImportMap>,
+    pub transpile_options: TranspileOptions,
+}
+
+impl WorkspaceConfig {
+    /// Loads the configuration for a workspace. A builder-supplied import map
+    /// takes precedence over one found in the workspace.
+    pub fn load(
+        workspace_dir: Option<&Path>,
+        import_map_json: Option<&str>,
+    ) -> Result<Self, String> {
+        let config_file = match workspace_dir.and_then(find_config_file) {
+            Some(path) => {
+                let value = read_jsonc(&path)?;
+                Some((path, value))
+            }
+            None => None,
+        };
+
+        let import_map = match import_map_json {
+            Some(json) => {
+                let base_url = directory_url(workspace_dir)?;
+                Some(parse_import_map(base_url, json, "the runtime import map")?)
+            }
+            None => load_workspace_import_map(workspace_dir, config_file.as_ref())?,
+        };
+
+        let compiler_options = match config_file
+            .as_ref()
+            .and_then(|(path, config)| Some((path, config.get("compilerOptions")?)))
+        {
+            Some((path, compiler_options)) => Some((path.clone(), compiler_options.clone())),
+            None => read_tsconfig_compiler_options(workspace_dir),
+        };
+        let transpile_options = match compiler_options {
+            Some((path, compiler_options)) => transpile_options(&compiler_options, &path),
+            None => default_transpile_options(),
+        };
+
+        Ok(Self {
+            import_map,
+            transpile_options,
+        })
+    }
+}
+
+/// Options used for Yasumu's built-in modules and for workspaces that do not
+/// configure the compiler: legacy decorators with metadata, and automatic
+/// JSX backed by `yasumu:ui`.
+pub(crate) fn default_transpile_options() -> TranspileOptions {
+    TranspileOptions {
+        imports_not_used_as_values: ImportsNotUsedAsValues::Remove,
+        decorators: DecoratorsTranspileOption::LegacyTypeScript {
+            emit_metadata: true,
+        },
+        jsx: Some(JsxRuntime::Automatic(JsxAutomaticOptions {
+            development: false,
+            import_source: Some(DEFAULT_JSX_IMPORT_SOURCE.to_string()),
+        })),
+        ..Default::default()
+    }
+}
+
+/// Maps the supported subset of TypeScript `compilerOptions` onto the
+/// defaults. Unknown keys are ignored and unsupported values are replaced by
+/// the default with a warning. Decorators follow TypeScript's defaults, so
+/// legacy decorators need `experimentalDecorators` once a workspace
+/// configures the compiler.
+fn transpile_options(compiler_options: &Value, origin: &Path) -> TranspileOptions {
+    let string = |key: &str| compiler_options.get(key).and_then(Value::as_str);
+    let flag = |key: &str, default: bool| {
+        compiler_options
+            .get(key)
+            .and_then(Value::as_bool)
+            .unwrap_or(default)
+    };
+
+    let import_source = string("jsxImportSource").unwrap_or(DEFAULT_JSX_IMPORT_SOURCE);
+    let automatic = |development| {
+        JsxRuntime::Automatic(JsxAutomaticOptions {
+            development,
+            import_source: Some(import_source.to_string()),
+        })
+    };
+    let jsx = match string("jsx") {
+        None | Some("react-jsx") => automatic(false),
+        Some("react-jsxdev") => automatic(true),
+        Some("react") => JsxRuntime::Classic(JsxClassicOptions {
+            factory: string("jsxFactory")
+                .unwrap_or("React.createElement")
+                .to_string(),
+            fragment_factory: string("jsxFragmentFactory")
+                .unwrap_or("React.Fragment")
+                .to_string(),
+        }),
+        Some(other) => {
+            tracing::warn!(
+                "Unsupported compilerOptions.jsx {other:?} in {}; expected \"react\", \"react-jsx\", or \"react-jsxdev\", using \"react-jsx\"",
+                origin.display()
+            );
+            automatic(false)
+        }
+    };
+
+    let decorators = if flag("experimentalDecorators", false) {
+        DecoratorsTranspileOption::LegacyTypeScript {
+            emit_metadata: flag("emitDecoratorMetadata", false),
+        }
+    } else {
+        DecoratorsTranspileOption::Ecma
+    };
+
+    TranspileOptions {
+        jsx: Some(jsx),
+        decorators,
+        verbatim_module_syntax: flag("verbatimModuleSyntax", false),
+        ..default_transpile_options()
+    }
+}
+
+/// Reads the `compilerOptions` of the workspace's `tsconfig.json`. The file
+/// may belong to other tools, so one that cannot be read is ignored with a
+/// warning.
+fn read_tsconfig_compiler_options(workspace_dir: Option<&Path>) -> Option<(PathBuf, Value)> {
+    let path = workspace_dir
+        .map(|dir| dir.join(TSCONFIG_FILE_NAME))
+        .filter(|path| path.is_file())?;
+
+    match read_jsonc(&path) {
+        Ok(tsconfig) => Some((path, tsconfig.get("compilerOptions")?.clone())),
+        Err(error) => {
+            tracing::warn!("{error}; using the default compiler options");
+            None
+        }
+    }
+}
+
+fn find_config_file(workspace_dir: &Path) -> Option<PathBuf> {
+    CONFIG_FILE_NAMES
+        .iter()
+        .map(|name| workspace_dir.join(name))
+        .find(|path| path.is_file())
+}
+
+fn read_jsonc(path: &Path) -> Result<Value, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    jsonc_parser::parse_to_serde_value(&text, &Default::default())
+        .map_err(|error| format!("Invalid JSON in {}: {error}", path.display()))
+        .map(|value| value.unwrap_or(Value::Null))
+}
+
+fn load_workspace_import_map(
+    workspace_dir: Option<&Path>,
+    config_file: Option<&(PathBuf, Value)>,
+) -> Result<Option<ImportMap>, String> {
+    if let Some((path, config)) = config_file {
+        let config_url = file_url(path)?;
+
+        if let Some(import_map_path) = config.get("importMap").and_then(Value::as_str) {
+            let import_map_url = config_url.join(import_map_path).map_err(|error| {
+                format!(
+                    "Invalid importMap {import_map_path:?} in {}: {error}",
+                    path.display()
+                )
+            })?;
+            let import_map_path = import_map_url
+                .to_file_path()
+                .map_err(|_| format!("importMap in {} must be a local file", path.display()))?;
+            return read_import_map(&import_map_path).map(Some);
+        }
+
+        if let Some(import_map) = inline_import_map(config) {
+            let origin = path.display().to_string();
+            return parse_import_map(config_url, &import_map.to_string(), &origin).map(Some);
+        }
+    }
+
+    match workspace_dir.map(|dir| dir.join(IMPORT_MAP_FILE_NAME)) {
+        Some(path) if path.is_file() => read_import_map(&path).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Extracts the `imports` and `scopes` keys of a `deno.json` as a standalone
+/// import map.
+fn inline_import_map(config: &Value) -> Option<Value> {
+    let mut import_map = serde_json::Map::new();
+    for key in ["imports", "scopes"] {
+        if let Some(value) = config.get(key) {
+            import_map.insert(key.to_string(), value.clone());
+        }
+    }
+
+    (!import_map.is_empty()).then_some(Value::Object(import_map))
+}
+
+fn read_import_map(path: &Path) -> Result<ImportMap, String> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read {}: {error}", path.display()))?;
+    parse_import_map(file_url(path)?, &json, &path.display().to_string())
+}
+
+fn parse_import_map(
+    base_url: ModuleSpecifier,
+    json: &str,
+    origin: &str,
+) -> Result<ImportMap, String> {
+    import_map::parse_from_json(base_url, json)
+        .map(|result| {
+            for diagnostic in &result.diagnostics {
+                tracing::warn!("Import map in {origin}: {diagnostic}");
+            }
+            result.import_map
+        })
+        .map_err(|error| format!("Invalid import map in {origin}: {error}"))
+}
+
+fn file_url(path: &Path) -> Result<ModuleSpecifier, String> {
+    ModuleSpecifier::from_file_path(path)
+        .map_err(|_| format!("Unable to create a file URL for {}", path.display()))
+}
+
+/// Relative targets in a builder-supplied import map resolve against the
+/// workspace, or the process working directory when there is none.
+fn directory_url(workspace_dir: Option<&Path>) -> Result<ModuleSpecifier, String> {
+    let dir = match workspace_dir {
+        Some(dir) => dir.to_path_buf(),
+        None => std::env::current_dir()
+            .map_err(|error| format!("Unable to determine the working directory: {error}"))?,
+    };
+    ModuleSpecifier::from_directory_path(&dir)
+        .map_err(|_| format!("Unable to create a file URL for {}", dir.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        default_transpile_options, inline_import_map, parse_import_map, transpile_options,
+    };
+    use deno_ast::{DecoratorsTranspileOption, JsxRuntime, ModuleSpecifier};
+    use serde_json::json;
+    use std::path::Path;
+
+    #[test]
+    fn resolves_scoped_mappings_before_top_level_imports() {
+        let config = json!({
+            "imports": { "@team/utils": "./shared/utils/mod.ts" },
+            "scopes": { "./legacy/": { "@team/utils": "./shared/utils/v1.ts" } },
+            "tasks": {},
+        });
+        let import_map = inline_import_map(&config).unwrap();
+        let base = ModuleSpecifier::parse("file:///workspace/deno.json").unwrap();
+        let import_map = parse_import_map(base, &import_map.to_string(), "deno.json").unwrap();
+
+        let referrer = ModuleSpecifier::parse("file:///workspace/scripts/a.ts").unwrap();
+        assert_eq!(
+            import_map
+                .resolve("@team/utils", &referrer)
+                .unwrap()
+                .as_str(),
+            "file:///workspace/shared/utils/mod.ts"
+        );
+
+        let referrer = ModuleSpecifier::parse("file:///workspace/legacy/b.ts").unwrap();
+        assert_eq!(
+            import_map
+                .resolve("@team/utils", &referrer)
+                .unwrap()
+                .as_str(),
+            "file:///workspace/shared/utils/v1.ts"
+        );
+    }
+
+    #[test]
+    fn ignores_config_files_without_mappings() {
+        assert!(inline_import_map(&json!({ "compilerOptions": {} })).is_none());
+    }
+
+    #[test]
+    fn maps_compiler_options_onto_the_yasumu_defaults() {
+        let origin = Path::new("/workspace/deno.json");
+        let options = default_transpile_options();
+        assert!(matches!(
+            options.decorators,
+            DecoratorsTranspileOption::LegacyTypeScript {
+                emit_metadata: true
+            }
+        ));
+
+        let options = transpile_options(&json!({}), origin);
+        assert!(matches!(
+            options.decorators,
+            DecoratorsTranspileOption::Ecma
+        ));
+        assert!(matches!(
+            options.jsx,
+            Some(JsxRuntime::Automatic(ref jsx)) if jsx.import_source.as_deref() == Some("yasumu:ui")
+        ));
+
+        let options = transpile_options(
+            &json!({
+                "jsx": "react-jsx",
+                "jsxImportSource": "react",
+                "experimentalDecorators": true,
+                "verbatimModuleSyntax": true,
+            }),
+            origin,
+        );
+        assert!(matches!(
+            options.decorators,
+            DecoratorsTranspileOption::LegacyTypeScript {
+                emit_metadata: false
+            }
+        ));
+        assert!(matches!(
+            options.jsx,
+            Some(JsxRuntime::Automatic(ref jsx)) if jsx.import_source.as_deref() == Some("react")
+        ));
+        assert!(options.verbatim_module_syntax);
+
+        let options = transpile_options(&json!({ "jsx": "preserve" }), origin);
+        assert!(matches!(
+            options.jsx,
+            Some(JsxRuntime::Automatic(ref jsx)) if !jsx.development
+        ));
+    }
+}
@@ -9,6 +9,7 @@ const YASUMU_MODULE_ENTRIES: &[(&str, &str)] = &[
         include_str!("runtime/modules/jsx-runtime.ts"),
     ),
     ("yasumu:test", include_str!("runtime/modules/test.js")),
+    ("yasumu:bench", include_str!("runtime/modules/bench.ts")),
     (
         "yasumu:workspace",
         include_str!("runtime/modules/workspace.ts"),
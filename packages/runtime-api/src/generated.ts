@@ -5,6 +5,7 @@ export const SCRIPT_HOOK_NAMES = ['onRequest', 'onResponse', 'onTest', 'onEmail'
 export type ScriptHookName = (typeof SCRIPT_HOOK_NAMES)[number];
 export const VIRTUAL_MODULES = {
   'yasumu:test': ['describe', 'test', 'expect'],
+  'yasumu:bench': ['bench'],
   'yasumu:workspace': ['workspace'],
   'yasumu:runtime': ['runtime'],
   'yasumu:env': ['env'],
@@ -0,0 +1,5 @@
+/**
+ * Benchmarks are measured by `tanxium bench`. Scripts run by this adapter
+ * only register them, which does nothing, like `test` outside a test run.
+ */
+export function bench(_name: string, _fn: () => unknown, _options?: object): void {}
@@ -191,6 +191,8 @@ function virtualModuleSource(specifier: keyof typeof VIRTUAL_MODULES): string {
   switch (specifier) {
     case 'yasumu:test':
       return `export { describe, expect, test } from ${runtime};`;
+    case 'yasumu:bench':
+      return `export { bench } from ${runtime};`;
     case 'yasumu:workspace':
       return workspaceModuleUrl
         ? `export { workspace } from ${runtime}; export * from ${JSON.stringify(workspaceModuleUrl)};`
@@ -1,2 +1,3 @@
 export { env, files, runtime, workspace } from './execution-state.js';
 export { describe, expect, test } from './test-runtime.js';
+export { bench } from './bench-runtime.js';
@@ -4,3 +4,4 @@ export {
   type TanxiumScriptRuntimeOptions,
 } from './tanxium-script-runtime.ts';
 export { TanxiumRuntimeError } from '../../workers/script-worker.ts';
+export type { WorkspaceEnvOptions } from '../../workers/types.ts';
@@ -9,6 +9,7 @@ import type {
 } from '../../../../runtime-api/src/types.ts';
 import { ScriptWorker, TanxiumRuntimeError } from '../../workers/script-worker.ts';
 import { WorkerThreadsStrategy } from '../../workers/strategies/worker-threads.strategy.ts';
+import type { WorkspaceEnvOptions } from '../../workers/types.ts';
 import { getHeadlessWorkerPreload } from '../../workers/worker-preload-core.ts';
 
 const TANXIUM_CAPABILITIES = Object.freeze({
@@ -31,6 +32,12 @@ const DEFAULT_EXECUTION_TIMEOUT = 30_000;
 
 export interface TanxiumScriptRuntimeOptions {
   defaultTimeoutMs?: number;
+  /**
+   * Back `Deno.env` and `process.env` in hooks with the active workspace
+   * environment, hiding the host process environment unless allowed. By
+   * default hooks see the host process environment.
+   */
+  workspaceEnv?: WorkspaceEnvOptions;
 }
 
 export class TanxiumScriptRuntime implements YasumuScriptRuntime {
@@ -38,13 +45,15 @@ export class TanxiumScriptRuntime implements YasumuScriptRuntime {
   public readonly capabilities = TANXIUM_CAPABILITIES;
 
   readonly #defaultTimeoutMs: number;
+  readonly #workspaceEnv?: WorkspaceEnvOptions;
 
   public constructor(options: TanxiumScriptRuntimeOptions = {}) {
     this.#defaultTimeoutMs = options.defaultTimeoutMs ?? DEFAULT_EXECUTION_TIMEOUT;
+    this.#workspaceEnv = options.workspaceEnv;
   }
 
   public async createSession(input: CreateRuntimeSessionInput): Promise<YasumuRuntimeSession> {
-    const session = new TanxiumRuntimeSession(input, this.#defaultTimeoutMs);
+    const session = new TanxiumRuntimeSession(input, this.#defaultTimeoutMs, this.#workspaceEnv);
     try {
       await session.start();
       return session;
@@ -63,15 +72,17 @@ class TanxiumRuntimeSession implements YasumuRuntimeSession {
   readonly #id = crypto.randomUUID();
   readonly #input: CreateRuntimeSessionInput;
   readonly #defaultTimeoutMs: number;
+  readonly #workspaceEnv?: WorkspaceEnvOptions;
   readonly #worker: ScriptWorker;
   readonly #workspaceModuleKey?: string;
 
   #disposed = false;
   #queue: Promise<void> = Promise.resolve();
 
-  public constructor(input: CreateRuntimeSessionInput, defaultTimeoutMs: number) {
+  public constructor(input: CreateRuntimeSessionInput, defaultTimeoutMs: number, workspaceEnv?: WorkspaceEnvOptions) {
     this.#input = input;
     this.#defaultTimeoutMs = defaultTimeoutMs;
+    this.#workspaceEnv = workspaceEnv;
     this.#worker = new ScriptWorker({
       source: getHeadlessWorkerPreload('worker-threads'),
       strategy: new WorkerThreadsStrategy(),
@@ -121,6 +132,7 @@ class TanxiumRuntimeSession implements YasumuRuntimeSession {
     return this.#worker.invokeRuntimeHook(moduleKey, invocation, this.#input.hostCall, {
       ...options,
       timeoutMs: options.timeoutMs ?? this.#defaultTimeoutMs,
+      workspaceEnv: this.#workspaceEnv,
     });
   }
 
@@ -6,6 +6,7 @@ import { environments, workspaces } from '../../../database/schema.ts';
 import { NotFoundException } from '../common/exceptions/http.exception.ts';
 import { TransactionalConnection } from '../common/transactional-connection.service.ts';
 import { WorkspacesService } from '../workspaces/workspaces.service.ts';
+import { applyModuleAuthTokens } from './module-auth-tokens.ts';
 
 @Injectable()
 export class EnvironmentsService {
@@ -53,19 +54,33 @@ export class EnvironmentsService {
       .set(data)
       .where(and(eq(environments.workspaceId, workspaceId), eq(environments.id, environmentId)))
       .returning();
+
+    if (await this.isActiveEnvironment(workspaceId, environmentId)) {
+      applyModuleAuthTokens(result);
+    }
+
     return result;
   }
 
   public async delete(workspaceId: string, environmentId: string): Promise<void> {
     const db = this.connection.getConnection();
+    const wasActive = await this.isActiveEnvironment(workspaceId, environmentId);
     await db
       .delete(environments)
       .where(and(eq(environments.workspaceId, workspaceId), eq(environments.id, environmentId)));
+
+    if (wasActive) {
+      applyModuleAuthTokens(null);
+    }
   }
 
   public async setActive(workspaceId: string, environmentId: string): Promise<void> {
     const db = this.connection.getConnection();
     await db.update(workspaces).set({ activeEnvironmentId: environmentId }).where(eq(workspaces.id, workspaceId));
+
+    if ((await this.workspacesService.getActiveWorkspaceId()) === workspaceId) {
+      applyModuleAuthTokens(await this.get(workspaceId, environmentId));
+    }
   }
 
   public async getActive(workspaceId: string): Promise<EnvironmentData | null> {
@@ -81,4 +96,14 @@ export class EnvironmentsService {
 
     return this.get(workspaceId, workspace.activeEnvironmentId);
   }
+
+  /**
+   * Whether the environment is the active one of the active workspace, whose
+   * secrets authenticate remote script imports.
+   */
+  private async isActiveEnvironment(workspaceId: string, environmentId: string): Promise<boolean> {
+    if ((await this.workspacesService.getActiveWorkspaceId()) !== workspaceId) return false;
+    const workspace = await this.workspacesService.findOneById(workspaceId);
+    return workspace?.activeEnvironmentId === environmentId;
+  }
 }
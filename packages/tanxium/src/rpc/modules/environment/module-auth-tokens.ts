@@ -0,0 +1,10 @@
+import type { EnvironmentData } from '@yasumu/common';
+
+/**
+ * Authenticates remote script imports with the `DENO_AUTH_TOKENS` secret of
+ * the given environment, or clears the credentials when it has none.
+ */
+export function applyModuleAuthTokens(environment: Pick<EnvironmentData, 'secrets'> | null | undefined) {
+  const secret = environment?.secrets.find((secret) => secret.enabled && secret.key === 'DENO_AUTH_TOKENS');
+  Yasumu.setModuleAuthTokens(secret?.value || null);
+}
@@ -1,10 +1,11 @@
 import { Injectable } from '@yasumu/den';
-import { eq } from 'drizzle-orm';
+import { and, eq } from 'drizzle-orm';
 
-import { workspaces } from '@/database/schema.ts';
+import { environments, workspaces } from '@/database/schema.ts';
 import { isDefaultWorkspacePath } from '@/rpc/common/constants.ts';
 
 import { TransactionalConnection } from '../common/transactional-connection.service.ts';
+import { applyModuleAuthTokens } from '../environment/module-auth-tokens.ts';
 
 @Injectable()
 export class WorkspaceActivatorService {
@@ -24,6 +25,14 @@ export class WorkspaceActivatorService {
 
     Yasumu.setWorkspaceDir(isDefaultWorkspacePath(workspace.path) ? null : workspace.path);
 
+    const [environment] = workspace.activeEnvironmentId
+      ? await db
+          .select()
+          .from(environments)
+          .where(and(eq(environments.workspaceId, id), eq(environments.id, workspace.activeEnvironmentId)))
+      : [];
+    applyModuleAuthTokens(environment);
+
     return workspace;
   }
 }
@@ -192,5 +192,6 @@ export class WorkspacesService implements OnModuleInit {
       });
 
     Yasumu.setWorkspaceDir(null);
+    Yasumu.setModuleAuthTokens(null);
   }
 }
@@ -8,6 +8,9 @@ export function getRuntimeWorkerSupportSource(): string {
   return /* javascript */ `
   let activeRuntimeState = null;
   const pendingRuntimeHostCalls = new Map();
+  // Taken before any hook runs, so hooks cannot remove the env provider.
+  const setEnvProvider = Yasumu[Symbol.for('yasumu.setEnvProvider')];
+  delete Yasumu[Symbol.for('yasumu.setEnvProvider')];
 
   class RuntimeEnvironment {
     constructor(snapshot) {
@@ -511,6 +514,11 @@ export function getRuntimeWorkerSupportSource(): string {
     state.workspace = createRuntimeWorkspace(message.invocation, environment);
     state.runtime = createRuntimeDescriptor(message.invocation, controller);
     activeRuntimeState = state;
+    if (message.workspaceEnv) {
+      setEnvProvider(createWorkspaceEnvProvider(environment, message.workspaceEnv), {
+        allowHostEnv: message.workspaceEnv.allowHostEnv,
+      });
+    }
 
     try {
       const result = await executeRuntimeHook(state, message.moduleKey);
@@ -528,9 +536,44 @@ export function getRuntimeWorkerSupportSource(): string {
     } finally {
       rejectRuntimeHostCalls(message.requestId, runtimeWorkerError('SCRIPT_EXECUTION_ENDED', 'Execution ended'));
       if (activeRuntimeState === state) activeRuntimeState = null;
+      if (message.workspaceEnv) setEnvProvider(null);
     }
   }
 
+  // Deno.env and process.env read the environment's variables and the
+  // secrets it opts into; writes update the environment like setVariable.
+  function createWorkspaceEnvProvider(environment, options) {
+    const exposesSecret = (key) =>
+      (options.secrets === true || (Array.isArray(options.secrets) && options.secrets.includes(key))) &&
+      environment.hasSecret(key);
+    const toEnvValue = (value) => typeof value === 'string' ? value : JSON.stringify(value);
+
+    return {
+      get(key) {
+        if (exposesSecret(key)) return environment.getSecret(key);
+        return environment.hasVariable(key) ? toEnvValue(environment.getVariable(key)) : undefined;
+      },
+      set(key, value) {
+        if (exposesSecret(key)) environment.setSecret(key, value);
+        else environment.setVariable(key, value);
+      },
+      delete(key) {
+        if (exposesSecret(key)) environment.deleteSecret(key);
+        else environment.deleteVariable(key);
+      },
+      has(key) {
+        return exposesSecret(key) || environment.hasVariable(key);
+      },
+      toObject() {
+        const entries = Object.entries(environment.variables).map(([key, value]) => [key, toEnvValue(value)]);
+        for (const key of Object.keys(environment.secrets)) {
+          if (exposesSecret(key)) entries.push([key, environment.secrets[key]]);
+        }
+        return Object.fromEntries(entries);
+      },
+    };
+  }
+
   function cancelRuntimeExecution(message) {
     if (activeRuntimeState?.requestId === message.requestId) {
       activeRuntimeState.cancelReason = message.reason ?? 'Cancelled by host';
@@ -22,6 +22,7 @@ import {
   WorkerInboundMessage,
   WorkerOutboundMessage,
   WorkerState,
+  WorkspaceEnvOptions,
 } from './types.ts';
 
 declare const Yasumu: {
@@ -29,6 +30,10 @@ declare const Yasumu: {
   unregisterVirtualModule(name: string): void;
 };
 
+export interface RuntimeHookOptions extends InvokeHookOptions {
+  workspaceEnv?: WorkspaceEnvOptions;
+}
+
 export interface ScriptWorkerOptions {
   source: string;
   onTerminate?: () => void;
@@ -332,7 +337,7 @@ export class ScriptWorker {
     moduleKey: string,
     invocation: ScriptHookInvocation,
     hostCall: RuntimeHostCallHandler,
-    options: InvokeHookOptions = {},
+    options: RuntimeHookOptions = {},
   ): Promise<ScriptHookResult> {
     if (options.signal?.aborted) throw cancellationError(options.signal.reason);
     await this.ensureWorker();
@@ -377,6 +382,7 @@ export class ScriptWorker {
         moduleKey,
         invocation,
         timeoutMs: timeout,
+        workspaceEnv: options.workspaceEnv,
       } satisfies WorkerInboundMessage);
     });
   }
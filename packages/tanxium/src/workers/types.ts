@@ -45,8 +45,26 @@ export interface WorkerPublishMessage<T = unknown> {
   data: T;
 }
 
+/**
+ * Backs `Deno.env` and `process.env` in hook workers with the active
+ * workspace environment instead of the host process environment.
+ */
+export interface WorkspaceEnvOptions {
+  /**
+   * Secrets exposed alongside the environment's variables: `true` for all of
+   * them, or a list of names. Defaults to none.
+   */
+  secrets?: boolean | string[];
+  /**
+   * Host process variables that stay visible: `true` for all of them, or a
+   * list of names such as `PATH`. Defaults to none.
+   */
+  allowHostEnv?: boolean | string[];
+}
+
 export interface TanxiumRuntimeInvokeMessage extends RuntimeInvokeMessage {
   moduleKey: string;
+  workspaceEnv?: WorkspaceEnvOptions;
 }
 
 export type TanxiumRuntimeInboundMessage =
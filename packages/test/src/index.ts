@@ -1,4 +1,13 @@
 import { expect as stdExpect } from '@std/expect';
+import type { Expected as StdExpected, ExtendMatchResult, MatcherContext } from '@std/expect';
+import { buildMessage } from '@std/internal/build-message';
+import { diff } from '@std/internal/diff';
+import { format } from '@std/internal/format';
+
+declare const Yasumu: {
+  readSnapshots(test: string): Record<string, string>;
+  writeSnapshots(test: string, snapshots: Record<string, string>): void;
+};
 
 export interface TestResult {
   suite?: string[];
@@ -21,6 +30,60 @@ interface TestStore {
 
 export interface TestRunResult {
   testResults: TestResult[];
+  /**
+   * Snapshot changes made by the run, when snapshots are enabled.
+   */
+  snapshots?: SnapshotSummary;
+}
+
+export interface SnapshotOptions {
+  /**
+   * The test module URL or path the snapshots belong to, or a name for tests
+   * without a file. Snapshots are stored in `__snapshots__/<file>.snap` next
+   * to the test file, or under the workspace's `yasumu/__snapshots__`.
+   */
+  file: string;
+  /**
+   * Overwrite mismatched snapshots and remove obsolete ones instead of failing.
+   */
+  update?: boolean;
+}
+
+export interface SnapshotSummary {
+  /**
+   * Number of snapshots written for the first time.
+   */
+  added: number;
+  /**
+   * Number of mismatched snapshots overwritten.
+   */
+  updated: number;
+  /**
+   * Names of stored snapshots that no test checked. They are removed when
+   * updating; runs with a filter do not report them.
+   */
+  obsolete: string[];
+}
+
+export interface RunTestOptions {
+  /**
+   * Only run tests whose full name, suites and test name joined with ` > `,
+   * contains this string or matches this pattern.
+   */
+  filter?: string | RegExp;
+  /**
+   * Called before each test runs. The test starts once a returned promise
+   * settles.
+   */
+  onTestStart?: (test: { suite: string[]; test: string }) => void | Promise<void>;
+  /**
+   * Called with the result of each test as soon as it finishes.
+   */
+  onTestFinish?: (result: TestResult) => void | Promise<void>;
+  /**
+   * Enable `toMatchSnapshot()`, storing snapshots for this file.
+   */
+  snapshot?: SnapshotOptions;
 }
 
 export interface TestContext {
@@ -38,8 +101,20 @@ export interface TestContext {
   succeed(): never;
 }
 
+interface SnapshotState {
+  options: SnapshotOptions;
+  stored: Record<string, string>;
+  checked: Set<string>;
+  counters: Map<string, number>;
+  added: number;
+  updated: number;
+  dirty: boolean;
+}
+
 let activeTestStore: TestStore | undefined;
 let activeTestSuite: string[] = [];
+let activeSnapshots: SnapshotState | undefined;
+let activeTestName: string | undefined;
 
 class SkipTestError extends Error {
   constructor() {
@@ -131,16 +206,41 @@ async function executeTest(entry: TestEntry): Promise<TestResult> {
   }
 }
 
-export async function runTest(fn: () => void | Promise<void>): Promise<TestRunResult> {
+function fullTestName(entry: TestEntry): string {
+  return [...entry.suite, entry.name].join(' > ');
+}
+
+function matchesFilter(entry: TestEntry, filter: string | RegExp | undefined): boolean {
+  if (filter === undefined) return true;
+  const fullName = fullTestName(entry);
+  return typeof filter === 'string' ? fullName.includes(filter) : filter.test(fullName);
+}
+
+export async function runTest(
+  fn: () => void | Promise<void>,
+  options: RunTestOptions = {},
+): Promise<TestRunResult> {
   const store: TestStore = {
     tests: [],
     pendingSuites: [],
   };
 
+  const snapshots: SnapshotState | undefined = options.snapshot && {
+    options: options.snapshot,
+    stored: Yasumu.readSnapshots(options.snapshot.file),
+    checked: new Set(),
+    counters: new Map(),
+    added: 0,
+    updated: 0,
+    dirty: false,
+  };
+
   const previousStore = activeTestStore;
   const previousSuite = activeTestSuite;
+  const previousSnapshots = activeSnapshots;
   activeTestStore = store;
   activeTestSuite = [];
+  activeSnapshots = snapshots;
 
   try {
     await fn();
@@ -148,15 +248,126 @@ export async function runTest(fn: () => void | Promise<void>): Promise<TestRunRe
 
     const testResults: TestResult[] = [];
     for (const entry of store.tests) {
-      testResults.push(await executeTest(entry));
+      if (!matchesFilter(entry, options.filter)) continue;
+      await options.onTestStart?.({ suite: entry.suite, test: entry.name });
+      activeTestName = fullTestName(entry);
+      const result = await executeTest(entry);
+      if (snapshots && result.result !== 'pass') keepSnapshots(snapshots, activeTestName);
+      activeTestName = undefined;
+      await options.onTestFinish?.(result);
+      testResults.push(result);
     }
-    return { testResults };
+    if (!snapshots) return { testResults };
+    return { testResults, snapshots: finishSnapshots(snapshots, options.filter !== undefined) };
   } finally {
     activeTestStore = previousStore;
     activeTestSuite = previousSuite;
+    activeSnapshots = previousSnapshots;
+    activeTestName = undefined;
+  }
+}
+
+function finishSnapshots(state: SnapshotState, filtered: boolean): SnapshotSummary {
+  // A filtered run only checks some snapshots, so the rest are not obsolete.
+  const obsolete = filtered ? [] : Object.keys(state.stored).filter((name) => !state.checked.has(name));
+  if (state.options.update && obsolete.length > 0) {
+    for (const name of obsolete) delete state.stored[name];
+    state.dirty = true;
+  }
+  if (state.dirty) {
+    Yasumu.writeSnapshots(state.options.file, state.stored);
+  }
+  return {
+    added: state.added,
+    updated: state.updated,
+    obsolete: state.options.update ? [] : obsolete,
+  };
+}
+
+/**
+ * Marks the snapshots of a failed or skipped test as checked, since the test
+ * may not have reached its assertions.
+ */
+function keepSnapshots(state: SnapshotState, test: string) {
+  const pattern = new RegExp(`^${test.replace(/[.*+?^${}()|[\]\\]/g, '\\$&')}(: .*)? \\d+$`, 's');
+  for (const name of Object.keys(state.stored)) {
+    if (pattern.test(name)) state.checked.add(name);
+  }
+}
+
+function serializeSnapshot(value: unknown): string {
+  return typeof value === 'string' ? `"${value}"` : format(value);
+}
+
+function snapshotMismatch(label: string, received: string, expected: string): ExtendMatchResult {
+  return {
+    pass: false,
+    message: () =>
+      `${label} does not match\n${buildMessage(diff(received.split('\n'), expected.split('\n'))).join('\n')}`,
+  };
+}
+
+function snapshotState(context: MatcherContext, matcher: string): SnapshotState {
+  if (context.isNot) {
+    throw new Error(`${matcher}() cannot be used with .not`);
+  }
+  if (!activeSnapshots || activeTestName === undefined) {
+    throw new Error(`${matcher}() can only be used in tests run with snapshots enabled`);
+  }
+  return activeSnapshots;
+}
+
+function toMatchSnapshot(context: MatcherContext, hint?: string): ExtendMatchResult {
+  const state = snapshotState(context, 'toMatchSnapshot');
+  const test = activeTestName!;
+  const count = (state.counters.get(test) ?? 0) + 1;
+  state.counters.set(test, count);
+
+  const name = `${test}${hint ? `: ${hint}` : ''} ${count}`;
+  const received = serializeSnapshot(context.value);
+  const expected = state.stored[name];
+  state.checked.add(name);
+
+  if (expected === received) {
+    return { pass: true, message: () => '' };
+  }
+  if (expected === undefined || state.options.update) {
+    if (expected === undefined) state.added++;
+    else state.updated++;
+    state.stored[name] = received;
+    state.dirty = true;
+    return { pass: true, message: () => '' };
+  }
+  return snapshotMismatch(`Snapshot "${name}"`, received, expected);
+}
+
+function toMatchInlineSnapshot(context: MatcherContext, snapshot?: string): ExtendMatchResult {
+  snapshotState(context, 'toMatchInlineSnapshot');
+  const received = serializeSnapshot(context.value);
+  if (snapshot === undefined) {
+    return {
+      pass: false,
+      message: () => `Missing inline snapshot. Pass the received value:\n\n${received}`,
+    };
   }
+
+  const expected = dedent(snapshot);
+  if (expected === received) {
+    return { pass: true, message: () => '' };
+  }
+  return snapshotMismatch('Inline snapshot', received, expected);
 }
 
+function dedent(text: string): string {
+  const lines = text.replace(/^\n/, '').replace(/\n[ \t]*$/, '').split('\n');
+  const indent = Math.min(
+    ...lines.filter((line) => line.trim() !== '').map((line) => line.length - line.trimStart().length),
+  );
+  return Number.isFinite(indent) ? lines.map((line) => line.slice(indent)).join('\n') : lines.join('\n');
+}
+
+stdExpect.extend({ toMatchSnapshot, toMatchInlineSnapshot });
+
 export type TestFunction = (ctx: TestContext) => void | Promise<void>;
 
 function proxyExpectStub(): ReturnType<typeof stdExpect> {
@@ -173,10 +384,25 @@ function proxyExpectStub(): ReturnType<typeof stdExpect> {
   return new Proxy(() => {}, handler) as ReturnType<typeof stdExpect>;
 }
 
+export interface Expected<IsAsync = false> extends StdExpected<IsAsync> {
+  /**
+   * Compare the value against the snapshot stored for this test, writing it
+   * on first use. `hint` distinguishes snapshots within a test.
+   */
+  toMatchSnapshot(hint?: string): void;
+  /**
+   * Compare the value against `snapshot`, ignoring its common indentation.
+   */
+  toMatchInlineSnapshot(snapshot?: string): void;
+}
+
+export type ExpectFunction = ((value: unknown, customMessage?: string) => Expected) &
+  Pick<typeof stdExpect, keyof typeof stdExpect>;
+
 export const expect = ((...args: Parameters<typeof stdExpect>) => {
   if (!activeTestStore) return proxyExpectStub();
-  return stdExpect(...args);
-}) as typeof stdExpect;
+  return stdExpect<Expected>(...args);
+}) as ExpectFunction;
 
 export function test(name: string, fn: TestFunction): void {
   const store = activeTestStore;
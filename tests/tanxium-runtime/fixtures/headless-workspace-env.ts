@@ -0,0 +1,60 @@
+import { snapshotRequest } from '../../../packages/runtime-api/src/serialization.ts';
+import type { ScriptHookInvocation } from '../../../packages/runtime-api/src/types.ts';
+import { TanxiumScriptRuntime } from '../../../packages/tanxium/src/headless/runtime/index.ts';
+
+const workspace = { id: 'workspace-env', name: 'Workspace', root: '.' };
+const code = `
+  import process from 'node:process';
+  export function onRequest() {
+    // a hook cannot remove the provider to reach the host environment
+    for (const key of [Symbol.for('yasumu.setEnvProvider'), 'setEnvProvider']) {
+      Yasumu[key]?.(null);
+    }
+    Deno.env.set('FROM_HOOK', 'written');
+    return Response.json({
+      apiUrl: process.env.API_URL,
+      port: Deno.env.get('PORT'),
+      token: Deno.env.get('API_TOKEN'),
+      hidden: Deno.env.get('PASSWORD'),
+      home: Deno.env.get('HOME'),
+      path: Deno.env.has('PATH'),
+      keys: Object.keys(Deno.env.toObject()).sort(),
+    });
+  }
+`;
+
+const invocation: ScriptHookInvocation = {
+  hook: 'onRequest',
+  source: { id: 'env-script', code },
+  workspace,
+  entity: { id: 'entity-1', name: 'Entity', kind: 'rest' },
+  execution: { id: 'execution-1', rootId: 'execution-1', depth: 0, mode: 'run', startedAt: Date.now() },
+  environment: {
+    variables: { API_URL: 'https://staging.example.test', PORT: 8080 },
+    secrets: { API_TOKEN: 'token-1', PASSWORD: 'hunter2' },
+  },
+  request: await snapshotRequest(new Request('https://example.test/')),
+  isMockResponse: false,
+};
+
+const runtime = new TanxiumScriptRuntime({
+  workspaceEnv: { secrets: ['API_TOKEN'], allowHostEnv: ['PATH'] },
+});
+const session = await runtime.createSession({
+  workspace,
+  hostCall: async (method) => {
+    throw new Error(`Unexpected host call: ${method}`);
+  },
+});
+try {
+  const result = await session.invokeHook(invocation);
+  const body = result.mockResponse?.body;
+  console.log(
+    `WORKSPACE_ENV:${JSON.stringify({
+      seen: body?.kind === 'json' ? body.value : body?.kind === 'text' ? JSON.parse(body.text) : null,
+      variables: result.environment.variables,
+    })}`,
+  );
+} finally {
+  await session.dispose();
+}
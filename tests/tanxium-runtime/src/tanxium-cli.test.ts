@@ -1,10 +1,12 @@
 import { execFileSync, spawn, spawnSync } from 'node:child_process';
+import { createHash } from 'node:crypto';
 import { once } from 'node:events';
-import { mkdirSync, mkdtempSync, rmSync, writeFileSync } from 'node:fs';
+import { existsSync, mkdirSync, mkdtempSync, readFileSync, rmSync, writeFileSync } from 'node:fs';
 import { createServer } from 'node:http';
 import { createConnection } from 'node:net';
 import { tmpdir } from 'node:os';
 import { join, resolve } from 'node:path';
+import { gzipSync } from 'node:zlib';
 
 import { afterAll, beforeAll, describe, expect, it } from 'vitest';
 
@@ -159,6 +161,32 @@ async function sendSmtpMessage(): Promise<string> {
   });
 }
 
+/** Builds a gzipped npm-style tarball with every file under `package/`. */
+function createPackageTarball(files: Record<string, string>): Buffer {
+  const blocks: Buffer[] = [];
+
+  for (const [name, content] of Object.entries(files)) {
+    const body = Buffer.from(content);
+    const header = Buffer.alloc(512);
+    header.write(`package/${name}`, 0, 100);
+    header.write('0000644\0', 100);
+    header.write('0000000\0', 108);
+    header.write('0000000\0', 116);
+    header.write(`${body.length.toString(8).padStart(11, '0')}\0`, 124);
+    header.write('00000000000\0', 136);
+    header.write('        ', 148);
+    header.write('0', 156);
+    header.write('ustar\0', 257);
+    header.write('00', 263);
+    const checksum = header.reduce((sum, byte) => sum + byte, 0);
+    header.write(`${checksum.toString(8).padStart(6, '0')}\0 `, 148);
+
+    blocks.push(header, body, Buffer.alloc((512 - (body.length % 512)) % 512));
+  }
+
+  return gzipSync(Buffer.concat([...blocks, Buffer.alloc(1024)]));
+}
+
 beforeAll(() => {
   execFileSync('cargo', ['build', '-p', 'tanxium-cli'], {
     cwd: root,
@@ -296,6 +324,196 @@ describe('tanxium CLI runtime semantics', () => {
     }
   }, 60_000);
 
+  it('registers redirected remote modules under their final URL', async () => {
+    const server = createServer((request, response) => {
+      if (request.url === '/latest/mod.js') {
+        response.writeHead(302, { Location: '/v2/mod.js' }).end();
+        return;
+      }
+
+      const content =
+        request.url === '/v2/mod.js'
+          ? `export { version } from './version.js';\nexport const url = import.meta.url;\n`
+          : request.url === '/v2/version.js'
+            ? `export const version = 2;\n`
+            : undefined;
+
+      if (content === undefined) {
+        response.writeHead(404).end();
+        return;
+      }
+
+      response.writeHead(200, { 'Content-Type': 'application/javascript' });
+      response.end(content);
+    });
+    server.listen(0, '127.0.0.1');
+    await once(server, 'listening');
+    const address = server.address();
+    if (address === null || typeof address === 'string') {
+      throw new Error('Unable to determine the HTTP fixture server address');
+    }
+
+    try {
+      const entrypoint = join(workspace, 'redirect-entry.ts');
+      writeFileSync(
+        entrypoint,
+        /* js */ `
+        const latest = await import('http://127.0.0.1:${address.port}/latest/mod.js');
+        const pinned = await import('http://127.0.0.1:${address.port}/v2/mod.js');
+        console.log(JSON.stringify({ version: latest.version, url: latest.url, same: latest === pinned }));
+      `,
+      );
+      const output = await new Promise<string>((resolve, reject) => {
+        const child = spawn(
+          binary,
+          ['--allow-http-imports', 'run', entrypoint, '--workspace', workspace, '--resources', workspace],
+          { cwd: workspace, stdio: ['ignore', 'pipe', 'pipe'] },
+        );
+        let stdout = '';
+        child.stdout.on('data', (chunk: Buffer) => {
+          stdout += chunk.toString();
+        });
+        child.once('error', reject);
+        child.once('close', () => resolve(stdout));
+      });
+
+      expect(JSON.parse(output.trim())).toEqual({
+        version: 2,
+        url: `http://127.0.0.1:${address.port}/v2/mod.js`,
+        same: true,
+      });
+    } finally {
+      await new Promise<void>((resolve, reject) => {
+        server.close((error) => (error ? reject(error) : resolve()));
+      });
+    }
+  }, 60_000);
+
+  it('authenticates private remote modules with auth tokens', async () => {
+    const server = createServer((request, response) => {
+      const { pathname } = new URL(request.url ?? '/', 'http://127.0.0.1');
+      const authorized =
+        (pathname === '/private/env.js' && request.headers.authorization === 'Bearer env-token') ||
+        (pathname === '/private/runtime.js' && request.headers.authorization === 'Bearer runtime-token');
+      if (!authorized) {
+        response.writeHead(401).end();
+        return;
+      }
+
+      response.writeHead(200, { 'Content-Type': 'application/javascript' });
+      response.end(`export const source = ${JSON.stringify(pathname)};\n`);
+    });
+    server.listen(0, '127.0.0.1');
+    await once(server, 'listening');
+    const address = server.address();
+    if (address === null || typeof address === 'string') {
+      throw new Error('Unable to determine the HTTP fixture server address');
+    }
+
+    try {
+      const origin = `http://127.0.0.1:${address.port}`;
+      const entrypoint = join(workspace, 'private-module-entry.ts');
+      writeFileSync(
+        entrypoint,
+        /* ts */ `
+        const fromEnv = await import('${origin}/private/env.js');
+        // a distinct URL, so the authorized import below is not a retry
+        const denied = await import('${origin}/private/runtime.js?attempt=1').then(
+          () => 'loaded',
+          () => 'denied',
+        );
+        Yasumu.setModuleAuthTokens('runtime-token@127.0.0.1:${address.port}');
+        const fromRuntime = await import('${origin}/private/runtime.js');
+        console.log(\`PRIVATE:\${fromEnv.source}:\${denied}:\${fromRuntime.source}\`);
+      `,
+      );
+
+      const output = await new Promise<string>((resolve, reject) => {
+        const child = spawn(
+          binary,
+          ['--allow-http-imports', 'run', entrypoint, '--workspace', workspace, '--resources', workspace],
+          {
+            cwd: workspace,
+            env: { ...process.env, TANXIUM_AUTH_TOKENS: `env-token@127.0.0.1:${address.port}` },
+            stdio: ['ignore', 'pipe', 'pipe'],
+          },
+        );
+        let output = '';
+        child.stdout.on('data', (chunk: Buffer) => {
+          output += chunk.toString();
+        });
+        child.stderr.on('data', (chunk: Buffer) => {
+          output += chunk.toString();
+        });
+        child.once('error', reject);
+        child.once('close', () => resolve(output));
+      });
+
+      expect(output).toContain('PRIVATE:/private/env.js:denied:/private/runtime.js');
+      expect(output).not.toContain('env-token');
+    } finally {
+      await new Promise<void>((resolve, reject) => {
+        server.close((error) => (error ? reject(error) : resolve()));
+      });
+    }
+  }, 60_000);
+
+  it('keeps timers running while a remote import stalls', async () => {
+    // accepts requests and never answers them
+    const server = createServer(() => {});
+    server.listen(0, '127.0.0.1');
+    await once(server, 'listening');
+    const address = server.address();
+    if (address === null || typeof address === 'string') {
+      throw new Error('Unable to determine the HTTP fixture server address');
+    }
+
+    try {
+      const entrypoint = join(workspace, 'stalled-import-entry.ts');
+      writeFileSync(
+        entrypoint,
+        /* ts */ `
+        const started = Date.now();
+        import('http://127.0.0.1:${address.port}/slow.ts').then(
+          () => console.log('IMPORTED'),
+          () => console.log('IMPORT FAILED'),
+        );
+        let ticks = 0;
+        const interval = setInterval(() => {
+          ticks += 1;
+          if (ticks === 5) {
+            clearInterval(interval);
+            console.log(\`TICKS:\${ticks}:\${Date.now() - started < 5_000}\`);
+            Deno.exit(0);
+          }
+        }, 50);
+      `,
+      );
+
+      const output = await new Promise<string>((resolve, reject) => {
+        const child = spawn(
+          binary,
+          ['--allow-http-imports', 'run', entrypoint, '--workspace', workspace, '--resources', workspace],
+          { cwd: workspace, stdio: ['ignore', 'pipe', 'pipe'] },
+        );
+        let stdout = '';
+        child.stdout.on('data', (chunk: Buffer) => {
+          stdout += chunk.toString();
+        });
+        child.once('error', reject);
+        child.once('close', () => resolve(stdout));
+      });
+
+      expect(output).toContain('TICKS:5:true');
+      expect(output).not.toContain('IMPORTED');
+    } finally {
+      server.closeAllConnections();
+      await new Promise<void>((resolve, reject) => {
+        server.close((error) => (error ? reject(error) : resolve()));
+      });
+    }
+  }, 60_000);
+
   it('loads registered virtual modules through the Yasumu module loader', () => {
     const output = run(/* js */ `
       Yasumu.registerVirtualModule('answer', 'export const answer: number = 42');
@@ -395,6 +613,952 @@ describe('tanxium CLI runtime semantics', () => {
     expect(output).toContain('resolved from node_modules');
   });
 
+  it('imports local and remote WebAssembly modules', async () => {
+    // (module (func (export "add") (param i32 i32) (result i32) local.get 0 local.get 1 i32.add))
+    const wasm = Buffer.from([
+      0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x07, 0x01, 0x60, 0x02, 0x7f, 0x7f, 0x01, 0x7f, 0x03,
+      0x02, 0x01, 0x00, 0x07, 0x07, 0x01, 0x03, 0x61, 0x64, 0x64, 0x00, 0x00, 0x0a, 0x09, 0x01, 0x07, 0x00, 0x20,
+      0x00, 0x20, 0x01, 0x6a, 0x0b,
+    ]);
+    writeFileSync(join(workspace, 'math.wasm'), wasm);
+    const server = createServer((request, response) => {
+      if (request.url !== '/math.wasm') {
+        response.writeHead(404).end();
+        return;
+      }
+
+      response.writeHead(200, { 'Content-Type': 'application/wasm' });
+      response.end(wasm);
+    });
+    server.listen(0, '127.0.0.1');
+    await once(server, 'listening');
+    const address = server.address();
+    if (address === null || typeof address === 'string') {
+      throw new Error('Unable to determine the HTTP fixture server address');
+    }
+
+    try {
+      const entrypoint = join(workspace, 'wasm-entry.ts');
+      writeFileSync(
+        entrypoint,
+        /* ts */ `
+        import { add } from './math.wasm';
+        const remote = await import('http://127.0.0.1:${address.port}/math.wasm');
+        console.log(\`WASM:\${add(2, 3)}:\${remote.add(40, 2)}\`);
+      `,
+      );
+
+      const output = await new Promise<string>((resolve, reject) => {
+        const child = spawn(
+          binary,
+          ['--allow-http-imports', 'run', entrypoint, '--workspace', workspace, '--resources', workspace],
+          { cwd: workspace, stdio: ['ignore', 'pipe', 'pipe'] },
+        );
+        let output = '';
+        child.stdout.on('data', (chunk: Buffer) => {
+          output += chunk.toString();
+        });
+        child.stderr.on('data', (chunk: Buffer) => {
+          output += chunk.toString();
+        });
+        child.once('error', reject);
+        child.once('close', () => resolve(output));
+      });
+
+      expect(output).toContain('WASM:5:42');
+    } finally {
+      await new Promise<void>((resolve, reject) => {
+        server.close((error) => (error ? reject(error) : resolve()));
+      });
+    }
+  }, 60_000);
+
+  it('imports files and data URLs as text or bytes', () => {
+    mkdirSync(join(workspace, 'fixtures'), { recursive: true });
+    writeFileSync(join(workspace, 'fixtures', 'query.graphql'), 'query { viewer { id } }\n');
+    writeFileSync(join(workspace, 'fixtures', 'body.bin'), Buffer.from([0x00, 0xff, 0x10]));
+
+    const output = run(/* ts */ `
+      import query from './fixtures/query.graphql' with { type: 'text' };
+      import body from './fixtures/body.bin' with { type: 'bytes' };
+      import inline from 'data:text/plain,hello%20world' with { type: 'text' };
+      console.log(\`RAW:\${query.trim()}:\${body instanceof Uint8Array}:\${Array.from(body).join(',')}:\${inline}\`);
+    `);
+
+    expect(output).toContain('RAW:query { viewer { id } }:true:0,255,16:hello world');
+  });
+
+  it('imports YAML, TOML, and JSONC files as data modules', () => {
+    mkdirSync(join(workspace, 'fixtures'), { recursive: true });
+    writeFileSync(join(workspace, 'fixtures', 'users.yaml'), 'users:\n  - name: ada\n    admin: true\n');
+    writeFileSync(join(workspace, 'fixtures', 'server.toml'), '[server]\nport = 8080\n');
+    writeFileSync(join(workspace, 'fixtures', 'client.jsonc'), '{\n  // retries per request\n  "retries": 3,\n}\n');
+    writeFileSync(join(workspace, 'fixtures', 'broken.yml'), 'name: api\nports: [80, 443\n');
+
+    const output = run(/* ts */ `
+      import data from './fixtures/users.yaml';
+      import config from './fixtures/server.toml' with { type: 'json' };
+      import client from './fixtures/client.jsonc';
+      console.log(\`DATA:\${data.users[0].name}:\${data.users[0].admin}:\${config.server.port}:\${client.retries}\`);
+    `);
+    expect(output).toContain('DATA:ada:true:8080:3');
+
+    const failure = runFailure(`import './fixtures/broken.yml';`, 'broken-data.ts');
+    expect(failure).toMatch(/Invalid YAML in .*broken\.yml at line \d+, column \d+/);
+  });
+
+  it('compiles an entrypoint into a standalone executable', () => {
+    const project = mkdtempSync(join(tmpdir(), 'tanxium-compile-'));
+    const output = join(workspace, process.platform === 'win32' ? 'compiled-app.exe' : 'compiled-app');
+    try {
+      const packageDir = join(project, 'node_modules', 'tanxium-compiled-dep');
+      mkdirSync(packageDir, { recursive: true });
+      writeFileSync(
+        join(packageDir, 'package.json'),
+        JSON.stringify({ name: 'tanxium-compiled-dep', type: 'module', exports: './index.js' }),
+      );
+      writeFileSync(join(packageDir, 'index.js'), `export const greet = (name) => \`hello \${name}\`;`);
+      writeFileSync(join(project, 'config.yaml'), 'name: standalone\n');
+      writeFileSync(join(project, 'lib.ts'), `export const answer: number = 42;`);
+      writeFileSync(
+        join(project, 'main.ts'),
+        `import { greet } from 'tanxium-compiled-dep';
+        import config from './config.yaml';
+        import { answer } from './lib.ts';
+        console.log(\`COMPILED:\${greet(config.name)}:\${answer}\`);`,
+      );
+
+      execFileSync(binary, ['compile', join(project, 'main.ts'), '-o', output, '--workspace', project], {
+        cwd: project,
+        encoding: 'utf8',
+        timeout: 60_000,
+      });
+      rmSync(project, { recursive: true, force: true });
+
+      const stdout = execFileSync(output, [], { cwd: workspace, encoding: 'utf8', timeout: 10_000 });
+
+      expect(stdout).toContain('COMPILED:hello standalone:42');
+    } finally {
+      rmSync(project, { recursive: true, force: true });
+      rmSync(output, { force: true });
+    }
+  });
+
+  it('refuses to compile CommonJS modules but still bundles them', () => {
+    const project = mkdtempSync(join(tmpdir(), 'tanxium-compile-cjs-'));
+    const output = join(project, process.platform === 'win32' ? 'compiled-cjs.exe' : 'compiled-cjs');
+    try {
+      writeFileSync(join(project, 'helper.cjs'), `module.exports = { greet: (name) => 'hello ' + name };`);
+      writeFileSync(join(project, 'main.ts'), `import { greet } from './helper.cjs';\nconsole.log(greet('cjs'));`);
+
+      const result = spawnSync(binary, ['compile', join(project, 'main.ts'), '-o', output, '--workspace', project], {
+        cwd: project,
+        encoding: 'utf8',
+        timeout: 60_000,
+      });
+
+      expect(result.status).not.toBe(0);
+      expect(`${result.stdout}${result.stderr}`).toMatch(/Unable to embed \S*helper\.cjs: CommonJS modules cannot be embedded/);
+      expect(existsSync(output)).toBe(false);
+
+      const bundled = join(project, 'bundled-cjs.js');
+      execFileSync(binary, ['bundle', join(project, 'main.ts'), '-o', bundled, '--workspace', project], {
+        cwd: project,
+        encoding: 'utf8',
+        timeout: 60_000,
+      });
+      const stdout = execFileSync(binary, ['run', bundled, '--workspace', project, '--resources', project], {
+        cwd: project,
+        encoding: 'utf8',
+        timeout: 10_000,
+      });
+      expect(stdout).toContain('hello cjs');
+    } finally {
+      rmSync(project, { recursive: true, force: true });
+    }
+  });
+
+  it('runs discovered test files and reports failures', () => {
+    const project = join(workspace, 'test-runner');
+    mkdirSync(join(project, 'nested'), { recursive: true });
+    mkdirSync(join(project, 'node_modules', 'ignored'), { recursive: true });
+    writeFileSync(
+      join(project, 'math.test.ts'),
+      `import { describe, expect, test } from 'yasumu:test';
+      describe('math', () => {
+        test('adds', () => expect(1 + 2).toBe(3));
+        test('skips', (ctx) => ctx.skip());
+      });`,
+    );
+    writeFileSync(
+      join(project, 'nested', 'strings_test.ts'),
+      `import { expect, test } from 'yasumu:test';
+      test('fails', () => expect('a').toBe('b'));`,
+    );
+    writeFileSync(join(project, 'node_modules', 'ignored', 'dep.test.ts'), `throw new Error('discovered');`);
+    writeFileSync(join(project, 'helper.ts'), `throw new Error('discovered');`);
+
+    const run = (...args: string[]) =>
+      spawnSync(binary, ['test', project, '--workspace', workspace, ...args], {
+        cwd: workspace,
+        encoding: 'utf8',
+        timeout: 60_000,
+      });
+
+    const failing = run('--reporter', 'json');
+    expect(failing.status).toBe(1);
+    const report = JSON.parse(failing.stdout);
+    expect(report.files).toHaveLength(2);
+    const results = report.files.flatMap((file: { results: unknown[] }) => file.results);
+    expect(results).toEqual([
+      expect.objectContaining({ suite: ['math'], test: 'adds', result: 'pass' }),
+      expect.objectContaining({ suite: ['math'], test: 'skips', result: 'skip' }),
+      expect.objectContaining({ test: 'fails', result: 'fail', error: expect.stringContaining('b') }),
+    ]);
+
+    const filtered = run('--filter', 'math > adds', '--reporter', 'tap');
+    expect(filtered.status).toBe(0);
+    expect(filtered.stdout).toContain('ok 1 - math > adds');
+    expect(filtered.stdout).toContain('1..1');
+
+    writeFileSync(
+      join(project, 'nested', 'colors_test.ts'),
+      `import { expect, test } from 'yasumu:test';
+      test('diffs', () => expect({ user: 'ada' }).toEqual({ user: 'grace' }));
+      test('colors', () => {
+        throw new Error('\\x1b[31mred\\x1b[39m bell\\x07');
+      });`,
+    );
+    const junit = run('--reporter', 'junit');
+    expect(junit.stdout).toContain('<testcase name="fails"');
+    expect(junit.stdout).toContain('<failure message=');
+    expect(junit.stdout).toContain('<testcase name="diffs"');
+    expect(junit.stdout).toContain('red bell');
+    // XML 1.0 cannot contain control characters other than whitespace.
+    expect(junit.stdout).not.toMatch(/[\x00-\x08\x0b\x0c\x0e-\x1f]/);
+    const tap = run('--reporter', 'tap');
+    expect(tap.stdout).not.toContain('\x1b');
+  });
+
+  it('runs test files in parallel shards and terminates tests that time out', () => {
+    const project = join(workspace, 'test-timeouts');
+    mkdirSync(project, { recursive: true });
+    writeFileSync(
+      join(project, 'a.test.ts'),
+      `import { test } from 'yasumu:test';
+      test('quick', () => {});
+      test('spins', () => { while (true) {} });
+      test('never runs', () => {});`,
+    );
+    writeFileSync(join(project, 'b.test.ts'), `import { test } from 'yasumu:test';\ntest('b', () => {});`);
+    writeFileSync(join(project, 'c.test.ts'), `import { test } from 'yasumu:test';\ntest('c', () => {});`);
+
+    const run = (...args: string[]) =>
+      spawnSync(binary, ['test', project, '--workspace', workspace, '--reporter', 'json', ...args], {
+        cwd: workspace,
+        encoding: 'utf8',
+        timeout: 60_000,
+      });
+
+    const timedOut = run('--jobs', '3', '--timeout', '500');
+    expect(timedOut.status).toBe(1);
+    const report = JSON.parse(timedOut.stdout);
+    expect(report.files.map((file: { path: string }) => file.path)).toEqual(
+      ['a.test.ts', 'b.test.ts', 'c.test.ts'].map((name) => join(project, name)),
+    );
+    const [spinning] = report.files;
+    expect(spinning.results.map((result: { test: string; result: string }) => [result.test, result.result])).toEqual([
+      ['quick', 'pass'],
+      ['spins', 'fail'],
+    ]);
+    expect(spinning.error).toContain('while running "spins"');
+
+    const shard = JSON.parse(run('--shard', '2/2').stdout);
+    expect(shard.files.map((file: { path: string }) => file.path)).toEqual([join(project, 'b.test.ts')]);
+  });
+
+  it('stores snapshots next to test files and updates them on request', () => {
+    const project = join(workspace, 'test-snapshots');
+    mkdirSync(project, { recursive: true });
+    const testFile = join(project, 'users.test.ts');
+    const writeTest = (name: string) =>
+      writeFileSync(
+        testFile,
+        `import { expect, test } from 'yasumu:test';
+        test('lists users', () => {
+          expect({ users: ['${name}'] }).toMatchSnapshot();
+          expect('${name}').toMatchInlineSnapshot(\`"${name}"\`);
+        });`,
+      );
+    const run = (...args: string[]) =>
+      spawnSync(binary, ['test', project, '--workspace', workspace, '--reporter', 'json', ...args], {
+        cwd: workspace,
+        encoding: 'utf8',
+        timeout: 60_000,
+      });
+    const snapshotFile = join(project, '__snapshots__', 'users.test.ts.snap');
+
+    writeTest('ada');
+    const first = run();
+    expect(first.status).toBe(0);
+    expect(JSON.parse(first.stdout).files[0].snapshots).toEqual({ added: 1, updated: 0, obsolete: [] });
+    expect(readFileSync(snapshotFile, 'utf8')).toContain('exports[`lists users 1`]');
+
+    writeTest('grace');
+    const mismatch = run();
+    expect(mismatch.status).toBe(1);
+    const [result] = JSON.parse(mismatch.stdout).files[0].results;
+    expect(result.error).toContain('Snapshot "lists users 1" does not match');
+    expect(result.error).toContain('ada');
+
+    const updated = run('--update-snapshots');
+    expect(updated.status).toBe(0);
+    expect(JSON.parse(updated.stdout).files[0].snapshots.updated).toBe(1);
+    expect(readFileSync(snapshotFile, 'utf8')).toContain('"grace"');
+
+    const script = runFailure(
+      `Yasumu.writeSnapshots(${JSON.stringify(testFile)}, { 'lists users 1': 'tampered' });`,
+      'snapshot-writer.ts',
+    );
+    expect(script).toContain('only available to test files run by `tanxium test`');
+    expect(readFileSync(snapshotFile, 'utf8')).toContain('"grace"');
+  });
+
+  it('measures benchmarks and reports their timings as JSON', () => {
+    const project = join(workspace, 'benches');
+    mkdirSync(project, { recursive: true });
+    writeFileSync(
+      join(project, 'payload.bench.ts'),
+      `import { bench } from 'yasumu:bench';
+      const options = { warmup: 10, time: 50 };
+      bench('build payload', () => JSON.stringify({ id: 1, tags: ['a', 'b'] }), options);
+      bench('async sign', async () => crypto.subtle.digest('SHA-256', new Uint8Array(8)), options);
+      bench('broken', () => { throw new Error('boom'); }, options);
+      bench('skipped', () => {}, { skip: true });`,
+    );
+
+    const run = (...args: string[]) =>
+      spawnSync(binary, ['bench', project, '--workspace', workspace, '--json', ...args], {
+        cwd: workspace,
+        encoding: 'utf8',
+        timeout: 60_000,
+      });
+
+    const failed = run();
+    expect(failed.status).toBe(1);
+    const [file] = JSON.parse(failed.stdout).files;
+    const results = Object.fromEntries(file.results.map((result: { name: string }) => [result.name, result]));
+    for (const name of ['build payload', 'async sign']) {
+      expect(results[name].result).toBe('pass');
+      expect(results[name].samples).toBeGreaterThanOrEqual(10);
+      expect(results[name].p50).toBeGreaterThan(0);
+      expect(results[name].p99).toBeGreaterThanOrEqual(results[name].p50);
+      expect(results[name].opsPerSecond).toBeGreaterThan(0);
+    }
+    expect(results.broken.error).toContain('boom');
+    expect(results.skipped.result).toBe('skip');
+
+    const filtered = run('--filter', 'payload');
+    expect(filtered.status).toBe(0);
+    expect(JSON.parse(filtered.stdout).files[0].results.map((result: { name: string }) => result.name)).toEqual([
+      'build payload',
+    ]);
+  });
+
+  it('passes arguments and env files to scripts', () => {
+    const entrypoint = join(workspace, 'args-env.ts');
+    const envFile = join(workspace, 'args-env.env');
+    writeFileSync(
+      entrypoint,
+      `import process from 'node:process';
+      Deno.env.set('TANXIUM_ARGS_SET', 'inside');
+      console.log(JSON.stringify({
+        args: Deno.args,
+        argv: process.argv.slice(2),
+        greeting: Deno.env.get('TANXIUM_ARGS_GREETING'),
+        processGreeting: process.env.TANXIUM_ARGS_GREETING,
+        path: Deno.env.has('PATH'),
+        keys: Object.keys(Deno.env.toObject()).sort(),
+      }));`,
+    );
+    writeFileSync(envFile, '# greeting\nexport TANXIUM_ARGS_GREETING="hello\\nworld"\n');
+
+    const run = (...flags: string[]) =>
+      JSON.parse(
+        execFileSync(
+          binary,
+          ['--no-sandbox', ...flags, 'run', entrypoint, '--workspace', workspace, '--', '-x', 'a b'],
+          { cwd: workspace, encoding: 'utf8', timeout: 10_000 },
+        ),
+      );
+
+    const inherited = run('--env-file', envFile);
+    expect(inherited.args).toEqual(['-x', 'a b']);
+    expect(inherited.argv).toEqual(['-x', 'a b']);
+    expect(inherited.greeting).toBe('hello\nworld');
+    expect(inherited.processGreeting).toBe('hello\nworld');
+    expect(inherited.path).toBe(true);
+
+    const cleared = run('--env-file', envFile, '--clear-env');
+    expect(cleared.path).toBe(false);
+    expect(cleared.keys).toEqual(['TANXIUM_ARGS_GREETING', 'TANXIUM_ARGS_SET']);
+  });
+
+  it('exits with the script exit code without restarting failed scripts', () => {
+    const run = (filename: string, source: string) => {
+      const entrypoint = join(workspace, filename);
+      writeFileSync(entrypoint, source);
+      return spawnSync(binary, ['run', entrypoint, '--workspace', workspace], {
+        cwd: workspace,
+        encoding: 'utf8',
+        timeout: 10_000,
+      });
+    };
+
+    const exited = run('exit-code.ts', "console.log('before exit');\nDeno.exit(3);\nconsole.log('after exit');");
+    expect(exited.status).toBe(3);
+    expect(exited.stdout).toContain('before exit');
+    expect(exited.stdout).not.toContain('after exit');
+
+    const exitCode = run('exit-code-property.ts', 'Deno.exitCode = 4;');
+    expect(exitCode.status).toBe(4);
+
+    const thrown = run('exit-thrown.ts', "console.log('started');\nthrow new Error('exit-code failure');");
+    expect(thrown.status).toBe(1);
+    expect(thrown.stdout.match(/started/g)).toHaveLength(1);
+    expect(thrown.stderr).toContain('exit-code failure');
+    expect(thrown.stderr).toContain('exit-thrown.ts');
+
+    expect(run('exit-ok.ts', "console.log('ok');").status).toBe(0);
+  });
+
+  it('prints uncaught errors with the original source line and their causes', () => {
+    const entrypoint = join(workspace, 'pretty-error.ts');
+    writeFileSync(
+      entrypoint,
+      [
+        'interface Request {',
+        '  url: string;',
+        '}',
+        '',
+        'function send(request: Request): never {',
+        "  throw new Error(`request to ${request.url} failed`, { cause: new TypeError('socket closed') });",
+        '}',
+        '',
+        "await Promise.resolve().then(() => send({ url: 'https://example.com' }));",
+      ].join('\n'),
+    );
+
+    const result = spawnSync(binary, ['run', entrypoint, '--workspace', workspace], {
+      cwd: workspace,
+      encoding: 'utf8',
+      timeout: 10_000,
+    });
+
+    expect(result.status).toBe(1);
+    expect(result.stderr).toContain('request to https://example.com failed');
+    expect(result.stderr).toContain(
+      "    throw new Error(`request to ${request.url} failed`, { cause: new TypeError('socket closed') });\n          ^",
+    );
+    expect(result.stderr).toContain('pretty-error.ts:6:9');
+    expect(result.stderr).toContain('Caused by: TypeError: socket closed');
+    expect(result.stderr).not.toMatch(/at .*ext:/);
+  });
+
+  it('bundles an entrypoint into a single module with a source map', () => {
+    const project = mkdtempSync(join(tmpdir(), 'tanxium-bundle-'));
+    const output = join(workspace, 'bundled-app.js');
+    try {
+      const packageDir = join(project, 'node_modules', 'tanxium-bundled-dep');
+      mkdirSync(packageDir, { recursive: true });
+      writeFileSync(
+        join(packageDir, 'package.json'),
+        JSON.stringify({ name: 'tanxium-bundled-dep', type: 'module', exports: './index.js' }),
+      );
+      writeFileSync(join(packageDir, 'index.js'), `export default (name) => \`hello \${name}\`;`);
+      writeFileSync(join(project, 'config.json'), JSON.stringify({ name: 'bundle' }));
+      writeFileSync(join(project, 'lib.ts'), `export const answer: number = 42;\nexport * from './extra.ts';`);
+      writeFileSync(join(project, 'extra.ts'), `export function extra(): string { return 'extra'; }`);
+      writeFileSync(
+        join(project, 'main.ts'),
+        `import greet from 'tanxium-bundled-dep';
+        import config from './config.json' with { type: 'json' };
+        import * as lib from './lib.ts';
+        import { basename } from 'node:path';
+        console.log(\`BUNDLED:\${greet(config.name)}:\${lib.answer}:\${lib.extra()}:\${basename('/a/b.txt')}\`);`,
+      );
+
+      execFileSync(binary, ['bundle', join(project, 'main.ts'), '-o', output, '--workspace', project], {
+        cwd: project,
+        encoding: 'utf8',
+        timeout: 60_000,
+      });
+      rmSync(project, { recursive: true, force: true });
+
+      const code = readFileSync(output, 'utf8');
+      expect(code).toContain('from "node:path"');
+      expect(code).toContain('//# sourceMappingURL=bundled-app.js.map');
+      const map = JSON.parse(readFileSync(`${output}.map`, 'utf8'));
+      expect(map.sections.length).toBeGreaterThan(0);
+
+      const stdout = execFileSync(binary, ['run', output, '--workspace', workspace, '--resources', workspace], {
+        cwd: workspace,
+        encoding: 'utf8',
+        timeout: 10_000,
+      });
+
+      expect(stdout).toContain('BUNDLED:hello bundle:42:extra:b.txt');
+    } finally {
+      rmSync(project, { recursive: true, force: true });
+      rmSync(output, { force: true });
+      rmSync(`${output}.map`, { force: true });
+    }
+  });
+
+  it('keeps imported bindings live in bundles, including through import cycles', () => {
+    const project = mkdtempSync(join(tmpdir(), 'tanxium-bundle-live-'));
+    const output = join(workspace, 'bundled-live.js');
+    try {
+      writeFileSync(join(project, 'counter.ts'), `export let count = 0;\nexport function increment() { count++; }`);
+      writeFileSync(
+        join(project, 'a.ts'),
+        `import { suffix } from './b.ts';\nexport const prefix = 'a';\nexport const joined = () => suffix();`,
+      );
+      writeFileSync(join(project, 'b.ts'), `import { prefix } from './a.ts';\nexport const suffix = () => \`\${prefix}b\`;`);
+      writeFileSync(
+        join(project, 'main.ts'),
+        `import { count, increment } from './counter.ts';
+        import { joined } from './a.ts';
+        const before = count;
+        increment();
+        console.log(\`LIVE:\${before}:\${count}:\${joined()}\`);`,
+      );
+
+      execFileSync(binary, ['bundle', join(project, 'main.ts'), '-o', output, '--workspace', project], {
+        cwd: project,
+        encoding: 'utf8',
+        timeout: 60_000,
+      });
+      const stdout = execFileSync(binary, ['run', output, '--workspace', workspace, '--resources', workspace], {
+        cwd: workspace,
+        encoding: 'utf8',
+        timeout: 10_000,
+      });
+
+      expect(stdout).toContain('LIVE:0:1:ab');
+    } finally {
+      rmSync(project, { recursive: true, force: true });
+      rmSync(output, { force: true });
+      rmSync(`${output}.map`, { force: true });
+    }
+  });
+
+  it('restarts a watched script when an imported module changes', async () => {
+    mkdirSync(join(workspace, 'watch'), { recursive: true });
+    const dependency = join(workspace, 'watch', 'value.ts');
+    const entrypoint = join(workspace, 'watch', 'entry.ts');
+    writeFileSync(dependency, `export const value = 'first';\n`);
+    writeFileSync(
+      entrypoint,
+      `import { value } from './value.ts';
+      console.log(\`WATCH:\${value}\`);
+      setInterval(() => {}, 1000);`,
+    );
+
+    const child = spawn(binary, ['run', entrypoint, '--workspace', workspace, '--resources', workspace, '--watch'], {
+      cwd: workspace,
+      stdio: ['ignore', 'pipe', 'pipe'],
+    });
+    let output = '';
+    child.stdout.on('data', (chunk: Buffer) => {
+      output += chunk.toString();
+    });
+    child.stderr.on('data', (chunk: Buffer) => {
+      output += chunk.toString();
+    });
+    const waitFor = (text: string) =>
+      new Promise<void>((resolve, reject) => {
+        const timeout = setTimeout(() => reject(new Error(`Timed out waiting for ${text}:\n${output}`)), 30_000);
+        const check = () => {
+          if (output.includes(text)) {
+            clearTimeout(timeout);
+            resolve();
+          }
+        };
+        child.stdout.on('data', check);
+        child.stderr.on('data', check);
+        check();
+      });
+
+    try {
+      await waitFor('WATCH:first');
+      writeFileSync(dependency, `export const value = 'second';\n`);
+      await waitFor('WATCH:second');
+
+      expect(output).toContain('changed, restarting');
+      expect(output.match(/WATCH:/g)).toHaveLength(2);
+
+      writeFileSync(dependency, `throw new Error('watched failure');\nexport const value = 'third';\n`);
+      await waitFor('Waiting for changes before restarting');
+      expect(output).toContain('watched failure');
+    } finally {
+      child.kill('SIGKILL');
+    }
+  }, 60_000);
+
+  it('lists the searched directories when a package cannot be resolved', () => {
+    const output = runFailure(`import 'missing-package/sub';`, 'missing-package.ts');
+
+    expect(output).toContain('Searched:');
+    expect(output).toContain(join(workspace, 'node_modules', 'missing-package'));
+
+    const entrypoint = join(workspace, 'missing-package.ts');
+    const traced = spawnSync(
+      binary,
+      ['--trace-resolution', 'run', entrypoint, '--workspace', workspace, '--resources', workspace],
+      { cwd: workspace, encoding: 'utf8', timeout: 60_000 },
+    );
+
+    expect(traced.stderr).toContain('Looking up package missing-package/sub (import)');
+    expect(traced.stderr).toContain(`${join(workspace, 'node_modules', 'missing-package')} (not found)`);
+    expect(traced.stderr).toContain('missing-package/sub from');
+  });
+
+  it('prints the module graph of an entrypoint without running it', () => {
+    mkdirSync(join(workspace, 'graph'), { recursive: true });
+    writeFileSync(join(workspace, 'graph', 'helper.ts'), `import 'yasumu:test';\nexport const helper = 1;\n`);
+    writeFileSync(join(workspace, 'graph', 'data.yaml'), 'answer: 42\n');
+    const entrypoint = join(workspace, 'graph', 'entry.ts');
+    writeFileSync(
+      entrypoint,
+      `import { helper } from './helper.ts';
+      import data from './data.yaml';
+      import './missing.ts';
+      console.log('GRAPH_SIDE_EFFECT', helper, data);`,
+    );
+
+    const output = execFileSync(binary, ['info', entrypoint, '--workspace', workspace, '--json'], {
+      cwd: workspace,
+      encoding: 'utf8',
+      timeout: 10_000,
+    });
+    const graph = JSON.parse(output);
+    const helper = graph.modules.find((module: { specifier: string }) => module.specifier.endsWith('/graph/helper.ts'));
+
+    expect(output).not.toContain('GRAPH_SIDE_EFFECT');
+    expect(graph.modules[0].dependencies.map((dependency: { specifier: string }) => dependency.specifier)).toEqual([
+      './helper.ts',
+      './data.yaml',
+      './missing.ts',
+    ]);
+    expect(helper.dependencies[0]).toMatchObject({ specifier: 'yasumu:test', resolved: 'yasumu:test' });
+    expect(graph.modules.find((module: { specifier: string }) => module.specifier === 'yasumu:test')).toMatchObject({
+      kind: 'builtin',
+    });
+    expect(graph.modules.find((module: { specifier: string }) => module.specifier.endsWith('/data.yaml'))).toMatchObject({
+      mediaType: 'YAML',
+      size: 11,
+    });
+    expect(graph.modules.find((module: { specifier: string }) => module.specifier.endsWith('/missing.ts')).error).toBeTruthy();
+  });
+
+  it('exposes named exports of CommonJS packages, including re-exports', () => {
+    const packageDir = join(workspace, 'node_modules', 'tanxium-cjs-fixture');
+    mkdirSync(join(packageDir, 'lib'), { recursive: true });
+    writeFileSync(join(packageDir, 'package.json'), JSON.stringify({ name: 'tanxium-cjs-fixture', main: 'index.js' }));
+    writeFileSync(
+      join(packageDir, 'index.js'),
+      `"use strict";
+      var __exportStar = function (m, exports) { for (var p in m) if (p !== "default") exports[p] = m[p]; };
+      Object.defineProperty(exports, "__esModule", { value: true });
+      // exports.commented = true;
+      exports.note = "exports.quoted = true";
+      __exportStar(require("./lib/client"), exports);`,
+    );
+    writeFileSync(
+      join(packageDir, 'lib', 'client.js'),
+      `const connect = () => 'connected';
+      const version = 3;
+      module.exports = { connect, version };`,
+    );
+
+    const output = run(/* js */ `
+      import * as fixture from 'tanxium-cjs-fixture';
+      import { connect, version } from 'tanxium-cjs-fixture';
+      console.log(\`CJS:\${connect()}:\${version}:\${'commented' in fixture}:\${'quoted' in fixture}\`);
+    `);
+
+    expect(output).toContain('CJS:connected:3:false:false');
+  });
+
+  it('loads ESM packages without wrapping them as CommonJS', () => {
+    const typedDir = join(workspace, 'node_modules', 'tanxium-esm-typed');
+    const untypedDir = join(workspace, 'node_modules', 'tanxium-esm-untyped');
+    mkdirSync(typedDir, { recursive: true });
+    mkdirSync(untypedDir, { recursive: true });
+    writeFileSync(
+      join(typedDir, 'package.json'),
+      JSON.stringify({ name: 'tanxium-esm-typed', type: 'module', main: 'index.js' }),
+    );
+    writeFileSync(join(typedDir, 'index.js'), `export const kind = typeof module === 'undefined' ? 'esm' : 'cjs';`);
+    writeFileSync(join(untypedDir, 'package.json'), JSON.stringify({ name: 'tanxium-esm-untyped', main: 'index.js' }));
+    writeFileSync(
+      join(untypedDir, 'index.js'),
+      `export default function format() { return typeof require === 'undefined' ? 'esm' : 'cjs'; }`,
+    );
+
+    const output = run(/* js */ `
+      import { kind } from 'tanxium-esm-typed';
+      import format from 'tanxium-esm-untyped';
+      console.log(\`ESM_FORMAT:\${kind}:\${format()}\`);
+    `);
+
+    expect(output).toContain('ESM_FORMAT:esm:esm');
+  });
+
+  it('applies workspace import maps, including scoped mappings', () => {
+    const configPath = join(workspace, 'deno.json');
+    mkdirSync(join(workspace, 'shared', 'utils'), { recursive: true });
+    mkdirSync(join(workspace, 'legacy'), { recursive: true });
+    writeFileSync(join(workspace, 'shared', 'utils', 'mod.ts'), `export const version = 'current';`);
+    writeFileSync(join(workspace, 'shared', 'utils', 'v1.ts'), `export const version = 'legacy';`);
+    writeFileSync(
+      join(workspace, 'legacy', 'entry.ts'),
+      `import { version } from '@team/utils'; export const legacyVersion = version;`,
+    );
+    writeFileSync(
+      configPath,
+      JSON.stringify({
+        imports: { '@team/utils': './shared/utils/mod.ts' },
+        scopes: { './legacy/': { '@team/utils': './shared/utils/v1.ts' } },
+      }),
+    );
+
+    try {
+      const output = run(/* ts */ `
+        import { version } from '@team/utils';
+        import { legacyVersion } from './legacy/entry.ts';
+        console.log(\`IMPORT_MAP:\${version}:\${legacyVersion}\`);
+      `);
+
+      expect(output).toContain('IMPORT_MAP:current:legacy');
+    } finally {
+      rmSync(configPath);
+    }
+  });
+
+  it('transpiles with the workspace compiler options', () => {
+    const configPath = join(workspace, 'deno.json');
+    // TC39 decorators are TypeScript's default; an unsupported `jsx` only warns.
+    writeFileSync(configPath, JSON.stringify({ compilerOptions: { jsx: 'preserve' } }));
+
+    try {
+      const output = run(/* ts */ `
+        function tagged(_value: unknown, context: ClassDecoratorContext) {
+          console.log(\`DECORATOR:\${context.kind}:\${String(context.name)}\`);
+        }
+
+        @tagged
+        class Example {}
+
+        new Example();
+      `);
+
+      expect(output).toContain('DECORATOR:class:Example');
+    } finally {
+      rmSync(configPath);
+    }
+  });
+
+  it('installs npm: specifiers from a configured registry into the managed cache', async () => {
+    const packages: Record<string, { version: string; files: Record<string, string>; dependencies?: object }> = {
+      'tanxium-npm-fixture': {
+        version: '1.4.0',
+        dependencies: { 'tanxium-npm-dependency': '^2.0.0' },
+        files: {
+          'package.json': JSON.stringify({ name: 'tanxium-npm-fixture', type: 'module', exports: './index.js' }),
+          'index.js': `import { suffix } from 'tanxium-npm-dependency'; export const greeting = 'hello ' + suffix;`,
+        },
+      },
+      'tanxium-npm-dependency': {
+        version: '2.1.0',
+        files: {
+          'package.json': JSON.stringify({ name: 'tanxium-npm-dependency', type: 'module', exports: './index.js' }),
+          'index.js': `export const suffix = 'from npm';`,
+        },
+      },
+    };
+    const tarballs = new Map(
+      Object.entries(packages).map(([name, { files }]) => [name, createPackageTarball(files)] as const),
+    );
+
+    const server = createServer((request, response) => {
+      const address = server.address();
+      const port = address !== null && typeof address !== 'string' ? address.port : 0;
+      const name = decodeURIComponent(request.url?.slice(1) ?? '');
+      const tarballName = name.endsWith('.tgz') ? name.slice(0, -'.tgz'.length) : undefined;
+
+      if (tarballName !== undefined && tarballs.has(tarballName)) {
+        response.writeHead(200, { 'Content-Type': 'application/octet-stream' }).end(tarballs.get(tarballName));
+        return;
+      }
+
+      const fixture = packages[name];
+      if (fixture === undefined) {
+        response.writeHead(404).end();
+        return;
+      }
+
+      const tarball = tarballs.get(name)!;
+      response.writeHead(200, { 'Content-Type': 'application/json' });
+      // a slow registry, so the script's timers run while the package installs
+      setTimeout(() => {
+        response.end(
+          JSON.stringify({
+            name,
+            'dist-tags': { latest: fixture.version },
+            versions: {
+              [fixture.version]: {
+                name,
+                version: fixture.version,
+                dependencies: fixture.dependencies,
+                dist: {
+                  tarball: `http://127.0.0.1:${port}/${name}.tgz`,
+                  integrity: `sha512-${createHash('sha512').update(tarball).digest('base64')}`,
+                },
+              },
+            },
+          }),
+        );
+      }, 200);
+    });
+    server.listen(0, '127.0.0.1');
+    await once(server, 'listening');
+    const address = server.address();
+    if (address === null || typeof address === 'string') {
+      throw new Error('Unable to determine the registry fixture server address');
+    }
+
+    const cacheDir = mkdtempSync(join(tmpdir(), 'tanxium-npm-cache-'));
+    try {
+      const entrypoint = join(workspace, 'npm-entry.ts');
+      writeFileSync(
+        entrypoint,
+        /* ts */ `
+        let ticks = 0;
+        const interval = setInterval(() => ticks++, 10);
+        const { greeting } = await import('npm:tanxium-npm-fixture@^1');
+        clearInterval(interval);
+        const resolved = import.meta.resolve('npm:tanxium-npm-fixture@^1');
+        console.log(\`NPM:\${greeting}:\${resolved}:\${ticks > 0}\`);
+      `,
+      );
+      const output = await new Promise<string>((resolve, reject) => {
+        const child = spawn(binary, ['run', entrypoint, '--workspace', workspace, '--resources', workspace], {
+          cwd: workspace,
+          env: {
+            ...process.env,
+            TANXIUM_DIR: cacheDir,
+            NPM_CONFIG_REGISTRY: `http://127.0.0.1:${address.port}/`,
+          },
+          stdio: ['ignore', 'pipe', 'pipe'],
+        });
+        let output = '';
+        child.stdout.on('data', (chunk: Buffer) => {
+          output += chunk.toString();
+        });
+        child.stderr.on('data', (chunk: Buffer) => {
+          output += chunk.toString();
+        });
+        child.once('error', reject);
+        child.once('close', () => resolve(output));
+      });
+
+      expect(output).toContain('NPM:hello from npm:npm:tanxium-npm-fixture@^1:true');
+    } finally {
+      rmSync(cacheDir, { recursive: true, force: true });
+      await new Promise<void>((resolve, reject) => {
+        server.close((error) => (error ? reject(error) : resolve()));
+      });
+    }
+  }, 60_000);
+
+  it('resolves jsr: specifiers against a configured registry and caches them', async () => {
+    const files: Record<string, string> = {
+      '/@team/utils/meta.json': JSON.stringify({
+        scope: 'team',
+        name: 'utils',
+        latest: '1.2.0',
+        versions: { '1.1.0': {}, '1.2.0': {}, '2.0.0': {} },
+      }),
+      '/@team/utils/1.2.0_meta.json': JSON.stringify({
+        exports: { '.': './mod.ts', './greet': './greet.ts' },
+      }),
+      '/@team/utils/1.2.0/mod.ts': `export { greet } from './greet.ts';\nexport const version: string = '1.2.0';\n`,
+      '/@team/utils/1.2.0/greet.ts': `export const greet = (name: string): string => 'hello ' + name;\n`,
+    };
+    const server = createServer((request, response) => {
+      const content = files[request.url ?? ''];
+      if (content === undefined) {
+        response.writeHead(404).end();
+        return;
+      }
+
+      const contentType = request.url?.endsWith('.ts') ? 'application/typescript' : 'application/json';
+      response.writeHead(200, { 'Content-Type': contentType }).end(content);
+    });
+    server.listen(0, '127.0.0.1');
+    await once(server, 'listening');
+    const address = server.address();
+    if (address === null || typeof address === 'string') {
+      throw new Error('Unable to determine the JSR fixture server address');
+    }
+
+    const cacheDir = mkdtempSync(join(tmpdir(), 'tanxium-jsr-cache-'));
+    const entrypoint = join(workspace, 'jsr-entry.ts');
+    writeFileSync(
+      entrypoint,
+      /* ts */ `
+      import { version } from 'jsr:@team/utils@^1';
+      import { greet } from 'jsr:@team/utils@^1/greet';
+      const resolved = import.meta.resolve('jsr:@team/utils@^1/greet');
+      console.log(\`JSR:\${version}:\${greet('jsr')}:\${resolved}\`);
+    `,
+    );
+    const runWithRegistry = () =>
+      new Promise<string>((resolve, reject) => {
+        const child = spawn(binary, ['run', entrypoint, '--workspace', workspace, '--resources', workspace], {
+          cwd: workspace,
+          env: { ...process.env, TANXIUM_DIR: cacheDir, JSR_URL: `http://127.0.0.1:${address.port}/` },
+          stdio: ['ignore', 'pipe', 'pipe'],
+        });
+        let output = '';
+        child.stdout.on('data', (chunk: Buffer) => {
+          output += chunk.toString();
+        });
+        child.stderr.on('data', (chunk: Buffer) => {
+          output += chunk.toString();
+        });
+        child.once('error', reject);
+        child.once('close', () => resolve(output));
+      });
+
+    try {
+      expect(await runWithRegistry()).toContain('JSR:1.2.0:hello jsr:jsr:@team/utils@^1/greet');
+
+      await new Promise<void>((resolve, reject) => {
+        server.close((error) => (error ? reject(error) : resolve()));
+      });
+
+      expect(await runWithRegistry()).toContain('JSR:1.2.0:hello jsr:jsr:@team/utils@^1/greet');
+    } finally {
+      rmSync(cacheDir, { recursive: true, force: true });
+      if (server.listening) {
+        server.close();
+      }
+    }
+  }, 60_000);
+
   it('loads CommonJS packages and serves SMTP over Node TCP', async () => {
     const child = spawn(binary, ['--no-sandbox', 'run', './test/smtp-server-test.ts', '--verbose'], {
       cwd: tanxiumPackage,
@@ -458,4 +1622,21 @@ describe('tanxium CLI runtime semantics', () => {
       ]),
     );
   }, 180_000);
+
+  it('backs Deno.env and process.env in hooks with the workspace environment', () => {
+    const entrypoint = join(root, 'tests/tanxium-runtime/fixtures/headless-workspace-env.ts');
+
+    const output = runEntrypoint(entrypoint, { noSandbox: true, timeout: 60_000 });
+    const marker = output.split('\n').find((line) => line.startsWith('WORKSPACE_ENV:'));
+    const { seen, variables } = JSON.parse(marker!.slice('WORKSPACE_ENV:'.length));
+
+    expect(seen).toEqual({
+      apiUrl: 'https://staging.example.test',
+      port: '8080',
+      token: 'token-1',
+      path: true,
+      keys: ['API_TOKEN', 'API_URL', 'FROM_HOOK', 'PATH', 'PORT'],
+    });
+    expect(variables.FROM_HOOK).toBe('written');
+  }, 90_000);
 });